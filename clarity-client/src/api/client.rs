@@ -0,0 +1,636 @@
+//! Request policy for calls to the Clarity server
+//!
+//! The transport is supplied by the caller as an async closure, so the same
+//! policy runs on native and wasm targets. Each attempt is bounded by a
+//! timeout, and failed requests are retried with exponential backoff when it
+//! is safe to do so: only for reads (and idempotent writes when the policy
+//! opts in), and only for connection errors, 5xx and 429 responses. A 429's `Retry-After` is waited
+//! out before the next attempt.
+
+use clarity_core::HttpMethod;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Default time a single request may take before it is abandoned
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Error details the server sends with a non-success response
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct ErrorBody {
+  /// Machine-readable error code, e.g. `"not_found"`
+  pub code: String,
+  /// Human-readable description
+  pub message: String,
+}
+
+/// The `{"error": {...}}` envelope wrapping `ErrorBody`
+#[derive(Deserialize)]
+struct ErrorEnvelope {
+  error: ErrorBody,
+}
+
+/// Errors returned by API requests
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClientError {
+  /// The server could not be reached
+  Network(String),
+  /// The server did not answer within the request timeout
+  Timeout(Duration),
+  /// The response body did not match the expected type
+  Deserialization(String),
+  /// The server answered with a non-success status code
+  Status {
+    /// HTTP status code
+    code: u16,
+    /// The error envelope, if the body contained one
+    body: Option<ErrorBody>,
+  },
+  /// The response body was not valid UTF-8 text
+  Decode(String),
+  /// The offline queue could not be read or written
+  Storage(String),
+  /// The server answered `429 Too Many Requests`
+  RateLimited {
+    /// How long the server asked the client to wait, from `Retry-After`
+    retry_after: Option<Duration>,
+  },
+}
+
+impl ClientError {
+  /// A `429 Too Many Requests` error carrying the response's `Retry-After` header
+  ///
+  /// Only the delay-seconds form of the header is understood; a missing or
+  /// malformed value leaves the delay unset.
+  #[must_use]
+  pub fn rate_limited(retry_after: Option<&str>) -> Self {
+    Self::RateLimited {
+      retry_after: retry_after
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs),
+    }
+  }
+
+  /// How long the server asked the client to wait before trying again
+  #[must_use]
+  pub const fn retry_after(&self) -> Option<Duration> {
+    match self {
+      Self::RateLimited { retry_after } => *retry_after,
+      _ => None,
+    }
+  }
+
+  /// Whether the error may go away if the request is repeated
+  ///
+  /// Timeouts are not: a server that hangs once is likely to hang again, and
+  /// retrying would keep the caller waiting several timeouts long.
+  #[must_use]
+  pub const fn is_transient(&self) -> bool {
+    match self {
+      Self::Network(_) | Self::RateLimited { .. } => true,
+      Self::Status { code, .. } => *code >= 500 && *code <= 599,
+      Self::Timeout(_) | Self::Deserialization(_) | Self::Decode(_) | Self::Storage(_) => false,
+    }
+  }
+}
+
+impl std::fmt::Display for ClientError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Network(msg) => write!(f, "Network error: {msg}"),
+      Self::Timeout(timeout) => write!(f, "Request timed out after {timeout:?}"),
+      Self::Deserialization(msg) => write!(f, "Unexpected response body: {msg}"),
+      Self::Status {
+        code,
+        body: Some(body),
+      } => write!(f, "Server responded with status {code}: {}", body.message),
+      Self::Status { code, body: None } => write!(f, "Server responded with status {code}"),
+      Self::Decode(msg) => write!(f, "Response body is not valid text: {msg}"),
+      Self::Storage(msg) => write!(f, "Offline queue storage failed: {msg}"),
+      Self::RateLimited {
+        retry_after: Some(delay),
+      } => write!(f, "Rate limited; retry after {delay:?}"),
+      Self::RateLimited { retry_after: None } => write!(f, "Rate limited"),
+    }
+  }
+}
+
+impl std::error::Error for ClientError {}
+
+/// Turn a response's status code and body into the expected value
+///
+/// Success responses are parsed as JSON into `T`. A 429 becomes
+/// `ClientError::RateLimited`; transports that can read headers should build
+/// it with [`ClientError::rate_limited`] instead, so `Retry-After` is kept.
+/// Other responses become `ClientError::Status`, carrying the server's error
+/// envelope when the body holds one.
+///
+/// # Errors
+/// - Returns `ClientError::RateLimited` for a 429 status
+/// - Returns `ClientError::Status` for any other non-2xx status
+/// - Returns `ClientError::Decode` if the body is not UTF-8
+/// - Returns `ClientError::Deserialization` if the body is not a valid `T`
+pub fn decode_response<T: DeserializeOwned>(status: u16, body: &[u8]) -> Result<T, ClientError> {
+  if status == 429 {
+    return Err(ClientError::rate_limited(None));
+  }
+  if !(200..=299).contains(&status) {
+    let body = serde_json::from_slice::<ErrorEnvelope>(body)
+      .ok()
+      .map(|envelope| envelope.error);
+    return Err(ClientError::Status { code: status, body });
+  }
+
+  let text = std::str::from_utf8(body).map_err(|e| ClientError::Decode(e.to_string()))?;
+  serde_json::from_str(text).map_err(|e| ClientError::Deserialization(e.to_string()))
+}
+
+/// How often and how quickly failed requests are retried
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+  /// Retries after the first attempt; zero disables retrying
+  pub max_retries: u32,
+  /// Delay before the first retry, doubled for each retry after it
+  pub base_delay: Duration,
+  /// Upper bound on the delay before any retry
+  pub max_delay: Duration,
+  /// Whether idempotent writes (`PUT`, `DELETE`) are retried too; off by
+  /// default, so only `GET`, `HEAD` and `OPTIONS` are
+  pub retry_writes: bool,
+}
+
+impl RetryPolicy {
+  /// Create a retry policy
+  #[must_use]
+  pub const fn new(max_retries: u32, base_delay: Duration, max_delay: Duration) -> Self {
+    Self {
+      max_retries,
+      base_delay,
+      max_delay,
+      retry_writes: false,
+    }
+  }
+
+  /// Also retry idempotent writes such as `PUT` and `DELETE`
+  #[must_use]
+  pub const fn with_retry_writes(mut self, retry_writes: bool) -> Self {
+    self.retry_writes = retry_writes;
+    self
+  }
+
+  /// How many times a failed request with `method` may be retried
+  ///
+  /// Safe methods get `max_retries`; idempotent writes only when
+  /// `retry_writes` is set, and `POST` and `PATCH` never.
+  #[must_use]
+  pub const fn retries_for(&self, method: HttpMethod) -> u32 {
+    if method.is_safe() || (self.retry_writes && method.is_idempotent()) {
+      self.max_retries
+    } else {
+      0
+    }
+  }
+
+  /// A policy that never retries
+  #[must_use]
+  pub const fn none() -> Self {
+    Self::new(0, Duration::ZERO, Duration::ZERO)
+  }
+
+  /// Delay before retry number `retry` (starting at 0), without jitter
+  #[must_use]
+  pub fn backoff(&self, retry: u32) -> Duration {
+    self
+      .base_delay
+      .saturating_mul(2_u32.saturating_pow(retry))
+      .min(self.max_delay)
+  }
+
+  /// Delay before retry number `retry` with jitter applied
+  ///
+  /// `jitter` is a fraction in `0.0..1.0`; the result lies between half the
+  /// backoff and the full backoff, so concurrent clients spread out without
+  /// retrying immediately.
+  #[must_use]
+  pub fn delay(&self, retry: u32, jitter: f64) -> Duration {
+    let half = self.backoff(retry) / 2;
+    half + half.mul_f64(jitter.clamp(0.0, 1.0))
+  }
+}
+
+impl Default for RetryPolicy {
+  /// Five retries from 200ms up to 5s, enough to ride out a server restart
+  fn default() -> Self {
+    Self::new(5, Duration::from_millis(200), Duration::from_secs(5))
+  }
+}
+
+/// Settings applied to every request sent to the server
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ApiClient {
+  /// How failed requests are retried
+  pub retry: RetryPolicy,
+  /// Time a single attempt may take before it fails with `ClientError::Timeout`
+  pub timeout: Duration,
+}
+
+impl ApiClient {
+  /// Create a client with the default retry policy and timeout
+  #[must_use]
+  pub fn new() -> Self {
+    Self {
+      retry: RetryPolicy::default(),
+      timeout: DEFAULT_TIMEOUT,
+    }
+  }
+
+  /// Use a different retry policy
+  #[must_use]
+  pub const fn with_retry(mut self, retry: RetryPolicy) -> Self {
+    self.retry = retry;
+    self
+  }
+
+  /// Use a different per-request timeout
+  #[must_use]
+  pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+    self.timeout = timeout;
+    self
+  }
+
+  /// Send a request, applying the timeout to each attempt and retrying
+  /// transient failures
+  ///
+  /// # Errors
+  /// Returns `ClientError::Timeout` if an attempt takes longer than the
+  /// timeout, otherwise the error of the last attempt
+  pub async fn send<T, F, SendFut>(&self, method: HttpMethod, mut send: F) -> Result<T, ClientError>
+  where
+    F: FnMut() -> SendFut,
+    SendFut: Future<Output = Result<T, ClientError>>,
+  {
+    send_with_retry(&self.retry, method, sleep, || {
+      with_timeout(self.timeout, send())
+    })
+    .await
+  }
+}
+
+impl Default for ApiClient {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// Run `request`, failing with `ClientError::Timeout` if it takes longer than `timeout`
+///
+/// # Errors
+/// Returns `ClientError::Timeout` on timeout, otherwise the request's own result
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn with_timeout<T>(
+  timeout: Duration,
+  request: impl Future<Output = Result<T, ClientError>>,
+) -> Result<T, ClientError> {
+  tokio::time::timeout(timeout, request)
+    .await
+    .unwrap_or(Err(ClientError::Timeout(timeout)))
+}
+
+/// Run `request`, failing with `ClientError::Timeout` if it takes longer than `timeout`
+///
+/// `tokio::time` is not available in the browser, so the request is raced
+/// against a `setTimeout`-backed timer instead.
+///
+/// # Errors
+/// Returns `ClientError::Timeout` on timeout, otherwise the request's own result
+#[cfg(target_arch = "wasm32")]
+pub async fn with_timeout<T>(
+  timeout: Duration,
+  request: impl Future<Output = Result<T, ClientError>>,
+) -> Result<T, ClientError> {
+  use std::task::Poll;
+
+  let mut request = std::pin::pin!(request);
+  let mut timer = std::pin::pin!(gloo_timers::future::sleep(timeout));
+  std::future::poll_fn(|cx| {
+    if let Poll::Ready(result) = request.as_mut().poll(cx) {
+      return Poll::Ready(result);
+    }
+    timer
+      .as_mut()
+      .poll(cx)
+      .map(|()| Err(ClientError::Timeout(timeout)))
+  })
+  .await
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(super) async fn sleep(duration: Duration) {
+  tokio::time::sleep(duration).await;
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(super) async fn sleep(duration: Duration) {
+  gloo_timers::future::sleep(duration).await;
+}
+
+/// Send a request, retrying transient failures according to `policy`
+///
+/// `send` performs one attempt and `sleep` waits between attempts, so tests
+/// can pass a sleep that returns immediately. How often a request may be
+/// retried depends on its method; see [`RetryPolicy::retries_for`]. A
+/// `Retry-After` delay from the server
+/// replaces the backoff when it is longer.
+///
+/// # Errors
+/// Returns the error of the last attempt if no attempt succeeded
+pub async fn send_with_retry<T, S, SleepFut, F, SendFut>(
+  policy: &RetryPolicy,
+  method: HttpMethod,
+  mut sleep: S,
+  mut send: F,
+) -> Result<T, ClientError>
+where
+  S: FnMut(Duration) -> SleepFut,
+  SleepFut: Future<Output = ()>,
+  F: FnMut() -> SendFut,
+  SendFut: Future<Output = Result<T, ClientError>>,
+{
+  let max_retries = policy.retries_for(method);
+
+  let mut retry = 0;
+  loop {
+    match send().await {
+      Err(error) if error.is_transient() && retry < max_retries => {
+        let delay = policy.delay(retry, jitter());
+        sleep(error.retry_after().map_or(delay, |after| after.max(delay))).await;
+        retry += 1;
+      }
+      result => return result,
+    }
+  }
+}
+
+/// A pseudo-random fraction in `0.0..1.0`, taken from std's randomly keyed hasher
+fn jitter() -> f64 {
+  let bits = RandomState::new().build_hasher().finish() >> 32;
+  f64::from(u32::try_from(bits).unwrap_or(0)) / (f64::from(u32::MAX) + 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::cell::{Cell, RefCell};
+
+  const fn status(code: u16) -> ClientError {
+    ClientError::Status { code, body: None }
+  }
+
+  fn policy() -> RetryPolicy {
+    RetryPolicy::new(3, Duration::from_millis(100), Duration::from_millis(250))
+  }
+
+  #[test]
+  fn test_backoff_doubles_up_to_max_delay() {
+    let policy = policy();
+    assert_eq!(policy.backoff(0), Duration::from_millis(100));
+    assert_eq!(policy.backoff(1), Duration::from_millis(200));
+    assert_eq!(policy.backoff(2), Duration::from_millis(250));
+    assert_eq!(policy.backoff(40), Duration::from_millis(250));
+  }
+
+  #[test]
+  fn test_delay_stays_within_jitter_bounds() {
+    let policy = policy();
+    assert_eq!(policy.delay(1, 0.0), Duration::from_millis(100));
+    assert_eq!(policy.delay(1, 1.0), Duration::from_millis(200));
+    let jittered = policy.delay(1, jitter());
+    assert!(jittered >= Duration::from_millis(100) && jittered <= Duration::from_millis(200));
+  }
+
+  #[test]
+  fn test_transient_errors() {
+    assert!(ClientError::Network("refused".to_string()).is_transient());
+    assert!(status(503).is_transient());
+    assert!(ClientError::rate_limited(None).is_transient());
+    assert!(!status(404).is_transient());
+  }
+
+  #[test]
+  fn test_rate_limited_reads_retry_after_seconds() {
+    assert_eq!(
+      ClientError::rate_limited(Some("3")).retry_after(),
+      Some(Duration::from_secs(3))
+    );
+    assert_eq!(ClientError::rate_limited(Some("soon")).retry_after(), None);
+    assert_eq!(ClientError::rate_limited(None).retry_after(), None);
+    assert_eq!(
+      decode_response::<String>(429, b""),
+      Err(ClientError::rate_limited(None))
+    );
+  }
+
+  #[test]
+  fn test_not_found_response_carries_error_envelope() {
+    let body = br#"{"error":{"code":"not_found","message":"bead 42 not found"}}"#;
+    let result = decode_response::<String>(404, body);
+    assert!(matches!(result, Err(ClientError::Status { code: 404, .. })));
+    assert_eq!(
+      result,
+      Err(ClientError::Status {
+        code: 404,
+        body: Some(ErrorBody {
+          code: "not_found".to_string(),
+          message: "bead 42 not found".to_string(),
+        }),
+      })
+    );
+  }
+
+  #[test]
+  fn test_error_status_without_envelope() {
+    assert_eq!(
+      decode_response::<String>(502, b"Bad Gateway"),
+      Err(status(502))
+    );
+  }
+
+  #[test]
+  fn test_success_response_is_deserialized() {
+    assert_eq!(decode_response::<Vec<u32>>(200, b"[1, 2]"), Ok(vec![1, 2]));
+    assert!(matches!(
+      decode_response::<Vec<u32>>(200, b"{}"),
+      Err(ClientError::Deserialization(_))
+    ));
+    assert!(matches!(
+      decode_response::<Vec<u32>>(200, &[0xff, 0xfe]),
+      Err(ClientError::Decode(_))
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_get_retries_until_success() {
+    let attempts = Cell::new(0);
+    let sleeps = RefCell::new(Vec::new());
+
+    let result = send_with_retry(
+      &policy(),
+      HttpMethod::Get,
+      |delay| {
+        sleeps.borrow_mut().push(delay);
+        async {}
+      },
+      || {
+        attempts.set(attempts.get() + 1);
+        let attempt = attempts.get();
+        async move {
+          if attempt < 3 {
+            Err(status(503))
+          } else {
+            Ok(attempt)
+          }
+        }
+      },
+    )
+    .await;
+
+    assert_eq!(result, Ok(3));
+    assert_eq!(sleeps.borrow().len(), 2);
+  }
+
+  #[tokio::test]
+  async fn test_get_waits_out_retry_after() {
+    let attempts = Cell::new(0);
+    let sleeps = RefCell::new(Vec::new());
+
+    let result = send_with_retry(
+      &policy(),
+      HttpMethod::Get,
+      |delay| {
+        sleeps.borrow_mut().push(delay);
+        async {}
+      },
+      || {
+        attempts.set(attempts.get() + 1);
+        let attempt = attempts.get();
+        async move {
+          if attempt < 2 {
+            Err(ClientError::rate_limited(Some("2")))
+          } else {
+            Ok(attempt)
+          }
+        }
+      },
+    )
+    .await;
+
+    assert_eq!(result, Ok(2));
+    assert_eq!(*sleeps.borrow(), vec![Duration::from_secs(2)]);
+  }
+
+  #[tokio::test]
+  async fn test_get_returns_last_error_after_max_retries() {
+    let attempts = Cell::new(0);
+
+    let result: Result<(), ClientError> = send_with_retry(
+      &policy(),
+      HttpMethod::Get,
+      |_| async {},
+      || {
+        attempts.set(attempts.get() + 1);
+        let attempt = attempts.get();
+        async move { Err(ClientError::Network(format!("attempt {attempt}"))) }
+      },
+    )
+    .await;
+
+    assert_eq!(attempts.get(), 4);
+    assert_eq!(result, Err(ClientError::Network("attempt 4".to_string())));
+  }
+
+  #[tokio::test]
+  async fn test_post_and_client_errors_are_not_retried() {
+    let attempts = Cell::new(0);
+    let send = || {
+      attempts.set(attempts.get() + 1);
+      async { Err::<(), _>(status(500)) }
+    };
+    let result = send_with_retry(&policy(), HttpMethod::Post, |_| async {}, send).await;
+    assert_eq!(result, Err(status(500)));
+    assert_eq!(attempts.get(), 1);
+
+    attempts.set(0);
+    let send = || {
+      attempts.set(attempts.get() + 1);
+      async { Err::<(), _>(status(404)) }
+    };
+    let result = send_with_retry(&policy(), HttpMethod::Get, |_| async {}, send).await;
+    assert_eq!(result, Err(status(404)));
+    assert_eq!(attempts.get(), 1);
+  }
+
+  #[tokio::test]
+  async fn test_writes_are_not_retried_by_default() {
+    for method in [HttpMethod::Put, HttpMethod::Delete, HttpMethod::Patch] {
+      let attempts = Cell::new(0);
+      let send = || {
+        attempts.set(attempts.get() + 1);
+        async { Err::<(), _>(status(503)) }
+      };
+      let result = send_with_retry(&policy(), method, |_| async {}, send).await;
+      assert_eq!(result, Err(status(503)));
+      assert_eq!(attempts.get(), 1, "{method} should be attempted once");
+    }
+  }
+
+  #[tokio::test]
+  async fn test_idempotent_writes_are_retried_when_enabled() {
+    let policy = policy().with_retry_writes(true);
+    for (method, expected) in [
+      (HttpMethod::Put, 4),
+      (HttpMethod::Delete, 4),
+      (HttpMethod::Post, 1),
+    ] {
+      let attempts = Cell::new(0);
+      let send = || {
+        attempts.set(attempts.get() + 1);
+        async { Err::<(), _>(status(503)) }
+      };
+      let result = send_with_retry(&policy, method, |_| async {}, send).await;
+      assert_eq!(result, Err(status(503)));
+      assert_eq!(attempts.get(), expected, "{method}");
+    }
+  }
+
+  #[tokio::test]
+  async fn test_hung_request_times_out_without_retry() {
+    let attempts = Cell::new(0);
+    let client = ApiClient::new()
+      .with_retry(policy())
+      .with_timeout(Duration::from_millis(10));
+
+    let result: Result<(), ClientError> = client
+      .send(HttpMethod::Get, || {
+        attempts.set(attempts.get() + 1);
+        std::future::pending()
+      })
+      .await;
+
+    assert_eq!(result, Err(ClientError::Timeout(Duration::from_millis(10))));
+    assert_eq!(attempts.get(), 1);
+  }
+
+  #[tokio::test]
+  async fn test_fast_request_is_not_timed_out() {
+    let result = with_timeout(Duration::from_secs(1), async { Ok(7) }).await;
+    assert_eq!(result, Ok(7));
+  }
+
+  #[test]
+  fn test_default_timeout() {
+    assert_eq!(ApiClient::default().timeout, DEFAULT_TIMEOUT);
+  }
+}
@@ -0,0 +1,12 @@
+//! Access to the Clarity server API
+
+pub mod client;
+pub mod offline;
+
+pub use client::{
+  decode_response, send_with_retry, with_timeout, ApiClient, ClientError, ErrorBody, RetryPolicy,
+  DEFAULT_TIMEOUT,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use offline::FileStorage;
+pub use offline::{MemoryStorage, OfflineQueue, PendingMutation, QueueStorage};
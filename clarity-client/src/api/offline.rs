@@ -0,0 +1,466 @@
+//! Queue of writes that could not reach the server
+//!
+//! When a `POST`, `PUT` or `DELETE` fails with `ClientError::Network`, the
+//! request is stored in an [`OfflineQueue`] instead of being lost, and
+//! replayed in order by [`OfflineQueue::flush`] once the server is reachable
+//! again. Each queued request carries an idempotency key for the transport
+//! to send as the `Idempotency-Key` header. The server applies a write once
+//! per key and answers repeats with the stored response, so a request that
+//! reached the server before the connection dropped is not applied twice
+//! when it is replayed.
+//!
+//! Where the queue lives is up to a [`QueueStorage`] backend: memory on wasm,
+//! a JSON file on desktop.
+
+use super::client::{sleep, ApiClient, ClientError};
+use clarity_core::HttpMethod;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// How many `Retry-After` delays one flush waits out before giving up
+pub const MAX_RATE_LIMIT_WAITS: u32 = 3;
+
+/// A write request waiting to be sent to the server
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingMutation {
+  /// Key sent as `Idempotency-Key`, unique to this mutation
+  pub idempotency_key: String,
+  /// HTTP method, e.g. `HttpMethod::Post`
+  pub method: HttpMethod,
+  /// Request path, e.g. `/api/beads`
+  pub path: String,
+  /// JSON request body, if any
+  pub body: Option<serde_json::Value>,
+}
+
+impl PendingMutation {
+  /// Create a mutation with a fresh idempotency key
+  pub fn new(method: HttpMethod, path: impl Into<String>, body: Option<serde_json::Value>) -> Self {
+    Self {
+      idempotency_key: idempotency_key(),
+      method,
+      path: path.into(),
+      body,
+    }
+  }
+}
+
+/// Where an [`OfflineQueue`] keeps its pending mutations
+pub trait QueueStorage {
+  /// Read the stored mutations, oldest first
+  ///
+  /// # Errors
+  /// Returns `ClientError::Storage` if the mutations cannot be read
+  fn load(&self) -> Result<Vec<PendingMutation>, ClientError>;
+
+  /// Replace the stored mutations
+  ///
+  /// # Errors
+  /// Returns `ClientError::Storage` if the mutations cannot be written
+  fn save(&mut self, pending: &[PendingMutation]) -> Result<(), ClientError>;
+}
+
+/// Keeps pending mutations in memory; they are lost when the app closes
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStorage {
+  pending: Vec<PendingMutation>,
+}
+
+impl QueueStorage for MemoryStorage {
+  fn load(&self) -> Result<Vec<PendingMutation>, ClientError> {
+    Ok(self.pending.clone())
+  }
+
+  fn save(&mut self, pending: &[PendingMutation]) -> Result<(), ClientError> {
+    self.pending = pending.to_vec();
+    Ok(())
+  }
+}
+
+/// Keeps pending mutations in a JSON file, so they survive a restart
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Debug)]
+pub struct FileStorage {
+  path: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileStorage {
+  /// Store the queue at `path`; the file is created on the first save
+  pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+    Self { path: path.into() }
+  }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl QueueStorage for FileStorage {
+  fn load(&self) -> Result<Vec<PendingMutation>, ClientError> {
+    match std::fs::read(&self.path) {
+      Ok(bytes) => serde_json::from_slice(&bytes).map_err(|e| ClientError::Storage(e.to_string())),
+      Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+      Err(e) => Err(ClientError::Storage(e.to_string())),
+    }
+  }
+
+  /// Write to a sibling temporary file first, so a crash mid-write cannot
+  /// leave a truncated queue behind
+  fn save(&mut self, pending: &[PendingMutation]) -> Result<(), ClientError> {
+    let json = serde_json::to_vec(pending).map_err(|e| ClientError::Storage(e.to_string()))?;
+    let tmp = self.path.with_extension("tmp");
+    std::fs::write(&tmp, json)
+      .and_then(|()| std::fs::rename(&tmp, &self.path))
+      .map_err(|e| ClientError::Storage(e.to_string()))
+  }
+}
+
+/// Mutations waiting to be replayed, backed by a [`QueueStorage`]
+#[derive(Debug)]
+pub struct OfflineQueue<S> {
+  storage: S,
+}
+
+impl<S: QueueStorage> OfflineQueue<S> {
+  /// Create a queue over `storage`, keeping anything already stored there
+  pub const fn new(storage: S) -> Self {
+    Self { storage }
+  }
+
+  /// Mutations waiting to be sent, oldest first
+  ///
+  /// # Errors
+  /// Returns `ClientError::Storage` if the queue cannot be read
+  pub fn pending(&self) -> Result<Vec<PendingMutation>, ClientError> {
+    self.storage.load()
+  }
+
+  /// Add a mutation to the end of the queue
+  ///
+  /// # Errors
+  /// Returns `ClientError::Storage` if the queue cannot be read or written
+  pub fn enqueue(&mut self, mutation: PendingMutation) -> Result<(), ClientError> {
+    let mut pending = self.storage.load()?;
+    pending.push(mutation);
+    self.storage.save(&pending)
+  }
+
+  /// Replay queued mutations in order, returning how many were sent
+  ///
+  /// When the server rate-limits a mutation with a `Retry-After` delay, the
+  /// flush waits that long and sends it again, up to
+  /// [`MAX_RATE_LIMIT_WAITS`] times. Otherwise it stops at the first failure.
+  /// A mutation that failed with a transient error or a timeout stays at the
+  /// head of the queue for the next flush; one the server rejected outright
+  /// is dropped, since replaying it would fail the same way.
+  ///
+  /// # Errors
+  /// Returns the error of the failed mutation, or `ClientError::Storage` if
+  /// the queue cannot be read or written
+  pub async fn flush<F, SendFut>(&mut self, send: F) -> Result<usize, ClientError>
+  where
+    F: FnMut(PendingMutation) -> SendFut,
+    SendFut: Future<Output = Result<(), ClientError>>,
+  {
+    self.flush_with(sleep, send).await
+  }
+
+  /// [`OfflineQueue::flush`] with the wait between attempts supplied by the caller
+  async fn flush_with<Sl, SleepFut, F, SendFut>(
+    &mut self,
+    mut sleep: Sl,
+    mut send: F,
+  ) -> Result<usize, ClientError>
+  where
+    Sl: FnMut(Duration) -> SleepFut,
+    SleepFut: Future<Output = ()>,
+    F: FnMut(PendingMutation) -> SendFut,
+    SendFut: Future<Output = Result<(), ClientError>>,
+  {
+    let mut pending = self.storage.load()?;
+    let mut replayed = 0;
+    let mut waits = 0;
+    while let Some(mutation) = pending.first().cloned() {
+      match send(mutation).await {
+        Ok(()) => {
+          replayed += 1;
+          waits = 0;
+          pending.remove(0);
+          self.storage.save(&pending)?;
+        }
+        Err(error) => match error.retry_after() {
+          Some(delay) if waits < MAX_RATE_LIMIT_WAITS => {
+            waits += 1;
+            sleep(delay).await;
+          }
+          _ if keeps_mutation(&error) => return Err(error),
+          _ => {
+            pending.remove(0);
+            self.storage.save(&pending)?;
+            return Err(error);
+          }
+        },
+      }
+    }
+    Ok(replayed)
+  }
+}
+
+/// Whether a mutation that failed with `error` should stay queued
+///
+/// A timed-out write may not have reached the server, so it is kept along
+/// with network errors, 5xx and 429 responses.
+const fn keeps_mutation(error: &ClientError) -> bool {
+  error.is_transient() || matches!(error, ClientError::Timeout(_))
+}
+
+impl ApiClient {
+  /// Send a mutation, queueing it for a later flush if the server is unreachable
+  ///
+  /// Returns `Ok(None)` when the mutation was queued.
+  ///
+  /// # Errors
+  /// Returns the error of the request if it failed for any reason other than
+  /// `ClientError::Network`, or `ClientError::Storage` if queueing failed
+  pub async fn send_or_enqueue<T, S, F, SendFut>(
+    &self,
+    queue: &mut OfflineQueue<S>,
+    mutation: PendingMutation,
+    mut send: F,
+  ) -> Result<Option<T>, ClientError>
+  where
+    S: QueueStorage,
+    F: FnMut(&PendingMutation) -> SendFut,
+    SendFut: Future<Output = Result<T, ClientError>>,
+  {
+    match self.send(mutation.method, || send(&mutation)).await {
+      Ok(value) => Ok(Some(value)),
+      Err(ClientError::Network(_)) => queue.enqueue(mutation).map(|()| None),
+      Err(error) => Err(error),
+    }
+  }
+}
+
+/// A fresh idempotency key: a per-process counter mixed with std's randomly
+/// keyed hasher, so keys differ across calls and across app runs
+fn idempotency_key() -> String {
+  static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+  let mut hasher = RandomState::new().build_hasher();
+  hasher.write_u64(COUNTER.fetch_add(1, Ordering::Relaxed));
+  let high = hasher.finish();
+  hasher.write_u64(high);
+  format!("{high:016x}{:016x}", hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::api::client::RetryPolicy;
+  use std::cell::RefCell;
+
+  fn post(path: &str) -> PendingMutation {
+    PendingMutation::new(
+      HttpMethod::Post,
+      path,
+      Some(serde_json::json!({ "title": path })),
+    )
+  }
+
+  fn paths(queue: &OfflineQueue<MemoryStorage>) -> Vec<String> {
+    match queue.pending() {
+      Ok(pending) => pending.into_iter().map(|m| m.path).collect(),
+      Err(e) => panic!("Expected pending mutations, got {e}"),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_flush_replays_in_enqueue_order() {
+    let mut queue = OfflineQueue::new(MemoryStorage::default());
+    for path in ["/a", "/b", "/c"] {
+      assert_eq!(queue.enqueue(post(path)), Ok(()));
+    }
+
+    let sent = RefCell::new(Vec::new());
+    let result = queue
+      .flush(|mutation| {
+        sent.borrow_mut().push(mutation.path);
+        async { Ok(()) }
+      })
+      .await;
+
+    assert_eq!(result, Ok(3));
+    assert_eq!(*sent.borrow(), vec!["/a", "/b", "/c"]);
+    assert!(paths(&queue).is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_flush_keeps_mutations_after_network_error() {
+    let mut queue = OfflineQueue::new(MemoryStorage::default());
+    for path in ["/a", "/b", "/c"] {
+      assert_eq!(queue.enqueue(post(path)), Ok(()));
+    }
+
+    let result = queue
+      .flush(|mutation| async move {
+        if mutation.path == "/b" {
+          Err(ClientError::Network("offline".to_string()))
+        } else {
+          Ok(())
+        }
+      })
+      .await;
+
+    assert_eq!(result, Err(ClientError::Network("offline".to_string())));
+    assert_eq!(paths(&queue), vec!["/b", "/c"]);
+  }
+
+  #[tokio::test]
+  async fn test_flush_keeps_mutations_after_timeout() {
+    let mut queue = OfflineQueue::new(MemoryStorage::default());
+    for path in ["/a", "/b"] {
+      assert_eq!(queue.enqueue(post(path)), Ok(()));
+    }
+
+    let timeout = ClientError::Timeout(Duration::from_secs(30));
+    let result = queue
+      .flush(|_| {
+        let timeout = timeout.clone();
+        async move { Err(timeout) }
+      })
+      .await;
+
+    assert_eq!(result, Err(timeout));
+    assert_eq!(paths(&queue), vec!["/a", "/b"]);
+  }
+
+  #[tokio::test]
+  async fn test_flush_waits_out_retry_after() {
+    let mut queue = OfflineQueue::new(MemoryStorage::default());
+    for path in ["/a", "/b"] {
+      assert_eq!(queue.enqueue(post(path)), Ok(()));
+    }
+
+    let attempts = RefCell::new(Vec::new());
+    let sleeps = RefCell::new(Vec::new());
+    let result = queue
+      .flush_with(
+        |delay| {
+          sleeps.borrow_mut().push(delay);
+          async {}
+        },
+        |mutation| {
+          let first_try = !attempts.borrow().contains(&mutation.path);
+          attempts.borrow_mut().push(mutation.path.clone());
+          async move {
+            if mutation.path == "/b" && first_try {
+              Err(ClientError::rate_limited(Some("2")))
+            } else {
+              Ok(())
+            }
+          }
+        },
+      )
+      .await;
+
+    assert_eq!(result, Ok(2));
+    assert_eq!(*attempts.borrow(), vec!["/a", "/b", "/b"]);
+    assert_eq!(*sleeps.borrow(), vec![Duration::from_secs(2)]);
+    assert!(paths(&queue).is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_flush_keeps_rate_limited_mutations() {
+    let mut queue = OfflineQueue::new(MemoryStorage::default());
+    for path in ["/a", "/b"] {
+      assert_eq!(queue.enqueue(post(path)), Ok(()));
+    }
+
+    let sleeps = RefCell::new(0);
+    let result = queue
+      .flush_with(
+        |_| {
+          *sleeps.borrow_mut() += 1;
+          async {}
+        },
+        |_| async { Err(ClientError::rate_limited(Some("1"))) },
+      )
+      .await;
+
+    assert_eq!(result, Err(ClientError::rate_limited(Some("1"))));
+    assert_eq!(*sleeps.borrow(), MAX_RATE_LIMIT_WAITS);
+    assert_eq!(paths(&queue), vec!["/a", "/b"]);
+
+    let result = queue
+      .flush_with(
+        |_| async {},
+        |_| async { Err(ClientError::rate_limited(None)) },
+      )
+      .await;
+    assert_eq!(result, Err(ClientError::rate_limited(None)));
+    assert_eq!(paths(&queue), vec!["/a", "/b"]);
+  }
+
+  #[tokio::test]
+  async fn test_flush_drops_rejected_mutation() {
+    let mut queue = OfflineQueue::new(MemoryStorage::default());
+    for path in ["/a", "/b"] {
+      assert_eq!(queue.enqueue(post(path)), Ok(()));
+    }
+
+    let rejected = ClientError::Status {
+      code: 422,
+      body: None,
+    };
+    let result = queue
+      .flush(|_| {
+        let rejected = rejected.clone();
+        async move { Err(rejected) }
+      })
+      .await;
+
+    assert_eq!(result, Err(rejected));
+    assert_eq!(paths(&queue), vec!["/b"]);
+  }
+
+  #[tokio::test]
+  async fn test_network_error_enqueues_mutation() {
+    let client = ApiClient::new().with_retry(RetryPolicy::none());
+    let mut queue = OfflineQueue::new(MemoryStorage::default());
+
+    let result: Result<Option<()>, ClientError> = client
+      .send_or_enqueue(&mut queue, post("/a"), |_| async {
+        Err(ClientError::Network("offline".to_string()))
+      })
+      .await;
+    assert_eq!(result, Ok(None));
+
+    let result = client
+      .send_or_enqueue(&mut queue, post("/b"), |_| async { Ok(7) })
+      .await;
+    assert_eq!(result, Ok(Some(7)));
+    assert_eq!(paths(&queue), vec!["/a"]);
+  }
+
+  #[test]
+  fn test_idempotency_keys_are_unique() {
+    assert_ne!(post("/a").idempotency_key, post("/a").idempotency_key);
+  }
+
+  #[cfg(not(target_arch = "wasm32"))]
+  #[test]
+  fn test_file_storage_survives_reopen() {
+    let path = std::env::temp_dir().join(format!("clarity-queue-{}.json", idempotency_key()));
+    let mutation = post("/a");
+
+    let mut queue = OfflineQueue::new(FileStorage::new(&path));
+    assert_eq!(queue.pending(), Ok(Vec::new()));
+    assert_eq!(queue.enqueue(mutation.clone()), Ok(()));
+
+    let reopened = OfflineQueue::new(FileStorage::new(&path));
+    assert_eq!(reopened.pending(), Ok(vec![mutation]));
+    let _ = std::fs::remove_file(&path);
+  }
+}
@@ -6,33 +6,58 @@
 // This is a framework limitation, not our code using unwrap.
 #![allow(clippy::disallowed_methods)]
 
+use crate::route::{parse_query, Route};
 use dioxus::prelude::*;
+use std::collections::{HashMap, VecDeque};
 use std::result::Result;
 
+/// Most routes kept for `AppState::back`; older entries are dropped
+pub const MAX_HISTORY: usize = 50;
+
+/// A visited route together with its query parameters
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct HistoryEntry {
+  route: Route,
+  query: HashMap<String, String>,
+}
+
 /// Application state that manages shared data across components
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct AppState {
-  /// Current route path
-  pub current_route: String,
+  /// Current route
+  pub current_route: Route,
+  /// Query parameters of the current route, percent-decoded
+  pub query: HashMap<String, String>,
   /// Application error state, if any
   pub error: Option<AppError>,
+  /// Routes visited before the current one, oldest first
+  back_history: VecDeque<HistoryEntry>,
+  /// Routes left with `back`, most recently left last
+  forward_history: Vec<HistoryEntry>,
 }
 
 impl AppState {
   /// Create a new application state with default values
   #[must_use]
-  pub const fn new() -> Self {
+  pub fn new() -> Self {
     Self {
-      current_route: String::new(),
+      current_route: Route::Home,
+      query: HashMap::new(),
       error: None,
+      back_history: VecDeque::new(),
+      forward_history: Vec::new(),
     }
   }
 
   /// Navigate to a new route
   ///
+  /// A trailing `?key=value&...` query is parsed into `query`, replacing the
+  /// parameters of the previous route. The previous route is pushed onto the
+  /// history and the forward history is cleared, as in a browser.
+  ///
   /// # Errors
   /// Returns an error if the route path is invalid
-  pub fn navigate_to(&mut self, path: String) -> Result<(), AppError> {
+  pub fn navigate_to(&mut self, path: &str) -> Result<(), AppError> {
     if path.is_empty() {
       return Err(AppError::InvalidRoute(
         "Route path cannot be empty".to_string(),
@@ -45,10 +70,63 @@ impl AppState {
       )));
     }
 
-    self.current_route = path;
+    let (route, query) = path.split_once('?').unwrap_or((path, ""));
+    let previous = self.replace_current(HistoryEntry {
+      route: Route::parse(route),
+      query: parse_query(query),
+    });
+    self.back_history.push_back(previous);
+    if self.back_history.len() > MAX_HISTORY {
+      self.back_history.pop_front();
+    }
+    self.forward_history.clear();
     Ok(())
   }
 
+  /// Return to the previous route
+  ///
+  /// Returns `false`, leaving the state unchanged, if there is no previous route.
+  pub fn back(&mut self) -> bool {
+    let Some(entry) = self.back_history.pop_back() else {
+      return false;
+    };
+    let current = self.replace_current(entry);
+    self.forward_history.push(current);
+    true
+  }
+
+  /// Return to the route most recently left with `back`
+  ///
+  /// Returns `false`, leaving the state unchanged, if there is no such route.
+  pub fn forward(&mut self) -> bool {
+    let Some(entry) = self.forward_history.pop() else {
+      return false;
+    };
+    let current = self.replace_current(entry);
+    self.back_history.push_back(current);
+    true
+  }
+
+  /// Whether `back` would change the route
+  #[must_use]
+  pub fn can_go_back(&self) -> bool {
+    !self.back_history.is_empty()
+  }
+
+  /// Whether `forward` would change the route
+  #[must_use]
+  pub const fn can_go_forward(&self) -> bool {
+    !self.forward_history.is_empty()
+  }
+
+  /// Make `entry` the current route, returning the route it replaces
+  fn replace_current(&mut self, entry: HistoryEntry) -> HistoryEntry {
+    HistoryEntry {
+      route: std::mem::replace(&mut self.current_route, entry.route),
+      query: std::mem::replace(&mut self.query, entry.query),
+    }
+  }
+
   /// Set an application error
   pub fn set_error(&mut self, error: AppError) {
     self.error = Some(error);
@@ -101,18 +179,27 @@ pub fn App() -> Element {
       div { class: "app-container",
           h1 { "Clarity" }
           div { class: "content",
-              match state.read().current_route.as_str() {
-                  "" => rsx! {
+              match &state.read().current_route {
+                  Route::Home => rsx! {
                       HomePage {}
                   },
-                  "/about" => rsx! {
+                  Route::About => rsx! {
                       AboutPage {}
                   },
-                  "/dashboard" => rsx! {
+                  Route::Dashboard => rsx! {
                       DashboardPage {}
                   },
-                  path => rsx! {
-                      NotFoundPage { path: path.to_string() }
+                  Route::Settings => rsx! {
+                      SettingsPage {}
+                  },
+                  Route::Beads => rsx! {
+                      BeadsPage {}
+                  },
+                  Route::Analysis(id) => rsx! {
+                      AnalysisPage { id: id.clone() }
+                  },
+                  Route::NotFound(path) => rsx! {
+                      NotFoundPage { path: path.clone() }
                   },
               }
           }
@@ -176,6 +263,42 @@ fn DashboardPage() -> Element {
   }
 }
 
+/// Settings page component
+#[component]
+fn SettingsPage() -> Element {
+  rsx! {
+      div { class: "settings-page",
+          h2 { "Settings" }
+          p { "Configure your Clarity workspace" }
+          Link { to: "/", text: "Go Home" }
+      }
+  }
+}
+
+/// Beads page component
+#[component]
+fn BeadsPage() -> Element {
+  rsx! {
+      div { class: "beads-page",
+          h2 { "Beads" }
+          p { "Track the work items in your workspace" }
+          Link { to: "/dashboard", text: "Back to Dashboard" }
+      }
+  }
+}
+
+/// Analysis result page component
+#[component]
+fn AnalysisPage(id: String) -> Element {
+  rsx! {
+      div { class: "analysis-page",
+          h2 { "Analysis {id}" }
+          p { "Results of analysis '{id}'" }
+          Link { to: "/dashboard", text: "Back to Dashboard" }
+      }
+  }
+}
+
 /// 404 Not Found page component
 #[component]
 fn NotFoundPage(path: String) -> Element {
@@ -207,29 +330,29 @@ mod tests {
   #[test]
   fn test_app_state_new() {
     let state = AppState::new();
-    assert_eq!(state.current_route, "");
+    assert_eq!(state.current_route, Route::Home);
     assert!(state.error.is_none());
   }
 
   #[test]
   fn test_app_state_default() {
     let state = AppState::default();
-    assert_eq!(state.current_route, "");
+    assert_eq!(state.current_route, Route::Home);
     assert!(state.error.is_none());
   }
 
   #[test]
   fn test_navigate_to_valid_route() {
     let mut state = AppState::new();
-    let result = state.navigate_to("/about".to_string());
+    let result = state.navigate_to("/about");
     assert!(result.is_ok(), "Navigation should succeed for valid route");
-    assert_eq!(state.current_route, "/about");
+    assert_eq!(state.current_route, Route::About);
   }
 
   #[test]
   fn test_navigate_to_empty_route_fails() {
     let mut state = AppState::new();
-    let result = state.navigate_to("".to_string());
+    let result = state.navigate_to("");
     assert!(result.is_err(), "Navigation should fail for empty route");
     assert!(matches!(result, Err(AppError::InvalidRoute(_))));
   }
@@ -237,7 +360,7 @@ mod tests {
   #[test]
   fn test_navigate_to_route_without_leading_slash_fails() {
     let mut state = AppState::new();
-    let result = state.navigate_to("about".to_string());
+    let result = state.navigate_to("about");
     assert!(
       result.is_err(),
       "Navigation should fail for route without leading slash"
@@ -281,29 +404,29 @@ mod tests {
     let mut state = AppState::new();
 
     // First navigation
-    let result = state.navigate_to("/about".to_string());
+    let result = state.navigate_to("/about");
     assert!(result.is_ok());
-    assert_eq!(state.current_route, "/about");
+    assert_eq!(state.current_route, Route::About);
 
     // Second navigation
-    let result = state.navigate_to("/".to_string());
+    let result = state.navigate_to("/");
     assert!(result.is_ok());
-    assert_eq!(state.current_route, "/");
+    assert_eq!(state.current_route, Route::Home);
 
     // Invalid navigation
-    let result = state.navigate_to("invalid".to_string());
+    let result = state.navigate_to("invalid");
     assert!(result.is_err());
     // State should remain unchanged after failed navigation
-    assert_eq!(state.current_route, "/");
+    assert_eq!(state.current_route, Route::Home);
   }
 
   // Martin Fowler Test Suite: Dashboard UI
   #[test]
   fn test_navigate_to_dashboard_shows_dashboard_component() {
     let mut state = AppState::new();
-    let result = state.navigate_to("/dashboard".to_string());
+    let result = state.navigate_to("/dashboard");
     assert!(result.is_ok(), "Navigation to /dashboard should succeed");
-    assert_eq!(state.current_route, "/dashboard");
+    assert_eq!(state.current_route, Route::Dashboard);
     assert!(state.error.is_none(), "No errors should be present");
   }
 
@@ -319,15 +442,15 @@ mod tests {
   #[test]
   fn test_dashboard_accessible_from_home_page() {
     let mut state = AppState::new();
-    let result = state.navigate_to("/".to_string());
+    let result = state.navigate_to("/");
     assert!(result.is_ok(), "Should be able to navigate to home");
-    assert_eq!(state.current_route, "/");
-    let result = state.navigate_to("/dashboard".to_string());
+    assert_eq!(state.current_route, Route::Home);
+    let result = state.navigate_to("/dashboard");
     assert!(result.is_ok(), "Should be able to navigate to dashboard");
-    assert_eq!(state.current_route, "/dashboard");
-    let result = state.navigate_to("/".to_string());
+    assert_eq!(state.current_route, Route::Dashboard);
+    let result = state.navigate_to("/");
     assert!(result.is_ok(), "Should be able to navigate back to home");
-    assert_eq!(state.current_route, "/");
+    assert_eq!(state.current_route, Route::Home);
   }
 
   #[test]
@@ -338,7 +461,7 @@ mod tests {
     ));
     assert!(state.error.is_some(), "Error should be captured in state");
     assert!(matches!(state.error, Some(AppError::ComponentInit(_))));
-    let result = state.navigate_to("/about".to_string());
+    let result = state.navigate_to("/about");
     assert!(
       result.is_ok(),
       "App should continue functioning despite error"
@@ -348,24 +471,26 @@ mod tests {
   #[test]
   fn test_dashboard_rejects_invalid_navigation() {
     let mut state = AppState::new();
-    let result = state.navigate_to("/dashboard".to_string());
+    let result = state.navigate_to("/dashboard");
     assert!(result.is_ok());
-    assert_eq!(state.current_route, "/dashboard");
-    let result = state.navigate_to(String::new());
+    assert_eq!(state.current_route, Route::Dashboard);
+    let result = state.navigate_to("");
     assert!(result.is_err(), "Navigation should fail for empty route");
     assert!(matches!(result, Err(AppError::InvalidRoute(_))));
     assert_eq!(
-      state.current_route, "/dashboard",
+      state.current_route,
+      Route::Dashboard,
       "Current route should remain unchanged"
     );
-    let result = state.navigate_to("invalid".to_string());
+    let result = state.navigate_to("invalid");
     assert!(
       result.is_err(),
       "Navigation should fail for route without leading slash"
     );
     assert!(matches!(result, Err(AppError::InvalidRoute(_))));
     assert_eq!(
-      state.current_route, "/dashboard",
+      state.current_route,
+      Route::Dashboard,
       "Current route should remain unchanged"
     );
   }
@@ -373,7 +498,76 @@ mod tests {
   #[test]
   fn test_dashboard_responsive_classes_present() {
     let state = AppState::new();
-    assert_eq!(state.current_route, "");
+    assert_eq!(state.current_route, Route::Home);
     assert!(state.error.is_none());
   }
+
+  #[test]
+  fn test_navigate_to_with_query() {
+    let mut state = AppState::new();
+    let result = state.navigate_to("/analysis/42?tab=logs");
+    assert!(result.is_ok());
+    assert_eq!(state.current_route, Route::Analysis("42".to_string()));
+    assert_eq!(state.query.get("tab").map(String::as_str), Some("logs"));
+
+    let result = state.navigate_to("/about");
+    assert!(result.is_ok());
+    assert!(state.query.is_empty(), "Query should not outlive its route");
+  }
+
+  #[test]
+  fn test_back_and_forward() {
+    let mut state = AppState::new();
+    assert!(!state.can_go_back());
+    assert!(!state.back(), "Back should do nothing without history");
+
+    assert!(state.navigate_to("/about").is_ok());
+    assert!(state.navigate_to("/analysis/42?tab=logs").is_ok());
+
+    assert!(state.back());
+    assert_eq!(state.current_route, Route::About);
+    assert!(state.query.is_empty());
+    assert!(state.can_go_forward());
+
+    assert!(state.forward());
+    assert_eq!(state.current_route, Route::Analysis("42".to_string()));
+    assert_eq!(state.query.get("tab").map(String::as_str), Some("logs"));
+    assert!(!state.can_go_forward());
+
+    assert!(state.back());
+    assert!(state.navigate_to("/dashboard").is_ok());
+    assert!(
+      !state.can_go_forward(),
+      "Navigating should truncate forward history"
+    );
+    assert!(state.back());
+    assert_eq!(state.current_route, Route::About);
+    assert!(state.back());
+    assert_eq!(state.current_route, Route::Home);
+    assert!(!state.can_go_back());
+  }
+
+  #[test]
+  fn test_history_is_bounded() {
+    let mut state = AppState::new();
+    for i in 0..MAX_HISTORY + 10 {
+      assert!(state.navigate_to(&format!("/analysis/{i}")).is_ok());
+    }
+
+    let mut steps = 0;
+    while state.back() {
+      steps += 1;
+    }
+    assert_eq!(steps, MAX_HISTORY);
+    assert_eq!(state.current_route, Route::Analysis("9".to_string()));
+  }
+
+  #[test]
+  fn test_failed_navigation_keeps_history() {
+    let mut state = AppState::new();
+    assert!(state.navigate_to("/about").is_ok());
+    assert!(state.back());
+    assert!(state.navigate_to("invalid").is_err());
+    assert!(state.can_go_forward());
+  }
 }
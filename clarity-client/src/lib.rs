@@ -26,6 +26,9 @@
 //! This is the web frontend for Clarity, built with Dioxus.
 //! It provides a modern, reactive UI for managing interviews and documentation.
 
+pub mod api;
 pub mod app;
+pub mod route;
 
 pub use app::{App, AppError, AppState};
+pub use route::Route;
@@ -4,6 +4,10 @@
 // It launches the Dioxus app with hot reload enabled for development.
 
 fn main() {
+  // Log natively through the shared subscriber; `RUST_LOG` sets the level
+  #[cfg(not(target_arch = "wasm32"))]
+  clarity_core::init_tracing();
+
   // Launch the Dioxus application
   // Note: Hot reload is automatically enabled in debug mode by Dioxus
   dioxus::launch(clarity_client::App);
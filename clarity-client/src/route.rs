@@ -0,0 +1,197 @@
+//! Typed application routes
+//!
+//! Paths are parsed into a `Route` once, so pages match on variants instead of
+//! string literals and unknown paths are explicit. Query strings are parsed
+//! separately by `parse_query`.
+
+use std::collections::HashMap;
+
+/// A page of the application
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Route {
+  /// `/`
+  #[default]
+  Home,
+  /// `/about`
+  About,
+  /// `/dashboard`
+  Dashboard,
+  /// `/settings`
+  Settings,
+  /// `/beads`
+  Beads,
+  /// `/analysis/{id}`
+  Analysis(String),
+  /// Any path that matches no page, kept as given
+  NotFound(String),
+}
+
+impl Route {
+  /// Prefix of analysis result paths
+  const ANALYSIS_PREFIX: &'static str = "/analysis/";
+
+  /// Parse a path into a route
+  ///
+  /// The empty path is treated as `/`. An analysis path needs a non-empty id
+  /// with no further segments; anything unrecognised becomes `NotFound`.
+  #[must_use]
+  pub fn parse(path: &str) -> Self {
+    match path {
+      "" | "/" => Self::Home,
+      "/about" => Self::About,
+      "/dashboard" => Self::Dashboard,
+      "/settings" => Self::Settings,
+      "/beads" => Self::Beads,
+      _ => path
+        .strip_prefix(Self::ANALYSIS_PREFIX)
+        .filter(|id| !id.is_empty() && !id.contains('/'))
+        .map_or_else(
+          || Self::NotFound(path.to_string()),
+          |id| Self::Analysis(id.to_string()),
+        ),
+    }
+  }
+
+  /// The path that parses back to this route
+  #[must_use]
+  pub fn to_path(&self) -> String {
+    match self {
+      Self::Home => "/".to_string(),
+      Self::About => "/about".to_string(),
+      Self::Dashboard => "/dashboard".to_string(),
+      Self::Settings => "/settings".to_string(),
+      Self::Beads => "/beads".to_string(),
+      Self::Analysis(id) => format!("{}{id}", Self::ANALYSIS_PREFIX),
+      Self::NotFound(path) => path.clone(),
+    }
+  }
+}
+
+impl std::fmt::Display for Route {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.to_path())
+  }
+}
+
+/// Parse a query string (without the leading `?`) into its parameters
+///
+/// Keys and values are percent-decoded and `+` is read as a space. A key
+/// without `=` maps to an empty value; when a key repeats, the last value wins.
+#[must_use]
+pub fn parse_query(query: &str) -> HashMap<String, String> {
+  query
+    .split('&')
+    .filter(|pair| !pair.is_empty())
+    .map(|pair| {
+      let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+      (percent_decode(key), percent_decode(value))
+    })
+    .collect()
+}
+
+/// Decode `%XX` escapes and `+` in a query component
+///
+/// Malformed escapes are kept as written and invalid UTF-8 is replaced.
+fn percent_decode(input: &str) -> String {
+  let bytes = input.as_bytes();
+  let mut decoded = Vec::with_capacity(bytes.len());
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'+' => decoded.push(b' '),
+      b'%' => {
+        let escaped = input
+          .get(i + 1..i + 3)
+          .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        if let Some(byte) = escaped {
+          decoded.push(byte);
+          i += 2;
+        } else {
+          decoded.push(b'%');
+        }
+      }
+      byte => decoded.push(byte),
+    }
+    i += 1;
+  }
+  String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_static_routes() {
+    assert_eq!(Route::parse(""), Route::Home);
+    assert_eq!(Route::parse("/"), Route::Home);
+    assert_eq!(Route::parse("/about"), Route::About);
+    assert_eq!(Route::parse("/dashboard"), Route::Dashboard);
+    assert_eq!(Route::parse("/settings"), Route::Settings);
+    assert_eq!(Route::parse("/beads"), Route::Beads);
+  }
+
+  #[test]
+  fn test_parse_analysis_id() {
+    assert_eq!(
+      Route::parse("/analysis/42"),
+      Route::Analysis("42".to_string())
+    );
+    assert_eq!(
+      Route::parse("/analysis/"),
+      Route::NotFound("/analysis/".to_string())
+    );
+    assert_eq!(
+      Route::parse("/analysis/42/extra"),
+      Route::NotFound("/analysis/42/extra".to_string())
+    );
+  }
+
+  #[test]
+  fn test_parse_unknown_path() {
+    assert_eq!(
+      Route::parse("/contact"),
+      Route::NotFound("/contact".to_string())
+    );
+  }
+
+  #[test]
+  fn test_round_trip() {
+    let routes = [
+      Route::Home,
+      Route::About,
+      Route::Dashboard,
+      Route::Settings,
+      Route::Beads,
+      Route::Analysis("42".to_string()),
+      Route::NotFound("/missing".to_string()),
+    ];
+    for route in routes {
+      assert_eq!(Route::parse(&route.to_path()), route);
+    }
+  }
+
+  #[test]
+  fn test_parse_empty_query() {
+    assert!(parse_query("").is_empty());
+    assert!(parse_query("&&").is_empty());
+  }
+
+  #[test]
+  fn test_parse_query_multiple_params() {
+    let query = parse_query("tab=logs&page=2&verbose&tab=summary");
+    assert_eq!(query.len(), 3);
+    assert_eq!(query.get("tab").map(String::as_str), Some("summary"));
+    assert_eq!(query.get("page").map(String::as_str), Some("2"));
+    assert_eq!(query.get("verbose").map(String::as_str), Some(""));
+  }
+
+  #[test]
+  fn test_parse_query_decodes_spaces() {
+    let query = parse_query("q=hello%20world&name=a+b&pct=100%&bad=%zz");
+    assert_eq!(query.get("q").map(String::as_str), Some("hello world"));
+    assert_eq!(query.get("name").map(String::as_str), Some("a b"));
+    assert_eq!(query.get("pct").map(String::as_str), Some("100%"));
+    assert_eq!(query.get("bad").map(String::as_str), Some("%zz"));
+  }
+}
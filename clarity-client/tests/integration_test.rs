@@ -4,20 +4,21 @@
 //! error handling, and application state management.
 
 use clarity_client::app::{AppError, AppState};
+use clarity_client::Route;
 
 #[test]
 fn test_app_state_navigation_flow() {
   let mut state = AppState::new();
 
   // Test complete navigation flow
-  assert!(state.navigate_to("/about".to_string()).is_ok());
-  assert_eq!(state.current_route, "/about");
+  assert!(state.navigate_to("/about").is_ok());
+  assert_eq!(state.current_route, Route::About);
 
-  assert!(state.navigate_to("/contact".to_string()).is_ok());
-  assert_eq!(state.current_route, "/contact");
+  assert!(state.navigate_to("/contact").is_ok());
+  assert_eq!(state.current_route.to_path(), "/contact");
 
-  assert!(state.navigate_to("/".to_string()).is_ok());
-  assert_eq!(state.current_route, "/");
+  assert!(state.navigate_to("/").is_ok());
+  assert_eq!(state.current_route, Route::Home);
 }
 
 #[test]
@@ -35,7 +36,7 @@ fn test_app_state_error_handling_flow() {
   assert!(state.error.is_none());
 
   // Verify state is still functional after error
-  assert!(state.navigate_to("/test".to_string()).is_ok());
+  assert!(state.navigate_to("/test").is_ok());
 }
 
 #[test]
@@ -43,15 +44,15 @@ fn test_app_state_error_preserves_on_navigation_failure() {
   let mut state = AppState::new();
 
   // Set initial valid state
-  assert!(state.navigate_to("/valid".to_string()).is_ok());
-  assert_eq!(state.current_route, "/valid");
+  assert!(state.navigate_to("/valid").is_ok());
+  assert_eq!(state.current_route.to_path(), "/valid");
 
   // Attempt invalid navigation
-  let result = state.navigate_to("invalid-path".to_string());
+  let result = state.navigate_to("invalid-path");
   assert!(result.is_err());
 
   // Verify state is unchanged after failed navigation
-  assert_eq!(state.current_route, "/valid");
+  assert_eq!(state.current_route.to_path(), "/valid");
 }
 
 #[test]
@@ -89,7 +90,7 @@ fn test_app_error_equality() {
 #[test]
 fn test_app_state_clone() {
   let mut state = AppState::new();
-  assert!(state.navigate_to("/test".to_string()).is_ok());
+  assert!(state.navigate_to("/test").is_ok());
 
   let cloned = state.clone();
   assert_eq!(state.current_route, cloned.current_route);
@@ -97,9 +98,9 @@ fn test_app_state_clone() {
 
   // Modify clone doesn't affect original
   let mut cloned = cloned;
-  assert!(cloned.navigate_to("/other".to_string()).is_ok());
-  assert_eq!(state.current_route, "/test");
-  assert_eq!(cloned.current_route, "/other");
+  assert!(cloned.navigate_to("/other").is_ok());
+  assert_eq!(state.current_route.to_path(), "/test");
+  assert_eq!(cloned.current_route.to_path(), "/other");
 }
 
 #[test]
@@ -119,7 +120,7 @@ fn test_route_validation_various_cases() {
   ];
 
   for route in valid_routes {
-    let result = state.navigate_to(route.to_string());
+    let result = state.navigate_to(route);
     assert!(
       result.is_ok(),
       "Route '{}' should be valid, got error: {:?}",
@@ -132,7 +133,7 @@ fn test_route_validation_various_cases() {
   let invalid_routes = vec!["", "no-leading-slash", " ", "\t", "\n"];
 
   for route in invalid_routes {
-    let result = state.navigate_to(route.to_string());
+    let result = state.navigate_to(route);
     assert!(
       result.is_err(),
       "Route '{}' should be invalid, but got success",
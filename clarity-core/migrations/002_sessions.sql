@@ -0,0 +1,17 @@
+-- Sessions table
+-- Interview sessions link to the interview they run; other kinds must not.
+
+CREATE TABLE sessions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    kind VARCHAR(32) NOT NULL CHECK (kind IN ('interview', 'analysis', 'planning')),
+    state VARCHAR(32) NOT NULL DEFAULT 'created'
+        CHECK (state IN ('created', 'in_progress', 'completed', 'failed', 'cancelled')),
+    interview_id UUID REFERENCES interviews(id) ON DELETE CASCADE,
+    title VARCHAR(500),
+    description TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT sessions_interview_link CHECK ((kind = 'interview') = (interview_id IS NOT NULL))
+);
+
+CREATE INDEX idx_sessions_interview_id ON sessions(interview_id);
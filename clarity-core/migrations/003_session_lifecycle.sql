@@ -0,0 +1,5 @@
+-- Session lifecycle columns
+-- When a session was soft-deleted, and what a completed session produced.
+
+ALTER TABLE sessions ADD COLUMN deleted_at TIMESTAMPTZ;
+ALTER TABLE sessions ADD COLUMN result JSONB;
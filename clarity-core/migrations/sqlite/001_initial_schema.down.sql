@@ -0,0 +1,9 @@
+-- Revert the initial SQLite schema
+
+DROP INDEX IF EXISTS idx_users_email;
+DROP INDEX IF EXISTS idx_beads_created_by;
+DROP INDEX IF EXISTS idx_beads_priority;
+DROP INDEX IF EXISTS idx_beads_type;
+DROP INDEX IF EXISTS idx_beads_status;
+DROP TABLE IF EXISTS beads;
+DROP TABLE IF EXISTS users;
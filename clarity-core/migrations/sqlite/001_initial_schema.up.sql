@@ -0,0 +1,33 @@
+-- Initial schema for the embedded SQLite database
+-- Mirrors the users and beads tables of the PostgreSQL schema. SQLite has no
+-- enum or UUID types, so those columns are TEXT with CHECK constraints and
+-- timestamps are stored as RFC 3339 strings.
+
+CREATE TABLE users (
+    id TEXT PRIMARY KEY NOT NULL,
+    email TEXT UNIQUE NOT NULL,
+    password_hash TEXT NOT NULL,
+    role TEXT NOT NULL DEFAULT 'user' CHECK (role IN ('admin', 'user')),
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE TABLE beads (
+    id TEXT PRIMARY KEY NOT NULL,
+    title TEXT NOT NULL CHECK (length(title) <= 500),
+    description TEXT,
+    status TEXT NOT NULL DEFAULT 'open'
+        CHECK (status IN ('open', 'in_progress', 'blocked', 'deferred', 'closed')),
+    priority INTEGER NOT NULL DEFAULT 2 CHECK (priority BETWEEN 1 AND 3),
+    bead_type TEXT NOT NULL DEFAULT 'feature'
+        CHECK (bead_type IN ('feature', 'bugfix', 'refactor', 'test', 'docs')),
+    created_by TEXT REFERENCES users(id) ON DELETE SET NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_beads_status ON beads(status);
+CREATE INDEX idx_beads_type ON beads(bead_type);
+CREATE INDEX idx_beads_priority ON beads(priority);
+CREATE INDEX idx_beads_created_by ON beads(created_by);
+CREATE INDEX idx_users_email ON users(email);
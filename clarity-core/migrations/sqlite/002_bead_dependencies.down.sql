@@ -0,0 +1,4 @@
+-- Revert bead dependencies
+
+DROP INDEX IF EXISTS idx_bead_dependencies_depends_on;
+DROP TABLE IF EXISTS bead_dependencies;
@@ -0,0 +1,12 @@
+-- Dependencies between beads
+-- A bead is blocked until every bead it depends on is closed. Rows go away
+-- with either bead.
+
+CREATE TABLE bead_dependencies (
+    bead_id TEXT NOT NULL REFERENCES beads(id) ON DELETE CASCADE,
+    depends_on TEXT NOT NULL REFERENCES beads(id) ON DELETE CASCADE,
+    PRIMARY KEY (bead_id, depends_on),
+    CHECK (bead_id <> depends_on)
+);
+
+CREATE INDEX idx_bead_dependencies_depends_on ON bead_dependencies(depends_on);
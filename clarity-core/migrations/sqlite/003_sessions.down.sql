@@ -0,0 +1,4 @@
+-- Revert sessions
+
+DROP INDEX IF EXISTS idx_sessions_interview_id;
+DROP TABLE IF EXISTS sessions;
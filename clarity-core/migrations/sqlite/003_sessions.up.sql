@@ -0,0 +1,22 @@
+-- Sessions
+-- Mirrors the PostgreSQL sessions table. Interview sessions link to the
+-- interview they run; other kinds must not. Interviews are not stored in
+-- SQLite, so `interview_id` is not a foreign key. A completed session's
+-- result is stored as JSON.
+
+CREATE TABLE sessions (
+    id TEXT PRIMARY KEY NOT NULL,
+    kind TEXT NOT NULL CHECK (kind IN ('interview', 'analysis', 'planning')),
+    state TEXT NOT NULL DEFAULT 'created'
+        CHECK (state IN ('created', 'in_progress', 'completed', 'failed', 'cancelled')),
+    interview_id TEXT,
+    title TEXT,
+    description TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    deleted_at TEXT,
+    result TEXT,
+    CHECK ((kind = 'interview') = (interview_id IS NOT NULL))
+);
+
+CREATE INDEX idx_sessions_interview_id ON sessions(interview_id);
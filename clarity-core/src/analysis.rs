@@ -0,0 +1,263 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Analysis results for Clarity
+//!
+//! An analysis session reviews a spec and produces an [`AnalysisResult`]:
+//! a list of findings, each with a severity and an optional location, plus
+//! an overall quality score.
+
+use crate::quality::{QualityScore, Severity};
+use crate::types::is_valid_uuid;
+pub use crate::types::time::Timestamp;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use thiserror::Error;
+
+/// Unique identifier for an analysis result
+///
+/// Serialized as its UUID string; deserializing an invalid UUID fails.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct AnalysisId(String);
+
+impl AnalysisId {
+  /// Creates a new `AnalysisId` from a string
+  ///
+  /// # Errors
+  ///
+  /// Returns `AnalysisError::InvalidIdFormat` if the string is not a valid UUID
+  pub fn new(id: String) -> Result<Self, AnalysisError> {
+    if is_valid_uuid(&id) {
+      Ok(Self(id))
+    } else {
+      Err(AnalysisError::InvalidIdFormat(id))
+    }
+  }
+
+  /// Creates a fresh random (v4) `AnalysisId`
+  #[must_use]
+  pub fn generate() -> Self {
+    Self(uuid::Uuid::new_v4().to_string())
+  }
+
+  /// Get the underlying UUID string
+  #[must_use]
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+}
+
+impl Display for AnalysisId {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+impl TryFrom<String> for AnalysisId {
+  type Error = AnalysisError;
+
+  fn try_from(id: String) -> Result<Self, Self::Error> {
+    Self::new(id)
+  }
+}
+
+impl From<AnalysisId> for String {
+  fn from(id: AnalysisId) -> Self {
+    id.0
+  }
+}
+
+/// One issue an analysis found in a spec
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Finding {
+  /// How serious the issue is
+  pub severity: Severity,
+  /// Human-readable description
+  pub message: String,
+  /// Where in the spec the issue is, e.g. `paths./users.get`
+  pub location: Option<String>,
+}
+
+impl Finding {
+  /// Create a finding with no location
+  pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+    Self {
+      severity,
+      message: message.into(),
+      location: None,
+    }
+  }
+
+  /// Set where in the spec the issue is
+  #[must_use]
+  pub fn at(mut self, location: impl Into<String>) -> Self {
+    self.location = Some(location.into());
+    self
+  }
+}
+
+/// The output of an analysis session
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnalysisResult {
+  /// Unique identifier for this result
+  pub id: AnalysisId,
+  /// Spec that was analysed
+  pub spec_name: String,
+  /// Issues found, in the order they were reported
+  pub findings: Vec<Finding>,
+  /// Overall quality of the spec
+  pub score: QualityScore,
+  /// When the analysis finished
+  pub generated_at: Timestamp,
+}
+
+impl AnalysisResult {
+  /// Create a result with no findings
+  pub fn new(
+    id: AnalysisId,
+    spec_name: impl Into<String>,
+    score: QualityScore,
+    generated_at: Timestamp,
+  ) -> Self {
+    Self {
+      id,
+      spec_name: spec_name.into(),
+      findings: Vec::new(),
+      score,
+      generated_at,
+    }
+  }
+
+  /// Return a new result with `finding` appended
+  #[must_use]
+  pub fn with_finding(mut self, finding: Finding) -> Self {
+    self.findings.push(finding);
+    self
+  }
+
+  /// Number of findings at each severity; severities with none are left out
+  #[must_use]
+  pub fn severity_counts(&self) -> HashMap<Severity, usize> {
+    self
+      .findings
+      .iter()
+      .fold(HashMap::new(), |mut counts, finding| {
+        *counts.entry(finding.severity).or_insert(0) += 1;
+        counts
+      })
+  }
+
+  /// Whether any finding is an error
+  #[must_use]
+  pub fn has_errors(&self) -> bool {
+    self.findings.iter().any(|f| f.severity == Severity::Error)
+  }
+
+  /// The most serious severity among the findings, or `None` if there are none
+  #[must_use]
+  pub fn worst_severity(&self) -> Option<Severity> {
+    self.findings.iter().map(|f| f.severity).max()
+  }
+}
+
+/// Errors that can occur when working with analysis results
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum AnalysisError {
+  /// Invalid analysis ID format (not a valid UUID)
+  #[error("invalid analysis ID format: {0}")]
+  InvalidIdFormat(String),
+
+  /// No analysis result exists with the given id
+  #[error("analysis not found: {0}")]
+  NotFound(String),
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const ID: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+  #[test]
+  fn test_analysis_id_rejects_invalid_uuid() {
+    assert_eq!(
+      AnalysisId::new("not-a-uuid".to_string()),
+      Err(AnalysisError::InvalidIdFormat("not-a-uuid".to_string()))
+    );
+    assert!(is_valid_uuid(AnalysisId::generate().as_str()));
+  }
+
+  #[allow(clippy::unwrap_used)]
+  #[test]
+  fn test_analysis_result_serde_round_trip() {
+    let result = AnalysisResult::new(
+      AnalysisId::new(ID.to_string()).unwrap(),
+      "petstore",
+      QualityScore::new(72).unwrap(),
+      Timestamp::from_secs(1_700_000_000),
+    )
+    .with_finding(Finding::new(Severity::Error, "missing response schema").at("paths./pets.get"))
+    .with_finding(Finding::new(Severity::Info, "no examples given"));
+
+    let json = serde_json::to_value(&result).unwrap();
+    assert_eq!(json["id"], ID);
+    assert_eq!(json["score"], 72);
+    assert_eq!(json["generated_at"], 1_700_000_000);
+    assert_eq!(json["findings"][0]["severity"], "error");
+    assert_eq!(json["findings"][0]["location"], "paths./pets.get");
+    assert_eq!(json["findings"][1]["location"], serde_json::Value::Null);
+
+    let parsed: AnalysisResult = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed, result);
+  }
+
+  #[allow(clippy::unwrap_used)]
+  #[test]
+  fn test_severity_summary() {
+    let result = AnalysisResult::new(
+      AnalysisId::generate(),
+      "petstore",
+      QualityScore::new(40).unwrap(),
+      Timestamp::from_secs(0),
+    );
+    assert_eq!(result.worst_severity(), None);
+    assert!(!result.has_errors());
+
+    let result = [
+      Severity::Warning,
+      Severity::Error,
+      Severity::Info,
+      Severity::Warning,
+    ]
+    .into_iter()
+    .fold(result, |result, severity| {
+      result.with_finding(Finding::new(severity, "finding"))
+    });
+
+    let counts = result.severity_counts();
+    assert_eq!(counts.get(&Severity::Error), Some(&1));
+    assert_eq!(counts.get(&Severity::Warning), Some(&2));
+    assert_eq!(counts.get(&Severity::Info), Some(&1));
+    assert!(result.has_errors());
+    assert_eq!(result.worst_severity(), Some(Severity::Error));
+  }
+
+  #[test]
+  fn test_analysis_result_rejects_invalid_id_when_deserializing() {
+    let json = serde_json::json!({
+      "id": "not-a-uuid",
+      "spec_name": "petstore",
+      "findings": [],
+      "score": 50,
+      "generated_at": 0,
+    });
+
+    assert!(serde_json::from_value::<AnalysisResult>(json).is_err());
+  }
+}
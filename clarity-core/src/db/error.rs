@@ -5,6 +5,10 @@
 #![warn(clippy::nursery)]
 #![forbid(unsafe_code)]
 
+use sqlx::error::DatabaseError;
+use sqlx::postgres::PgDatabaseError;
+use sqlx::sqlite::SqliteError;
+
 /// Database operation errors
 #[derive(Debug, thiserror::Error)]
 pub enum DbError {
@@ -54,7 +58,215 @@ impl DbError {
   pub fn duplicate(msg: impl Into<String>) -> Self {
     Self::Duplicate(msg.into())
   }
+
+  /// Whether the requested record does not exist
+  #[must_use]
+  pub const fn is_not_found(&self) -> bool {
+    matches!(
+      self,
+      Self::NotFound { .. } | Self::Connection(sqlx::Error::RowNotFound)
+    )
+  }
+
+  /// Whether the write clashes with an existing record, e.g. a unique constraint
+  #[must_use]
+  pub fn is_conflict(&self) -> bool {
+    match self {
+      Self::Duplicate(_) => true,
+      Self::Connection(e) => e
+        .as_database_error()
+        .is_some_and(DatabaseError::is_unique_violation),
+      _ => false,
+    }
+  }
+
+  /// Whether the operation may succeed if retried later
+  ///
+  /// True for lost connections, pool timeouts, a busy or locked `SQLite`
+  /// database, and Postgres serialization failures, deadlocks, shutdowns
+  /// and connection errors.
+  #[must_use]
+  pub fn is_transient(&self) -> bool {
+    let Self::Connection(e) = self else {
+      return false;
+    };
+    match e {
+      sqlx::Error::Io(_)
+      | sqlx::Error::PoolTimedOut
+      | sqlx::Error::PoolClosed
+      | sqlx::Error::WorkerCrashed => true,
+      sqlx::Error::Database(db) => match (
+        db.try_downcast_ref::<SqliteError>(),
+        db.try_downcast_ref::<PgDatabaseError>(),
+      ) {
+        (Some(sqlite), _) => sqlite
+          .code()
+          .and_then(|code| code.parse::<i32>().ok())
+          .is_some_and(is_transient_sqlite_code),
+        (None, Some(postgres)) => is_transient_sqlstate(postgres.code()),
+        (None, None) => false,
+      },
+      _ => false,
+    }
+  }
+}
+
+/// `SQLite` result code for a database file locked by another connection
+const SQLITE_BUSY: i32 = 5;
+
+/// `SQLite` result code for a table locked within the same connection
+const SQLITE_LOCKED: i32 = 6;
+
+/// Whether an `SQLite` result code means the database was busy or locked
+const fn is_transient_sqlite_code(code: i32) -> bool {
+  // The primary result code is the low byte of an extended one
+  matches!(code & 0xff, SQLITE_BUSY | SQLITE_LOCKED)
+}
+
+/// Whether a Postgres SQLSTATE is worth retrying
+///
+/// Covers connection exceptions (class 08), serialization failures,
+/// deadlocks, too many connections and server shutdowns.
+fn is_transient_sqlstate(code: &str) -> bool {
+  code.starts_with("08")
+    || matches!(
+      code,
+      "40001" | "40P01" | "53300" | "57P01" | "57P02" | "57P03"
+    )
 }
 
 /// Result type for database operations
 pub type DbResult<T> = Result<T, DbError>;
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_not_found_predicate() {
+    assert!(DbError::not_found("bead", "42").is_not_found());
+    assert!(DbError::Connection(sqlx::Error::RowNotFound).is_not_found());
+    assert!(!DbError::validation("bad").is_not_found());
+  }
+
+  #[allow(clippy::unwrap_used)]
+  #[tokio::test]
+  async fn test_unique_violation_is_conflict() {
+    let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+    sqlx::query("CREATE TABLE t (name TEXT UNIQUE)")
+      .execute(&pool)
+      .await
+      .unwrap();
+    let insert = || sqlx::query("INSERT INTO t (name) VALUES ('a')").execute(&pool);
+    insert().await.unwrap();
+
+    let error = DbError::from(insert().await.unwrap_err());
+    assert!(error.is_conflict());
+    assert!(!error.is_transient());
+    assert!(DbError::duplicate("a").is_conflict());
+    assert!(!DbError::Connection(sqlx::Error::PoolTimedOut).is_conflict());
+  }
+
+  #[test]
+  fn test_transient_predicate() {
+    assert!(DbError::Connection(sqlx::Error::PoolTimedOut).is_transient());
+    assert!(DbError::Connection(sqlx::Error::PoolClosed).is_transient());
+    assert!(!DbError::Connection(sqlx::Error::RowNotFound).is_transient());
+    assert!(!DbError::Migration("bad".to_string()).is_transient());
+  }
+
+  #[test]
+  fn test_postgres_sqlstates() {
+    for code in ["40001", "40P01", "57P01", "08006", "53300"] {
+      assert!(is_transient_sqlstate(code), "{code} should be transient");
+    }
+    // insufficient_privilege; its numeric value has SQLITE_BUSY as low byte
+    assert!(!is_transient_sqlstate("42501"));
+    assert!(!is_transient_sqlstate("23505"));
+  }
+
+  /// A database error from neither backend
+  #[derive(Debug)]
+  struct OtherDbError(&'static str);
+
+  impl std::fmt::Display for OtherDbError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      write!(f, "error {}", self.0)
+    }
+  }
+
+  impl std::error::Error for OtherDbError {}
+
+  impl DatabaseError for OtherDbError {
+    fn message(&self) -> &str {
+      self.0
+    }
+
+    fn code(&self) -> Option<std::borrow::Cow<'_, str>> {
+      Some(self.0.into())
+    }
+
+    fn as_error(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+      self
+    }
+
+    fn as_error_mut(&mut self) -> &mut (dyn std::error::Error + Send + Sync + 'static) {
+      self
+    }
+
+    fn into_error(self: Box<Self>) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+      self
+    }
+
+    fn kind(&self) -> sqlx::error::ErrorKind {
+      sqlx::error::ErrorKind::Other
+    }
+  }
+
+  #[test]
+  fn test_sqlite_codes_only_apply_to_sqlite_errors() {
+    assert!(is_transient_sqlite_code(SQLITE_BUSY));
+    // SQLITE_BUSY_SNAPSHOT, an extended busy code
+    assert!(is_transient_sqlite_code(517));
+    assert!(!is_transient_sqlite_code(19));
+
+    let error = DbError::Connection(sqlx::Error::Database(Box::new(OtherDbError("42501"))));
+    assert!(!error.is_transient());
+  }
+
+  #[allow(clippy::unwrap_used)]
+  #[tokio::test]
+  async fn test_busy_sqlite_database_is_transient() {
+    use sqlx::sqlite::SqliteConnectOptions;
+    use sqlx::{ConnectOptions, Connection};
+    use std::time::Duration;
+
+    let path = std::env::temp_dir().join(format!("clarity-busy-{}.db", uuid::Uuid::new_v4()));
+    let options = SqliteConnectOptions::new()
+      .filename(&path)
+      .create_if_missing(true)
+      .busy_timeout(Duration::ZERO);
+    let mut holder = options.connect().await.unwrap();
+    let mut other = options.connect().await.unwrap();
+    sqlx::query("CREATE TABLE t (name TEXT)")
+      .execute(&mut holder)
+      .await
+      .unwrap();
+    sqlx::query("BEGIN EXCLUSIVE")
+      .execute(&mut holder)
+      .await
+      .unwrap();
+
+    let error = DbError::from(
+      sqlx::query("INSERT INTO t (name) VALUES ('a')")
+        .execute(&mut other)
+        .await
+        .unwrap_err(),
+    );
+    assert!(error.is_transient(), "{error}");
+
+    holder.close().await.unwrap();
+    other.close().await.unwrap();
+    let _ = std::fs::remove_file(&path);
+  }
+}
@@ -8,19 +8,144 @@
 //! Database migrations
 
 use crate::db::error::{DbError, DbResult};
-use sqlx::PgPool;
+use sqlx::migrate::{MigrationType, Migrator};
+use sqlx::{PgPool, SqlitePool};
+
+/// Migrations embedded from the crate's `migrations` directory
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+/// `SQLite` migrations embedded from `migrations/sqlite`
+///
+/// These are reversible: each `NNN_name.up.sql` has a matching
+/// `NNN_name.down.sql` used by `rollback`.
+static SQLITE_MIGRATOR: Migrator = sqlx::migrate!("./migrations/sqlite");
+
+/// A schema migration and the script that reverts it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Migration {
+  /// Version number, taken from the file name prefix
+  pub version: i64,
+  /// Description, taken from the rest of the file name
+  pub description: String,
+  /// SQL that applies the migration
+  pub up: String,
+  /// SQL that reverts the migration, if one was written
+  pub down: Option<String>,
+}
+
+/// The embedded `SQLite` migrations, ordered by version
+#[must_use]
+pub fn sqlite_migrations() -> Vec<Migration> {
+  let mut migrations: Vec<Migration> = SQLITE_MIGRATOR
+    .iter()
+    .filter(|m| m.migration_type != MigrationType::ReversibleDown)
+    .map(|m| Migration {
+      version: m.version,
+      description: m.description.to_string(),
+      up: m.sql.to_string(),
+      down: SQLITE_MIGRATOR
+        .iter()
+        .find(|d| d.version == m.version && d.migration_type == MigrationType::ReversibleDown)
+        .map(|d| d.sql.to_string()),
+    })
+    .collect();
+  migrations.sort_by_key(|m| m.version);
+  migrations
+}
 
 /// Run all pending migrations
 ///
 /// # Errors
 /// - Returns a `DbError::Migration` if migrations fail to execute
 pub async fn run_migrations(pool: &PgPool) -> DbResult<()> {
-  sqlx::migrate!("./migrations")
+  MIGRATOR
+    .run(pool)
+    .await
+    .map_err(|e| DbError::Migration(format!("Migration failed: {e}")))
+}
+
+/// Run all pending migrations against an embedded `SQLite` database
+///
+/// # Errors
+/// - Returns a `DbError::Migration` if migrations fail to execute
+pub async fn run_sqlite_migrations(pool: &SqlitePool) -> DbResult<()> {
+  SQLITE_MIGRATOR
     .run(pool)
     .await
     .map_err(|e| DbError::Migration(format!("Migration failed: {e}")))
 }
 
+/// Revert the last `steps` applied `SQLite` migrations, newest first
+///
+/// Every migration to revert is checked for a down script before anything
+/// runs, and each revert runs in its own transaction together with the
+/// removal of its row from `_sqlx_migrations`.
+///
+/// # Errors
+/// - Returns a `DbError::Migration` if fewer than `steps` migrations are applied,
+///   if an applied migration is unknown, or if one has no down script
+/// - Returns a `DbError::Connection` if a down script or the bookkeeping fails
+pub async fn rollback(pool: &SqlitePool, steps: u32) -> DbResult<()> {
+  let applied: Vec<i64> =
+    sqlx::query_scalar("SELECT version FROM _sqlx_migrations WHERE success ORDER BY version")
+      .fetch_all(pool)
+      .await
+      .map_err(DbError::from)?;
+
+  let migrations = sqlite_migrations();
+  for (version, down) in plan_rollback(&migrations, &applied, steps)? {
+    let mut tx = pool.begin().await.map_err(DbError::from)?;
+    sqlx::raw_sql(down)
+      .execute(&mut *tx)
+      .await
+      .map_err(DbError::from)?;
+    sqlx::query("DELETE FROM _sqlx_migrations WHERE version = ?")
+      .bind(version)
+      .execute(&mut *tx)
+      .await
+      .map_err(DbError::from)?;
+    tx.commit().await.map_err(DbError::from)?;
+  }
+  Ok(())
+}
+
+/// Pick the down scripts that revert the last `steps` of `applied`
+fn plan_rollback<'a>(
+  migrations: &'a [Migration],
+  applied: &[i64],
+  steps: u32,
+) -> DbResult<Vec<(i64, &'a str)>> {
+  let steps = usize::try_from(steps).unwrap_or(usize::MAX);
+  if steps > applied.len() {
+    return Err(DbError::Migration(format!(
+      "Cannot roll back {steps} migrations: only {} applied",
+      applied.len()
+    )));
+  }
+
+  applied
+    .iter()
+    .rev()
+    .take(steps)
+    .map(|&version| {
+      let migration = migrations
+        .iter()
+        .find(|m| m.version == version)
+        .ok_or_else(|| DbError::Migration(format!("Applied migration {version} is unknown")))?;
+      migration
+        .down
+        .as_deref()
+        .map(|down| (version, down))
+        .ok_or_else(|| {
+          DbError::Migration(format!(
+            "Migration {version} ({}) has no down script",
+            migration.description
+          ))
+        })
+    })
+    .collect()
+}
+
 /// Get migration version information
 ///
 /// # Errors
@@ -35,11 +160,164 @@ pub async fn get_migration_version(pool: &PgPool) -> DbResult<Option<i64>> {
   Ok(result)
 }
 
+/// Check that every embedded migration has been applied successfully
+///
+/// # Errors
+/// - Returns a `DbError::DatabaseError` if the applied migrations cannot be read
+/// - Returns a `DbError::Migration` listing the versions still pending
+pub async fn verify_migrations(pool: &PgPool) -> DbResult<()> {
+  let applied: Vec<i64> =
+    sqlx::query_scalar("SELECT version FROM _sqlx_migrations WHERE success ORDER BY version")
+      .fetch_all(pool)
+      .await
+      .map_err(DbError::from)?;
+
+  let expected: Vec<i64> = MIGRATOR.iter().map(|m| m.version).collect();
+  check_pending(&expected, &applied)
+}
+
+/// Check that every embedded `SQLite` migration has been applied successfully
+///
+/// # Errors
+/// - Returns a `DbError::DatabaseError` if the applied migrations cannot be read
+/// - Returns a `DbError::Migration` listing the versions still pending
+pub async fn verify_sqlite_migrations(pool: &SqlitePool) -> DbResult<()> {
+  let applied: Vec<i64> =
+    sqlx::query_scalar("SELECT version FROM _sqlx_migrations WHERE success ORDER BY version")
+      .fetch_all(pool)
+      .await
+      .map_err(DbError::from)?;
+
+  let expected: Vec<i64> = sqlite_migrations().iter().map(|m| m.version).collect();
+  check_pending(&expected, &applied)
+}
+
+/// Fail with the versions in `expected` that are missing from `applied`
+fn check_pending(expected: &[i64], applied: &[i64]) -> DbResult<()> {
+  let pending = pending_migrations(expected, applied);
+  if pending.is_empty() {
+    Ok(())
+  } else {
+    let versions: Vec<String> = pending.iter().map(ToString::to_string).collect();
+    Err(DbError::Migration(format!(
+      "Pending migrations: {}",
+      versions.join(", ")
+    )))
+  }
+}
+
+/// Versions in `expected` that are missing from `applied`
+fn pending_migrations(expected: &[i64], applied: &[i64]) -> Vec<i64> {
+  expected
+    .iter()
+    .copied()
+    .filter(|version| !applied.contains(version))
+    .collect()
+}
+
 #[cfg(test)]
 mod tests {
+  use super::*;
+
   #[test]
   fn test_migration_module_exists() {
     // This test verifies the module compiles
     // Actual migration tests require a database
   }
+
+  #[test]
+  fn test_embedded_migrations_are_ordered() {
+    let versions: Vec<i64> = MIGRATOR.iter().map(|m| m.version).collect();
+    assert!(!versions.is_empty());
+    assert!(versions.windows(2).all(|pair| pair[0] < pair[1]));
+  }
+
+  fn migration(version: i64, down: Option<&str>) -> Migration {
+    Migration {
+      version,
+      description: format!("migration {version}"),
+      up: "SELECT 1".to_string(),
+      down: down.map(str::to_string),
+    }
+  }
+
+  #[test]
+  fn test_sqlite_migrations_are_reversible() {
+    let migrations = sqlite_migrations();
+    assert!(!migrations.is_empty());
+    assert!(migrations.iter().all(|m| m.down.is_some()));
+  }
+
+  #[test]
+  fn test_plan_rollback_newest_first() {
+    let migrations = vec![migration(1, Some("DROP 1")), migration(2, Some("DROP 2"))];
+
+    let plan = match plan_rollback(&migrations, &[1, 2], 2) {
+      Ok(plan) => plan,
+      Err(e) => panic!("Expected a rollback plan: {e}"),
+    };
+    assert_eq!(plan, vec![(2, "DROP 2"), (1, "DROP 1")]);
+  }
+
+  #[test]
+  fn test_plan_rollback_requires_down_script() {
+    let migrations = vec![migration(1, Some("DROP 1")), migration(2, None)];
+
+    match plan_rollback(&migrations, &[1, 2], 1) {
+      Err(DbError::Migration(message)) => assert!(message.contains("no down script")),
+      other => panic!("Expected a migration error, got {other:?}"),
+    }
+  }
+
+  #[test]
+  fn test_plan_rollback_too_many_steps() {
+    let migrations = vec![migration(1, Some("DROP 1"))];
+
+    assert!(matches!(
+      plan_rollback(&migrations, &[1], 2),
+      Err(DbError::Migration(_))
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_apply_then_rollback_sqlite() {
+    let config = crate::db::SqliteDbConfig::in_memory().with_max_connections(1);
+    let pool = match crate::db::create_sqlite_pool(&config).await {
+      Ok(pool) => pool,
+      Err(e) => panic!("Failed to create pool: {e}"),
+    };
+    let table_count = || {
+      sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'beads'",
+      )
+      .fetch_one(&pool)
+    };
+
+    if let Err(e) = run_sqlite_migrations(&pool).await {
+      panic!("Failed to run migrations: {e}");
+    }
+    assert_eq!(table_count().await.ok(), Some(1));
+    assert!(verify_sqlite_migrations(&pool).await.is_ok());
+
+    let steps = u32::try_from(sqlite_migrations().len()).unwrap_or(u32::MAX);
+    if let Err(e) = rollback(&pool, steps).await {
+      panic!("Failed to roll back: {e}");
+    }
+    assert_eq!(table_count().await.ok(), Some(0));
+    assert!(matches!(
+      verify_sqlite_migrations(&pool).await,
+      Err(DbError::Migration(_))
+    ));
+    assert!(matches!(
+      rollback(&pool, 1).await,
+      Err(DbError::Migration(_))
+    ));
+  }
+
+  #[test]
+  fn test_pending_migrations() {
+    assert!(pending_migrations(&[1, 2], &[1, 2]).is_empty());
+    assert_eq!(pending_migrations(&[1, 2, 3], &[1]), vec![2, 3]);
+    assert_eq!(pending_migrations(&[1, 2], &[]), vec![1, 2]);
+  }
 }
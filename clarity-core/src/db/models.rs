@@ -17,6 +17,7 @@ macro_rules! uuid_id {
   ($(#[$meta:meta])* $name:ident) => {
     $(#[$meta])*
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
     pub struct $name(pub Uuid);
 
     impl $name {
@@ -84,34 +85,49 @@ uuid_id!(
 pub struct Email(pub String);
 
 impl Email {
-  /// Create a new Email with validation
+  /// Create a new Email with validation and normalization
+  ///
+  /// Surrounding whitespace is trimmed and the domain is lowercased; the
+  /// local part is kept as given, since it may be case-sensitive.
   ///
   /// # Errors
   /// - Returns `DbError::InvalidEmail` if the email is empty, malformed, or invalid
-  pub fn new(email: String) -> DbResult<Self> {
+  pub fn new(raw: &str) -> DbResult<Self> {
     // Basic email validation:
     // - Must contain exactly one '@'
     // - Must have at least one character before '@'
     // - Must have at least one '.' after '@'
     // - Must have at least one character between '@' and '.'
     // - Must have at least one character after '.'
-    let parts: Vec<&str> = email.split('@').collect();
-    if parts.len() != 2 {
-      return Err(DbError::InvalidEmail(email));
-    }
-
-    let local = parts[0];
-    let domain = parts[1];
+    let email = raw.trim();
+    let Some((local, domain)) = email.split_once('@') else {
+      return Err(DbError::InvalidEmail(raw.to_string()));
+    };
 
-    if local.is_empty() || domain.is_empty() {
-      return Err(DbError::InvalidEmail(email));
+    if local.is_empty() || domain.is_empty() || domain.contains('@') {
+      return Err(DbError::InvalidEmail(raw.to_string()));
     }
 
     if !domain.contains('.') || domain.ends_with('.') || domain.starts_with('.') {
-      return Err(DbError::InvalidEmail(email));
+      return Err(DbError::InvalidEmail(raw.to_string()));
     }
 
-    Ok(Self(email))
+    Ok(Self(format!("{local}@{}", domain.to_lowercase())))
+  }
+
+  /// The part before the `@`
+  #[must_use]
+  pub fn local_part(&self) -> &str {
+    self
+      .0
+      .split_once('@')
+      .map_or(self.0.as_str(), |(local, _)| local)
+  }
+
+  /// The part after the `@`
+  #[must_use]
+  pub fn domain(&self) -> &str {
+    self.0.split_once('@').map_or("", |(_, domain)| domain)
   }
 
   /// Get the email as a string
@@ -157,6 +173,7 @@ impl std::str::FromStr for UserRole {
 
 /// Bead status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[sqlx(type_name = "bead_status", rename_all = "lowercase")]
 pub enum BeadStatus {
   Open,
@@ -208,6 +225,7 @@ impl std::str::FromStr for BeadStatus {
 
 /// Bead type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[sqlx(type_name = "bead_type", rename_all = "lowercase")]
 pub enum BeadType {
   Feature,
@@ -259,6 +277,7 @@ impl std::str::FromStr for BeadType {
 
 /// Bead priority (1 = high, 2 = medium, 3 = low)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct BeadPriority(pub i16);
 
 impl BeadPriority {
@@ -302,6 +321,7 @@ pub struct NewUser {
 
 /// Bead entity
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Bead {
   pub id: BeadId,
   pub title: String,
@@ -316,6 +336,7 @@ pub struct Bead {
 
 /// New bead (without id and timestamps)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct NewBead {
   pub title: String,
   pub description: Option<String>,
@@ -325,6 +346,15 @@ pub struct NewBead {
   pub created_by: Option<UserId>,
 }
 
+/// A bead that stays blocked until another bead is closed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BeadDependency {
+  /// The dependent bead
+  pub bead_id: BeadId,
+  /// The bead it waits on
+  pub depends_on: BeadId,
+}
+
 /// Interview entity
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Interview {
@@ -335,6 +365,22 @@ pub struct Interview {
   pub updated_at: DateTime<Utc>,
 }
 
+/// Session entity
+///
+/// `kind` and `state` hold the lowercase names used by `session::SessionKind`
+/// and `session::SessionState`; `interview_id` is set only for interview sessions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+  pub id: Uuid,
+  pub kind: String,
+  pub state: String,
+  pub interview_id: Option<Uuid>,
+  pub title: Option<String>,
+  pub description: Option<String>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: DateTime<Utc>,
+}
+
 /// Spec entity
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Spec {
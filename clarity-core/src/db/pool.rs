@@ -66,6 +66,26 @@ impl DbConfig {
     self.acquire_timeout = timeout;
     self
   }
+
+  /// Check that the configuration can be used to build a pool
+  ///
+  /// # Errors
+  /// - Returns `DbError::Validation` if the URL is not a `postgres://` or
+  ///   `postgresql://` URL, or if `max_connections` is zero
+  pub fn validate(&self) -> DbResult<()> {
+    if !(self.database_url.starts_with("postgres://")
+      || self.database_url.starts_with("postgresql://"))
+    {
+      return Err(DbError::validation(format!(
+        "database_url must be a postgres URL, got '{}'",
+        self.database_url
+      )));
+    }
+    if self.max_connections == 0 {
+      return Err(DbError::validation("max_connections must be at least 1"));
+    }
+    Ok(())
+  }
 }
 
 /// Create a database connection pool
@@ -126,6 +146,22 @@ mod tests {
     assert_eq!(config.max_connections, 20);
   }
 
+  #[test]
+  fn test_db_config_validate() {
+    assert!(DbConfig::default().validate().is_ok());
+    assert!(DbConfig::new("postgres://db/clarity".to_string())
+      .validate()
+      .is_ok());
+    assert!(matches!(
+      DbConfig::new("mysql://localhost/clarity".to_string()).validate(),
+      Err(DbError::Validation(_))
+    ));
+    assert!(matches!(
+      DbConfig::default().with_max_connections(0).validate(),
+      Err(DbError::Validation(_))
+    ));
+  }
+
   #[test]
   #[allow(clippy::panic)]
   fn test_db_config_from_env_missing() {
@@ -0,0 +1,501 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Repository layer for beads
+//!
+//! CRUD operations against the embedded `SQLite` database. Queries are built
+//! at runtime with `sqlx::query` so no database is needed at compile time.
+//! Ids and enums are stored as text; see `migrations/sqlite`.
+
+use crate::db::error::{DbError, DbResult};
+use crate::db::models::{Bead, BeadDependency, BeadId, BeadPriority, BeadStatus, NewBead, UserId};
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Executor, QueryBuilder, Row, Sqlite, SqlitePool};
+
+/// Largest page `list_beads_paged` will return, whatever limit is requested
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+const BEAD_COLUMNS: &str =
+  "id, title, description, status, priority, bead_type, created_by, created_at, updated_at";
+
+/// Insert a new bead
+///
+/// # Errors
+/// - Returns a `DbError::Connection` if the insert fails
+pub async fn create_bead(pool: &SqlitePool, bead: &NewBead) -> DbResult<Bead> {
+  insert_bead(pool, bead).await.map_err(DbError::from)
+}
+
+/// Insert several beads in one transaction
+///
+/// Either every bead is inserted or, if any insert fails, none is.
+///
+/// # Errors
+/// - Returns `DbError::Validation` naming the first bead that breaks a table
+///   constraint, e.g. a priority outside 1-3 or an unknown `created_by` user
+/// - Returns a `DbError::Connection` if the transaction fails
+pub async fn create_beads(pool: &SqlitePool, beads: &[NewBead]) -> DbResult<Vec<Bead>> {
+  let mut tx = pool.begin().await.map_err(DbError::from)?;
+  let mut created = Vec::with_capacity(beads.len());
+  for (index, bead) in beads.iter().enumerate() {
+    let inserted = insert_bead(&mut *tx, bead)
+      .await
+      .map_err(|e| match e.as_database_error() {
+        Some(db) if db.is_check_violation() || db.is_foreign_key_violation() => {
+          DbError::validation(format!("Bead {index} is invalid: {}", db.message()))
+        }
+        _ => DbError::from(e),
+      })?;
+    created.push(inserted);
+  }
+  tx.commit().await.map_err(DbError::from)?;
+
+  Ok(created)
+}
+
+/// Insert one bead through `executor`, a pool or an open transaction
+async fn insert_bead<'e>(
+  executor: impl Executor<'e, Database = Sqlite>,
+  bead: &NewBead,
+) -> Result<Bead, sqlx::Error> {
+  let now = Utc::now();
+  let created = Bead {
+    id: BeadId::new(),
+    title: bead.title.clone(),
+    description: bead.description.clone(),
+    status: bead.status,
+    priority: bead.priority,
+    bead_type: bead.bead_type,
+    created_by: bead.created_by,
+    created_at: now,
+    updated_at: now,
+  };
+
+  sqlx::query(&format!(
+    "INSERT INTO beads ({BEAD_COLUMNS}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)"
+  ))
+  .bind(created.id.to_string())
+  .bind(&created.title)
+  .bind(&created.description)
+  .bind(created.status.as_str())
+  .bind(created.priority.0)
+  .bind(created.bead_type.as_str())
+  .bind(created.created_by.map(|id| id.to_string()))
+  .bind(created.created_at)
+  .bind(created.updated_at)
+  .execute(executor)
+  .await?;
+
+  Ok(created)
+}
+
+/// Fetch a bead by id
+///
+/// # Errors
+/// - Returns `DbError::NotFound` if no bead has this id
+/// - Returns a `DbError::Connection` if the query fails
+pub async fn get_bead(pool: &SqlitePool, id: &BeadId) -> DbResult<Bead> {
+  let row = sqlx::query(&format!("SELECT {BEAD_COLUMNS} FROM beads WHERE id = ?"))
+    .bind(id.to_string())
+    .fetch_optional(pool)
+    .await
+    .map_err(DbError::from)?
+    .ok_or_else(|| DbError::not_found("bead", id.to_string()))?;
+
+  bead_from_row(&row)
+}
+
+/// Replace a bead's fields, keeping its id and creation time
+///
+/// # Errors
+/// - Returns `DbError::NotFound` if no bead has this id
+/// - Returns a `DbError::Connection` if the update fails
+pub async fn update_bead(pool: &SqlitePool, id: &BeadId, bead: &NewBead) -> DbResult<Bead> {
+  let result = sqlx::query(
+    "UPDATE beads SET title = ?, description = ?, status = ?, priority = ?, bead_type = ?, \
+     created_by = ?, updated_at = ? WHERE id = ?",
+  )
+  .bind(&bead.title)
+  .bind(&bead.description)
+  .bind(bead.status.as_str())
+  .bind(bead.priority.0)
+  .bind(bead.bead_type.as_str())
+  .bind(bead.created_by.map(|id| id.to_string()))
+  .bind(Utc::now())
+  .bind(id.to_string())
+  .execute(pool)
+  .await
+  .map_err(DbError::from)?;
+
+  if result.rows_affected() == 0 {
+    return Err(DbError::not_found("bead", id.to_string()));
+  }
+  get_bead(pool, id).await
+}
+
+/// Delete a bead by id
+///
+/// # Errors
+/// - Returns `DbError::NotFound` if no bead has this id
+/// - Returns a `DbError::Connection` if the delete fails
+pub async fn delete_bead(pool: &SqlitePool, id: &BeadId) -> DbResult<()> {
+  let result = sqlx::query("DELETE FROM beads WHERE id = ?")
+    .bind(id.to_string())
+    .execute(pool)
+    .await
+    .map_err(DbError::from)?;
+
+  if result.rows_affected() == 0 {
+    return Err(DbError::not_found("bead", id.to_string()));
+  }
+  Ok(())
+}
+
+/// List all beads, most recently created first
+///
+/// # Errors
+/// - Returns a `DbError::Connection` if the query fails
+/// - Returns `DbError::Validation` or `DbError::InvalidUuid` if a stored row is malformed
+pub async fn list_beads(pool: &SqlitePool) -> DbResult<Vec<Bead>> {
+  let rows = sqlx::query(&format!(
+    "SELECT {BEAD_COLUMNS} FROM beads ORDER BY created_at DESC, id"
+  ))
+  .fetch_all(pool)
+  .await
+  .map_err(DbError::from)?;
+
+  rows.iter().map(bead_from_row).collect()
+}
+
+/// One page of results and the cursor for the next page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<T, C = BeadId> {
+  /// Items on this page
+  pub items: Vec<T>,
+  /// Cursor to pass to get the following page, or `None` on the last page
+  pub next_cursor: Option<C>,
+}
+
+/// Optional conditions for listing beads; unset fields match every bead
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BeadFilter {
+  /// Only beads with this status
+  pub status: Option<BeadStatus>,
+  /// Only beads with this priority
+  pub priority: Option<BeadPriority>,
+}
+
+/// List beads one page at a time, oldest first
+///
+/// Beads are ordered by creation time and then id, so pages are stable while
+/// new beads are added. `cursor` is the id of the last bead of the previous
+/// page (`None` for the first page). `limit` is clamped to
+/// `1..=MAX_PAGE_SIZE`.
+///
+/// # Errors
+/// - Returns `DbError::NotFound` if the cursor bead does not exist
+/// - Returns a `DbError::Connection` if the query fails
+pub async fn list_beads_paged(
+  pool: &SqlitePool,
+  cursor: Option<BeadId>,
+  limit: u32,
+) -> DbResult<Page<Bead>> {
+  list_beads_filtered(pool, BeadFilter::default(), cursor, limit).await
+}
+
+/// Bead field a listing can be sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortField {
+  /// Priority number, so `asc` puts high priority (1) first
+  Priority,
+  /// Creation time
+  CreatedAt,
+  /// Workflow position: open, in progress, blocked, deferred, closed
+  Status,
+}
+
+impl SortField {
+  /// SQL expression to sort by; a fixed string, never built from input
+  const fn sql(self) -> &'static str {
+    match self {
+      Self::Priority => "priority",
+      Self::CreatedAt => "created_at",
+      Self::Status => {
+        "CASE status WHEN 'open' THEN 0 WHEN 'in_progress' THEN 1 WHEN 'blocked' THEN 2 \
+         WHEN 'deferred' THEN 3 ELSE 4 END"
+      }
+    }
+  }
+}
+
+impl std::str::FromStr for SortField {
+  type Err = DbError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "priority" => Ok(Self::Priority),
+      "created_at" => Ok(Self::CreatedAt),
+      "status" => Ok(Self::Status),
+      _ => Err(DbError::validation(format!("Invalid sort field: {s}"))),
+    }
+  }
+}
+
+/// Direction of a sorted listing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+  /// Smallest first
+  Asc,
+  /// Largest first
+  Desc,
+}
+
+impl SortOrder {
+  const fn sql(self) -> &'static str {
+    match self {
+      Self::Asc => "ASC",
+      Self::Desc => "DESC",
+    }
+  }
+
+  /// Comparison selecting the rows that come after a cursor row
+  const fn after(self) -> &'static str {
+    match self {
+      Self::Asc => ">",
+      Self::Desc => "<",
+    }
+  }
+}
+
+impl std::str::FromStr for SortOrder {
+  type Err = DbError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "asc" => Ok(Self::Asc),
+      "desc" => Ok(Self::Desc),
+      _ => Err(DbError::validation(format!("Invalid sort order: {s}"))),
+    }
+  }
+}
+
+/// How a bead listing is ordered; defaults to newest first
+///
+/// Ties are broken by creation time and then id, in the same direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortSpec {
+  /// Field to sort by
+  pub field: SortField,
+  /// Direction to sort in
+  pub order: SortOrder,
+}
+
+impl SortSpec {
+  /// Create a sort specification
+  #[must_use]
+  pub const fn new(field: SortField, order: SortOrder) -> Self {
+    Self { field, order }
+  }
+
+  /// Sort keys, most significant first
+  fn keys(self) -> Vec<&'static str> {
+    match self.field {
+      SortField::CreatedAt => vec!["created_at", "id"],
+      field => vec![field.sql(), "created_at", "id"],
+    }
+  }
+}
+
+impl Default for SortSpec {
+  fn default() -> Self {
+    Self::new(SortField::CreatedAt, SortOrder::Desc)
+  }
+}
+
+/// List the beads matching `filter` one page at a time, oldest first
+///
+/// Paging works as in [`list_beads_paged`]; the cursor must come from a page
+/// listed with the same filter.
+///
+/// # Errors
+/// - Returns `DbError::NotFound` if the cursor bead does not exist
+/// - Returns a `DbError::Connection` if the query fails
+pub async fn list_beads_filtered(
+  pool: &SqlitePool,
+  filter: BeadFilter,
+  cursor: Option<BeadId>,
+  limit: u32,
+) -> DbResult<Page<Bead>> {
+  let oldest_first = SortSpec::new(SortField::CreatedAt, SortOrder::Asc);
+  list_beads_sorted(pool, filter, oldest_first, cursor, limit).await
+}
+
+/// List the beads matching `filter` one page at a time, in `sort` order
+///
+/// Paging works as in [`list_beads_paged`]; the cursor must come from a page
+/// listed with the same filter and sort.
+///
+/// # Errors
+/// - Returns `DbError::NotFound` if the cursor bead does not exist
+/// - Returns a `DbError::Connection` if the query fails
+pub async fn list_beads_sorted(
+  pool: &SqlitePool,
+  filter: BeadFilter,
+  sort: SortSpec,
+  cursor: Option<BeadId>,
+  limit: u32,
+) -> DbResult<Page<Bead>> {
+  let limit = clamp_page_size(limit);
+  if let Some(cursor) = &cursor {
+    get_bead(pool, cursor).await?;
+  }
+
+  let mut query =
+    QueryBuilder::<Sqlite>::new(format!("SELECT {BEAD_COLUMNS} FROM beads WHERE 1 = 1"));
+  if let Some(status) = filter.status {
+    query.push(" AND status = ").push_bind(status.as_str());
+  }
+  if let Some(priority) = filter.priority {
+    query.push(" AND priority = ").push_bind(priority.0);
+  }
+  let keys = sort.keys().join(", ");
+  if let Some(cursor) = cursor {
+    // Row values compare key by key, like the ORDER BY below
+    query
+      .push(format!(
+        " AND ({keys}) {} (SELECT {keys} FROM beads WHERE id = ",
+        sort.order.after()
+      ))
+      .push_bind(cursor.to_string())
+      .push(")");
+  }
+  let order_by = sort
+    .keys()
+    .iter()
+    .map(|key| format!("{key} {}", sort.order.sql()))
+    .collect::<Vec<_>>()
+    .join(", ");
+  // Fetch one extra row to learn whether another page follows
+  query
+    .push(format!(" ORDER BY {order_by} LIMIT "))
+    .push_bind(i64::from(limit) + 1);
+
+  let rows = query.build().fetch_all(pool).await.map_err(DbError::from)?;
+
+  let mut items = rows
+    .iter()
+    .map(bead_from_row)
+    .collect::<DbResult<Vec<Bead>>>()?;
+  let next_cursor = if items.len() > limit as usize {
+    items.truncate(limit as usize);
+    items.last().map(|bead| bead.id)
+  } else {
+    None
+  };
+
+  Ok(Page { items, next_cursor })
+}
+
+/// Record that one bead depends on another
+///
+/// # Errors
+/// - Returns `DbError::Validation` if a bead would depend on itself
+/// - Returns `DbError::NotFound` if either bead does not exist
+/// - Returns `DbError::Duplicate` if the dependency is already recorded
+/// - Returns a `DbError::Connection` if the insert fails
+pub async fn add_bead_dependency(pool: &SqlitePool, dependency: &BeadDependency) -> DbResult<()> {
+  if dependency.bead_id == dependency.depends_on {
+    return Err(DbError::validation(format!(
+      "Bead {} cannot depend on itself",
+      dependency.bead_id
+    )));
+  }
+  get_bead(pool, &dependency.bead_id).await?;
+  get_bead(pool, &dependency.depends_on).await?;
+
+  sqlx::query("INSERT INTO bead_dependencies (bead_id, depends_on) VALUES (?, ?)")
+    .bind(dependency.bead_id.to_string())
+    .bind(dependency.depends_on.to_string())
+    .execute(pool)
+    .await
+    .map_err(|e| match e.as_database_error() {
+      Some(db) if db.is_unique_violation() => DbError::duplicate(format!(
+        "Bead {} already depends on {}",
+        dependency.bead_id, dependency.depends_on
+      )),
+      _ => DbError::from(e),
+    })?;
+
+  Ok(())
+}
+
+/// List beads with at least one dependency that is not closed, oldest first
+///
+/// # Errors
+/// - Returns a `DbError::Connection` if the query fails
+/// - Returns `DbError::Validation` or `DbError::InvalidUuid` if a stored row is malformed
+pub async fn blocked_beads(pool: &SqlitePool) -> DbResult<Vec<Bead>> {
+  let rows = sqlx::query(&format!(
+    "SELECT {BEAD_COLUMNS} FROM beads WHERE EXISTS (\
+       SELECT 1 FROM bead_dependencies d JOIN beads dep ON dep.id = d.depends_on \
+       WHERE d.bead_id = beads.id AND dep.status <> ?\
+     ) ORDER BY created_at, id"
+  ))
+  .bind(BeadStatus::Closed.as_str())
+  .fetch_all(pool)
+  .await
+  .map_err(DbError::from)?;
+
+  rows.iter().map(bead_from_row).collect()
+}
+
+/// Clamp a requested page size to `1..=MAX_PAGE_SIZE`
+const fn clamp_page_size(limit: u32) -> u32 {
+  if limit == 0 {
+    1
+  } else if limit > MAX_PAGE_SIZE {
+    MAX_PAGE_SIZE
+  } else {
+    limit
+  }
+}
+
+/// Decode a `beads` row into a `Bead`
+fn bead_from_row(row: &SqliteRow) -> DbResult<Bead> {
+  let id: String = row.try_get("id")?;
+  let status: String = row.try_get("status")?;
+  let priority: i16 = row.try_get("priority")?;
+  let bead_type: String = row.try_get("bead_type")?;
+  let created_by: Option<String> = row.try_get("created_by")?;
+  let created_at: DateTime<Utc> = row.try_get("created_at")?;
+  let updated_at: DateTime<Utc> = row.try_get("updated_at")?;
+
+  Ok(Bead {
+    id: BeadId::from_str(&id)?,
+    title: row.try_get("title")?,
+    description: row.try_get("description")?,
+    status: status.parse()?,
+    priority: BeadPriority::new(priority)?,
+    bead_type: bead_type.parse()?,
+    created_by: created_by.as_deref().map(UserId::from_str).transpose()?,
+    created_at,
+    updated_at,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_clamp_page_size() {
+    assert_eq!(clamp_page_size(0), 1);
+    assert_eq!(clamp_page_size(25), 25);
+    assert_eq!(clamp_page_size(MAX_PAGE_SIZE + 1), MAX_PAGE_SIZE);
+  }
+}
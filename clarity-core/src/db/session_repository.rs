@@ -0,0 +1,179 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Repository layer for sessions
+//!
+//! Sessions live in the `sessions` table of the embedded `SQLite` database.
+//! Kinds and states are stored as their `Display` text, timestamps as
+//! RFC 3339 strings, and a completed session's result as JSON.
+
+use crate::db::error::{DbError, DbResult};
+use crate::interview::InterviewId;
+use crate::session::{Session, SessionError, SessionId, SessionResult, Timestamp};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqlitePool};
+
+const SESSION_COLUMNS: &str = "id, kind, state, interview_id, title, description, created_at, \
+                               updated_at, deleted_at, result";
+
+/// Insert a new session
+///
+/// # Errors
+/// - Returns `DbError::Duplicate` if a session with this id is already stored
+/// - Returns `DbError::Validation` if the result cannot be encoded
+/// - Returns a `DbError::Connection` if the insert fails
+pub async fn create_session(pool: &SqlitePool, session: &Session) -> DbResult<()> {
+  sqlx::query(&format!(
+    "INSERT INTO sessions ({SESSION_COLUMNS}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+  ))
+  .bind(session.id.as_str())
+  .bind(session.kind.to_string())
+  .bind(session.state.to_string())
+  .bind(session.interview_id.as_ref().map(InterviewId::as_str))
+  .bind(&session.title)
+  .bind(&session.description)
+  .bind(session.created_at.to_rfc3339())
+  .bind(session.updated_at.to_rfc3339())
+  .bind(session.deleted_at.as_ref().map(Timestamp::to_rfc3339))
+  .bind(encode_result(session.result.as_ref())?)
+  .execute(pool)
+  .await
+  .map_err(|e| match e.as_database_error() {
+    Some(db) if db.is_unique_violation() => {
+      DbError::duplicate(format!("Session {} already exists", session.id))
+    }
+    _ => DbError::from(e),
+  })?;
+
+  Ok(())
+}
+
+/// Fetch a session by id
+///
+/// # Errors
+/// - Returns `DbError::NotFound` if no session has this id
+/// - Returns `DbError::Validation` if the stored row is malformed
+/// - Returns a `DbError::Connection` if the query fails
+pub async fn get_session(pool: &SqlitePool, id: &SessionId) -> DbResult<Session> {
+  let row = sqlx::query(&format!(
+    "SELECT {SESSION_COLUMNS} FROM sessions WHERE id = ?"
+  ))
+  .bind(id.as_str())
+  .fetch_optional(pool)
+  .await
+  .map_err(DbError::from)?
+  .ok_or_else(|| DbError::not_found("session", id.to_string()))?;
+
+  session_from_row(&row)
+}
+
+/// Store a session's current state, title, description, deletion time and result
+///
+/// The kind, interview link and creation time are fixed when the session is
+/// created and are not changed.
+///
+/// # Errors
+/// - Returns `DbError::NotFound` if no session has this id
+/// - Returns `DbError::Validation` if the result cannot be encoded
+/// - Returns a `DbError::Connection` if the update fails
+pub async fn update_session(pool: &SqlitePool, session: &Session) -> DbResult<()> {
+  let result = sqlx::query(
+    "UPDATE sessions SET state = ?, title = ?, description = ?, updated_at = ?, deleted_at = ?, \
+     result = ? WHERE id = ?",
+  )
+  .bind(session.state.to_string())
+  .bind(&session.title)
+  .bind(&session.description)
+  .bind(session.updated_at.to_rfc3339())
+  .bind(session.deleted_at.as_ref().map(Timestamp::to_rfc3339))
+  .bind(encode_result(session.result.as_ref())?)
+  .bind(session.id.as_str())
+  .execute(pool)
+  .await
+  .map_err(DbError::from)?;
+
+  if result.rows_affected() == 0 {
+    return Err(DbError::not_found("session", session.id.to_string()));
+  }
+  Ok(())
+}
+
+/// List the sessions that ran an interview, oldest first
+///
+/// # Errors
+/// - Returns `DbError::Validation` if a stored row is malformed
+/// - Returns a `DbError::Connection` if the query fails
+pub async fn list_interview_sessions(
+  pool: &SqlitePool,
+  interview_id: &InterviewId,
+) -> DbResult<Vec<Session>> {
+  let rows = sqlx::query(&format!(
+    "SELECT {SESSION_COLUMNS} FROM sessions WHERE interview_id = ? ORDER BY created_at, id"
+  ))
+  .bind(interview_id.as_str())
+  .fetch_all(pool)
+  .await
+  .map_err(DbError::from)?;
+
+  rows.iter().map(session_from_row).collect()
+}
+
+/// Encode a session result as JSON text
+fn encode_result(result: Option<&SessionResult>) -> DbResult<Option<String>> {
+  result
+    .map(serde_json::to_string)
+    .transpose()
+    .map_err(|e| DbError::validation(format!("Session result cannot be stored: {e}")))
+}
+
+/// Parse a stored RFC 3339 timestamp
+fn timestamp(value: &str) -> DbResult<Timestamp> {
+  Timestamp::from_rfc3339(value)
+    .map_err(|e| DbError::validation(format!("Invalid stored timestamp {value:?}: {e}")))
+}
+
+/// Decode a `sessions` row into a `Session`
+///
+/// Goes through `Session::new`, so a row whose kind and interview link
+/// disagree is rejected.
+fn session_from_row(row: &SqliteRow) -> DbResult<Session> {
+  let invalid = |e: SessionError| DbError::validation(e.to_string());
+  let id: String = row.try_get("id")?;
+  let kind: String = row.try_get("kind")?;
+  let state: String = row.try_get("state")?;
+  let interview_id: Option<String> = row.try_get("interview_id")?;
+  let created_at: String = row.try_get("created_at")?;
+  let updated_at: String = row.try_get("updated_at")?;
+  let deleted_at: Option<String> = row.try_get("deleted_at")?;
+  let result: Option<String> = row.try_get("result")?;
+
+  let interview_id = interview_id
+    .map(InterviewId::new)
+    .transpose()
+    .map_err(|e| DbError::validation(e.to_string()))?;
+  let session = Session::new(
+    SessionId::new(id).map_err(invalid)?,
+    kind.parse().map_err(invalid)?,
+    interview_id,
+    timestamp(&created_at)?,
+  )
+  .map_err(invalid)?;
+
+  Ok(Session {
+    state: state.parse().map_err(invalid)?,
+    updated_at: timestamp(&updated_at)?,
+    title: row.try_get("title")?,
+    description: row.try_get("description")?,
+    deleted_at: deleted_at.as_deref().map(timestamp).transpose()?,
+    result: result
+      .as_deref()
+      .map(serde_json::from_str)
+      .transpose()
+      .map_err(|e| DbError::validation(format!("Invalid stored session result: {e}")))?,
+    ..session
+  })
+}
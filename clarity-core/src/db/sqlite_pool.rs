@@ -12,9 +12,16 @@
 
 use crate::db::error::{DbError, DbResult};
 #[allow(unused_imports)]
-use sqlx::{sqlite::SqlitePoolOptions, Row, SqlitePool};
+use sqlx::{
+  sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+  Row, SqlitePool,
+};
+use std::str::FromStr;
 use std::time::Duration;
 
+/// How long `health_check` waits for the database before reporting failure
+pub const DEFAULT_HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
 /// `SQLite` database configuration
 #[derive(Debug, Clone)]
 pub struct SqliteDbConfig {
@@ -95,6 +102,24 @@ impl SqliteDbConfig {
     self.max_lifetime = lifetime;
     self
   }
+
+  /// Check that the configuration can be used to build a pool
+  ///
+  /// # Errors
+  /// - Returns `DbError::Validation` if the URL is not a `sqlite:` URL, or if
+  ///   `max_connections` is zero
+  pub fn validate(&self) -> DbResult<()> {
+    if !self.database_url.starts_with("sqlite:") {
+      return Err(DbError::validation(format!(
+        "database_url must be a sqlite URL, got '{}'",
+        self.database_url
+      )));
+    }
+    if self.max_connections == 0 {
+      return Err(DbError::validation("max_connections must be at least 1"));
+    }
+    Ok(())
+  }
 }
 
 /// Create a `SQLite` database connection pool with WAL mode enabled
@@ -108,9 +133,15 @@ impl SqliteDbConfig {
 /// - 64MB cache size for better performance
 /// - Memory-based temporary storage
 ///
+/// The database file is created if it does not exist yet.
+///
 /// # Errors
-/// - Returns a `DbError::DatabaseError` if connection fails
+/// - Returns a `DbError::DatabaseError` if the URL is invalid or connection fails
 pub async fn create_sqlite_pool(config: &SqliteDbConfig) -> DbResult<SqlitePool> {
+  let options = SqliteConnectOptions::from_str(&config.database_url)
+    .map_err(DbError::from)?
+    .create_if_missing(true);
+
   let pool = SqlitePoolOptions::new()
     .max_connections(config.max_connections)
     .acquire_timeout(config.acquire_timeout)
@@ -146,7 +177,7 @@ pub async fn create_sqlite_pool(config: &SqliteDbConfig) -> DbResult<SqlitePool>
         Ok(())
       })
     })
-    .connect(&config.database_url)
+    .connect_with(options)
     .await
     .map_err(DbError::from)?;
 
@@ -165,6 +196,26 @@ pub async fn test_sqlite_connection(pool: &SqlitePool) -> DbResult<()> {
     .map_err(DbError::from)
 }
 
+/// Check that the database answers a trivial query within the default timeout
+///
+/// Uses [`DEFAULT_HEALTH_CHECK_TIMEOUT`]; see [`health_check_with_timeout`].
+///
+/// # Errors
+/// - Returns a `DbError::Connection` if the query fails or times out
+pub async fn health_check(pool: &SqlitePool) -> DbResult<()> {
+  health_check_with_timeout(pool, DEFAULT_HEALTH_CHECK_TIMEOUT).await
+}
+
+/// Check that the database answers a trivial query within `timeout`
+///
+/// # Errors
+/// - Returns a `DbError::Connection` if the query fails or times out
+pub async fn health_check_with_timeout(pool: &SqlitePool, timeout: Duration) -> DbResult<()> {
+  tokio::time::timeout(timeout, test_sqlite_connection(pool))
+    .await
+    .map_err(|_| DbError::Connection(sqlx::Error::PoolTimedOut))?
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -225,6 +276,36 @@ mod tests {
     std::env::remove_var("SQLITE_DATABASE_URL");
   }
 
+  #[test]
+  fn test_sqlite_config_validate() {
+    assert!(SqliteDbConfig::default().validate().is_ok());
+    assert!(SqliteDbConfig::in_memory().validate().is_ok());
+    assert!(matches!(
+      SqliteDbConfig::new("postgres://localhost/clarity".to_string()).validate(),
+      Err(DbError::Validation(_))
+    ));
+    assert!(matches!(
+      SqliteDbConfig::in_memory()
+        .with_max_connections(0)
+        .validate(),
+      Err(DbError::Validation(_))
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_sqlite_pool_creates_missing_file() {
+    let path = std::env::temp_dir().join(format!("clarity-{}.db", uuid::Uuid::new_v4()));
+    let config = SqliteDbConfig::new(format!("sqlite:{}", path.display()));
+
+    let pool = create_sqlite_pool(&config)
+      .await
+      .expect("Failed to create file-backed SQLite pool");
+    assert!(path.exists());
+
+    pool.close().await;
+    let _ = std::fs::remove_file(&path);
+  }
+
   #[tokio::test]
   async fn test_sqlite_pool_in_memory() {
     let config = SqliteDbConfig::in_memory();
@@ -366,4 +447,20 @@ mod tests {
 
     pool.close().await;
   }
+
+  #[tokio::test]
+  async fn test_health_check_in_memory() {
+    let pool = match create_sqlite_pool(&SqliteDbConfig::in_memory()).await {
+      Ok(pool) => pool,
+      Err(e) => panic!("Failed to create in-memory SQLite pool: {e}"),
+    };
+
+    assert!(health_check(&pool).await.is_ok());
+    assert!(health_check_with_timeout(&pool, Duration::from_millis(500))
+      .await
+      .is_ok());
+
+    pool.close().await;
+    assert!(health_check(&pool).await.is_err());
+  }
 }
@@ -9,3 +9,6 @@ mod models_test;
 
 #[cfg(feature = "integration-tests")]
 mod integration_test;
+
+mod repository_test;
+mod session_repository_test;
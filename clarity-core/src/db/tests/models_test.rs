@@ -25,7 +25,7 @@ fn test_email_valid() {
   ];
 
   for email in valid_emails {
-    let result = Email::new(email.to_string());
+    let result = Email::new(email);
     assert!(
       result.is_ok(),
       "Email '{}' should be valid, got error: {:?}",
@@ -43,7 +43,7 @@ fn test_email_invalid() {
   let invalid_emails = vec!["notanemail", "@example.com", "user@", "user@.com"];
 
   for email in invalid_emails {
-    let result = Email::new(email.to_string());
+    let result = Email::new(email);
     assert!(
       result.is_err(),
       "Email '{}' should be invalid, but got success: {:?}",
@@ -58,6 +58,24 @@ fn test_email_invalid() {
   }
 }
 
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_email_normalizes_domain_only() {
+  let email = Email::new("  Foo@Example.COM ").unwrap();
+
+  assert_eq!(email.as_str(), "Foo@example.com");
+  assert_eq!(email.local_part(), "Foo");
+  assert_eq!(email.domain(), "example.com");
+}
+
+#[test]
+fn test_email_rejects_multiple_at_signs() {
+  assert!(matches!(
+    Email::new("a@b@example.com"),
+    Err(DbError::InvalidEmail(_))
+  ));
+}
+
 #[allow(clippy::unwrap_used)]
 #[allow(clippy::panic)]
 #[test]
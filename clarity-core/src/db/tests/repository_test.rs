@@ -0,0 +1,231 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+#![allow(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Integration tests for the `SQLite` bead repository
+//!
+//! Each test migrates a fresh in-memory database, so no external
+//! database is required.
+
+use crate::db::{
+  add_bead_dependency, blocked_beads, create_bead, create_sqlite_pool, delete_bead, get_bead,
+  list_beads, list_beads_filtered, list_beads_paged, list_beads_sorted, run_sqlite_migrations,
+  update_bead, BeadDependency, BeadFilter, BeadPriority, BeadStatus, BeadType, DbError, NewBead,
+  SortField, SortOrder, SortSpec, SqliteDbConfig,
+};
+use sqlx::SqlitePool;
+
+async fn migrated_pool() -> SqlitePool {
+  // A single connection keeps every query on the same in-memory database
+  let config = SqliteDbConfig::in_memory().with_max_connections(1);
+  let pool = create_sqlite_pool(&config)
+    .await
+    .expect("Failed to create in-memory pool");
+  run_sqlite_migrations(&pool)
+    .await
+    .expect("Failed to run migrations");
+  pool
+}
+
+fn new_bead(title: &str) -> NewBead {
+  NewBead {
+    title: title.to_string(),
+    description: Some("A test bead".to_string()),
+    status: BeadStatus::Open,
+    priority: BeadPriority::MEDIUM,
+    bead_type: BeadType::Feature,
+    created_by: None,
+  }
+}
+
+#[tokio::test]
+async fn test_bead_round_trip() {
+  let pool = migrated_pool().await;
+
+  let created = create_bead(&pool, &new_bead("Round trip")).await.unwrap();
+  let fetched = get_bead(&pool, &created.id).await.unwrap();
+
+  assert_eq!(fetched.id, created.id);
+  assert_eq!(fetched.title, "Round trip");
+  assert_eq!(fetched.description.as_deref(), Some("A test bead"));
+  assert_eq!(fetched.status, BeadStatus::Open);
+  assert_eq!(fetched.priority, BeadPriority::MEDIUM);
+  assert_eq!(fetched.bead_type, BeadType::Feature);
+  assert_eq!(fetched.created_at, created.created_at);
+}
+
+#[tokio::test]
+async fn test_update_and_delete_bead() {
+  let pool = migrated_pool().await;
+  let created = create_bead(&pool, &new_bead("Before")).await.unwrap();
+
+  let mut changes = new_bead("After");
+  changes.status = BeadStatus::Closed;
+  let updated = update_bead(&pool, &created.id, &changes).await.unwrap();
+  assert_eq!(updated.title, "After");
+  assert_eq!(updated.status, BeadStatus::Closed);
+  assert_eq!(updated.created_at, created.created_at);
+
+  assert_eq!(list_beads(&pool).await.unwrap().len(), 1);
+
+  delete_bead(&pool, &created.id).await.unwrap();
+  assert!(matches!(
+    get_bead(&pool, &created.id).await,
+    Err(DbError::NotFound { .. })
+  ));
+  assert!(matches!(
+    delete_bead(&pool, &created.id).await,
+    Err(DbError::NotFound { .. })
+  ));
+}
+
+#[tokio::test]
+async fn test_paged_listing_follows_cursor() {
+  let pool = migrated_pool().await;
+  for i in 0..5 {
+    create_bead(&pool, &new_bead(&format!("Bead {i}")))
+      .await
+      .unwrap();
+  }
+
+  let first = list_beads_paged(&pool, None, 2).await.unwrap();
+  assert_eq!(first.items.len(), 2);
+  let cursor = first.next_cursor.expect("First page should have a cursor");
+
+  let second = list_beads_paged(&pool, Some(cursor), 2).await.unwrap();
+  assert_eq!(second.items.len(), 2);
+  assert!(second
+    .items
+    .iter()
+    .all(|bead| first.items.iter().all(|seen| seen.id != bead.id)));
+
+  let last = list_beads_paged(&pool, second.next_cursor, 2)
+    .await
+    .unwrap();
+  assert_eq!(last.items.len(), 1);
+  assert_eq!(last.next_cursor, None);
+
+  let titles: Vec<String> = [first.items, second.items, last.items]
+    .concat()
+    .into_iter()
+    .map(|bead| bead.title)
+    .collect();
+  assert_eq!(
+    titles,
+    vec!["Bead 0", "Bead 1", "Bead 2", "Bead 3", "Bead 4"]
+  );
+}
+
+#[tokio::test]
+async fn test_filtered_listing() {
+  let pool = migrated_pool().await;
+  for (i, status) in [BeadStatus::Open, BeadStatus::Closed, BeadStatus::Open]
+    .into_iter()
+    .enumerate()
+  {
+    let mut bead = new_bead(&format!("Bead {i}"));
+    bead.status = status;
+    create_bead(&pool, &bead).await.unwrap();
+  }
+
+  let filter = BeadFilter {
+    status: Some(BeadStatus::Open),
+    priority: None,
+  };
+  let first = list_beads_filtered(&pool, filter, None, 1).await.unwrap();
+  let second = list_beads_filtered(&pool, filter, first.next_cursor, 1)
+    .await
+    .unwrap();
+
+  assert_eq!(first.items[0].title, "Bead 0");
+  assert_eq!(second.items[0].title, "Bead 2");
+  assert_eq!(second.next_cursor, None);
+
+  let high = BeadFilter {
+    status: None,
+    priority: Some(BeadPriority::HIGH),
+  };
+  let none = list_beads_filtered(&pool, high, None, 10).await.unwrap();
+  assert!(none.items.is_empty());
+}
+
+#[tokio::test]
+async fn test_sorted_listing_by_status_follows_workflow() {
+  let pool = migrated_pool().await;
+  for status in [BeadStatus::Closed, BeadStatus::Open, BeadStatus::Blocked] {
+    let mut bead = new_bead(&status.to_string());
+    bead.status = status;
+    create_bead(&pool, &bead).await.unwrap();
+  }
+
+  let sort = SortSpec::new(SortField::Status, SortOrder::Asc);
+  let first = list_beads_sorted(&pool, BeadFilter::default(), sort, None, 2)
+    .await
+    .unwrap();
+  let rest = list_beads_sorted(&pool, BeadFilter::default(), sort, first.next_cursor, 2)
+    .await
+    .unwrap();
+
+  let titles: Vec<String> = [first.items, rest.items]
+    .concat()
+    .into_iter()
+    .map(|bead| bead.title)
+    .collect();
+  assert_eq!(titles, vec!["open", "blocked", "closed"]);
+  assert_eq!(rest.next_cursor, None);
+}
+
+#[tokio::test]
+async fn test_blocked_beads_follow_dependency_status() {
+  let pool = migrated_pool().await;
+  let open = create_bead(&pool, &new_bead("Open")).await.unwrap();
+  let mut closed = new_bead("Closed");
+  closed.status = BeadStatus::Closed;
+  let closed = create_bead(&pool, &closed).await.unwrap();
+  let waiting = create_bead(&pool, &new_bead("Waiting")).await.unwrap();
+  let ready = create_bead(&pool, &new_bead("Ready")).await.unwrap();
+
+  for (bead_id, depends_on) in [
+    (waiting.id, open.id),
+    (waiting.id, closed.id),
+    (ready.id, closed.id),
+  ] {
+    add_bead_dependency(
+      &pool,
+      &BeadDependency {
+        bead_id,
+        depends_on,
+      },
+    )
+    .await
+    .unwrap();
+  }
+
+  let blocked: Vec<String> = blocked_beads(&pool)
+    .await
+    .unwrap()
+    .into_iter()
+    .map(|bead| bead.title)
+    .collect();
+  assert_eq!(blocked, vec!["Waiting"]);
+
+  let duplicate = BeadDependency {
+    bead_id: ready.id,
+    depends_on: closed.id,
+  };
+  assert!(matches!(
+    add_bead_dependency(&pool, &duplicate).await,
+    Err(DbError::Duplicate(_))
+  ));
+  let itself = BeadDependency {
+    bead_id: ready.id,
+    depends_on: ready.id,
+  };
+  assert!(matches!(
+    add_bead_dependency(&pool, &itself).await,
+    Err(DbError::Validation(_))
+  ));
+}
@@ -0,0 +1,199 @@
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+#![allow(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Integration tests for the `SQLite` session repository
+//!
+//! Each test migrates a fresh in-memory database, so no external
+//! database is required.
+
+use crate::analysis::{AnalysisId, AnalysisResult};
+use crate::db::{
+  create_session, create_sqlite_pool, get_session, list_interview_sessions, run_sqlite_migrations,
+  update_session, DbError, SqliteDbConfig,
+};
+use crate::interview::{AnswerValue, Interview, InterviewId, Question, QuestionType};
+use crate::quality::QualityScore;
+use crate::session::{Session, SessionId, SessionKind, SessionResult, SessionState, Timestamp};
+use sqlx::SqlitePool;
+
+const INTERVIEW_ID: &str = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
+
+async fn migrated_pool() -> SqlitePool {
+  // A single connection keeps every query on the same in-memory database
+  let config = SqliteDbConfig::in_memory().with_max_connections(1);
+  let pool = create_sqlite_pool(&config)
+    .await
+    .expect("Failed to create in-memory pool");
+  run_sqlite_migrations(&pool)
+    .await
+    .expect("Failed to run migrations");
+  pool
+}
+
+fn interview_id() -> InterviewId {
+  InterviewId::new(INTERVIEW_ID.to_string()).unwrap()
+}
+
+fn interview_session(created_at: i64) -> Session {
+  Session::new(
+    SessionId::generate(),
+    SessionKind::Interview,
+    Some(interview_id()),
+    Timestamp::from_secs(created_at),
+  )
+  .unwrap()
+}
+
+#[tokio::test]
+async fn test_session_round_trip_keeps_interview_link() {
+  let pool = migrated_pool().await;
+  let session = Session {
+    title: Some("Onboarding".to_string()),
+    ..interview_session(1_000)
+  };
+
+  create_session(&pool, &session).await.unwrap();
+  let fetched = get_session(&pool, &session.id).await.unwrap();
+
+  assert_eq!(fetched, session);
+  assert_eq!(fetched.interview_id, Some(interview_id()));
+}
+
+#[tokio::test]
+async fn test_update_session_stores_lifecycle_and_result() {
+  let pool = migrated_pool().await;
+  let session = interview_session(1_000);
+  create_session(&pool, &session).await.unwrap();
+
+  let interview = Interview::builder()
+    .id(INTERVIEW_ID.to_string())
+    .spec_name("petstore".to_string())
+    .created_at(Timestamp::from_secs(1_000))
+    .add_question(Question {
+      id: Some("name".to_string()),
+      text: "What is your name?".to_string(),
+      help_text: None,
+      required: true,
+      question_type: QuestionType::Text,
+    })
+    .build()
+    .unwrap()
+    .submit_answer(
+      0,
+      AnswerValue::Text("Ada".to_string()),
+      Timestamp::from_secs(1_200),
+    )
+    .unwrap();
+  let completed = session
+    .transition_to(SessionState::InProgress, Timestamp::from_secs(1_500))
+    .and_then(|s| {
+      s.complete_with(
+        SessionResult::Interview(interview),
+        Timestamp::from_secs(2_000),
+      )
+    })
+    .unwrap();
+  update_session(&pool, &completed).await.unwrap();
+  assert_eq!(get_session(&pool, &session.id).await.unwrap(), completed);
+
+  let deleted = Session {
+    deleted_at: Some(Timestamp::from_secs(3_000)),
+    ..completed
+  };
+  update_session(&pool, &deleted).await.unwrap();
+  let fetched = get_session(&pool, &session.id).await.unwrap();
+  assert_eq!(fetched, deleted);
+}
+
+#[tokio::test]
+async fn test_analysis_result_round_trip() {
+  let pool = migrated_pool().await;
+  let analysis = AnalysisResult::new(
+    AnalysisId::generate(),
+    "petstore",
+    QualityScore::new(90).unwrap(),
+    Timestamp::from_secs(1_900),
+  );
+  let session = Session::new(
+    SessionId::generate(),
+    SessionKind::Analysis,
+    None,
+    Timestamp::from_secs(1_000),
+  )
+  .and_then(|s| s.transition_to(SessionState::InProgress, Timestamp::from_secs(1_500)))
+  .and_then(|s| {
+    s.complete_with(
+      SessionResult::Analysis(analysis),
+      Timestamp::from_secs(2_000),
+    )
+  })
+  .unwrap();
+
+  create_session(&pool, &session).await.unwrap();
+
+  assert_eq!(get_session(&pool, &session.id).await.unwrap(), session);
+}
+
+#[tokio::test]
+async fn test_list_interview_sessions_oldest_first() {
+  let pool = migrated_pool().await;
+  let later = interview_session(2_000);
+  let earlier = interview_session(1_000);
+  let planning = Session::new(
+    SessionId::generate(),
+    SessionKind::Planning,
+    None,
+    Timestamp::from_secs(500),
+  )
+  .unwrap();
+  for session in [&later, &earlier, &planning] {
+    create_session(&pool, session).await.unwrap();
+  }
+
+  let sessions = list_interview_sessions(&pool, &interview_id())
+    .await
+    .unwrap();
+
+  assert_eq!(sessions, vec![earlier, later]);
+}
+
+#[tokio::test]
+async fn test_missing_and_duplicate_sessions() {
+  let pool = migrated_pool().await;
+  let session = interview_session(1_000);
+
+  assert!(matches!(
+    get_session(&pool, &session.id).await,
+    Err(DbError::NotFound { .. })
+  ));
+  assert!(matches!(
+    update_session(&pool, &session).await,
+    Err(DbError::NotFound { .. })
+  ));
+
+  create_session(&pool, &session).await.unwrap();
+  assert!(matches!(
+    create_session(&pool, &session).await,
+    Err(DbError::Duplicate(_))
+  ));
+}
+
+#[tokio::test]
+async fn test_interview_link_is_enforced_by_the_table() {
+  let pool = migrated_pool().await;
+  let result = sqlx::query(
+    "INSERT INTO sessions (id, kind, state, created_at, updated_at) \
+     VALUES (?, 'interview', 'created', ?, ?)",
+  )
+  .bind(SessionId::generate().as_str())
+  .bind(Timestamp::from_secs(1_000).to_rfc3339())
+  .bind(Timestamp::from_secs(1_000).to_rfc3339())
+  .execute(&pool)
+  .await;
+
+  assert!(result.is_err());
+}
@@ -18,6 +18,9 @@
 use std::fmt::{self, Display};
 
 use super::db::error::DbError;
+use super::interview::InterviewError;
+use super::schema_registry::SchemaRegistryError;
+use super::session::SessionError;
 use super::validation::ValidationError;
 
 /// Exit code for CLI processes
@@ -55,6 +58,9 @@ impl ExitCode {
   /// File not found
   pub const NOT_FOUND: Self = Self(8);
 
+  /// Internal software error (`EX_SOFTWARE` in sysexits.h)
+  pub const SOFTWARE: Self = Self(70);
+
   /// Create a new `ExitCode`, ensuring it's within 0-255
   ///
   /// # Errors
@@ -143,9 +149,75 @@ pub const fn map_validation_error(error: &ValidationError) -> Result<ExitCode, E
   }
 }
 
+/// Map session errors to appropriate exit codes
+///
+/// # Errors
+///
+/// Returns `ExitCodeError::OutOfRange` if the mapped exit code is > 255
+pub const fn map_session_error(error: &SessionError) -> Result<ExitCode, ExitCodeError> {
+  match error {
+    SessionError::InvalidIdFormat(_)
+    | SessionError::InvalidState(_)
+    | SessionError::InvalidKind(_)
+    | SessionError::MissingField(_)
+    | SessionError::UnexpectedInterviewId { .. }
+    | SessionError::ResultKindMismatch { .. } => Ok(ExitCode::USAGE),
+    SessionError::InvalidStateTransition { .. }
+    | SessionError::AlreadyExists(_)
+    | SessionError::StaleUpdate(_) => Ok(ExitCode::ERROR),
+    SessionError::NotFound(_) => Ok(ExitCode::NOT_FOUND),
+    SessionError::SystemTimeInvalid => Ok(ExitCode::SOFTWARE),
+  }
+}
+
+/// Map interview errors to appropriate exit codes
+///
+/// # Errors
+///
+/// Returns `ExitCodeError::OutOfRange` if the mapped exit code is > 255
+pub const fn map_interview_error(error: &InterviewError) -> Result<ExitCode, ExitCodeError> {
+  match error {
+    InterviewError::InvalidIdFormat(_)
+    | InterviewError::InvalidState(_)
+    | InterviewError::MissingField(_)
+    | InterviewError::EmptySpecName
+    | InterviewError::InvalidQuestionIndex(_) => Ok(ExitCode::USAGE),
+    InterviewError::AnswerTypeMismatch { .. }
+    | InterviewError::MissingRequiredAnswers(_)
+    | InterviewError::InvalidQuestionId(_)
+    | InterviewError::DuplicateQuestionId(_) => Ok(ExitCode::VALIDATION_ERROR),
+    InterviewError::InvalidStateTransition { .. }
+    | InterviewError::NotAcceptingAnswers(_)
+    | InterviewError::AlreadyExists(_) => Ok(ExitCode::ERROR),
+    InterviewError::NotFound(_) => Ok(ExitCode::NOT_FOUND),
+    InterviewError::SystemTimeInvalid => Ok(ExitCode::SOFTWARE),
+  }
+}
+
+/// Map schema registry errors to appropriate exit codes
+///
+/// # Errors
+///
+/// Returns `ExitCodeError::OutOfRange` if the mapped exit code is > 255
+pub const fn map_schema_registry_error(
+  error: &SchemaRegistryError,
+) -> Result<ExitCode, ExitCodeError> {
+  match error {
+    SchemaRegistryError::InvalidId(_) | SchemaRegistryError::InvalidVersion(_) => {
+      Ok(ExitCode::USAGE)
+    }
+    SchemaRegistryError::DuplicateSchema { .. } => Ok(ExitCode::ERROR),
+    SchemaRegistryError::NotFound { .. } | SchemaRegistryError::VersionNotFound { .. } => {
+      Ok(ExitCode::NOT_FOUND)
+    }
+    SchemaRegistryError::ValidationError { .. } => Ok(ExitCode::VALIDATION_ERROR),
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::interview::InterviewState;
   #[allow(clippy::unwrap_used)]
   #[allow(clippy::expect_used)]
   #[allow(clippy::float_cmp)]
@@ -337,4 +409,60 @@ mod tests {
     let result = map_validation_error(&error);
     assert_eq!(result, Ok(ExitCode::USAGE));
   }
+
+  #[test]
+  fn test_software_constant() {
+    assert_eq!(ExitCode::SOFTWARE.as_u8(), 70);
+  }
+
+  #[test]
+  fn test_map_session_invalid_id_format() {
+    let error = SessionError::InvalidIdFormat("not-a-uuid".to_string());
+    assert_eq!(map_session_error(&error), Ok(ExitCode::USAGE));
+  }
+
+  #[test]
+  fn test_map_session_system_time_invalid() {
+    assert_eq!(
+      map_session_error(&SessionError::SystemTimeInvalid),
+      Ok(ExitCode::SOFTWARE)
+    );
+  }
+
+  #[test]
+  fn test_map_session_not_found() {
+    let error = SessionError::NotFound("id".to_string());
+    assert_eq!(map_session_error(&error), Ok(ExitCode::NOT_FOUND));
+  }
+
+  #[test]
+  fn test_map_interview_errors() {
+    assert_eq!(
+      map_interview_error(&InterviewError::EmptySpecName),
+      Ok(ExitCode::USAGE)
+    );
+    assert_eq!(
+      map_interview_error(&InterviewError::MissingRequiredAnswers(vec![0])),
+      Ok(ExitCode::VALIDATION_ERROR)
+    );
+    assert_eq!(
+      map_interview_error(&InterviewError::NotAcceptingAnswers(
+        InterviewState::Completed
+      )),
+      Ok(ExitCode::ERROR)
+    );
+    assert_eq!(
+      map_interview_error(&InterviewError::SystemTimeInvalid),
+      Ok(ExitCode::SOFTWARE)
+    );
+  }
+
+  #[test]
+  fn test_map_schema_registry_not_found() {
+    let error = SchemaRegistryError::VersionNotFound {
+      id: "spec".to_string(),
+      version: "1.0.0".to_string(),
+    };
+    assert_eq!(map_schema_registry_error(&error), Ok(ExitCode::NOT_FOUND));
+  }
 }
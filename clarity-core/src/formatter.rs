@@ -398,18 +398,21 @@ mod tests {
       .title("Requirements Gathering".to_string())
       .description("Gather system requirements from stakeholders".to_string())
       .add_question(Question {
+        id: None,
         text: "What are the main features?".to_string(),
         help_text: Some("List the top 3-5 features".to_string()),
         required: true,
         question_type: QuestionType::Text,
       })
       .add_question(Question {
+        id: None,
         text: "Is performance critical?".to_string(),
         help_text: None,
         required: true,
         question_type: QuestionType::Boolean,
       })
       .add_question(Question {
+        id: None,
         text: "What is the target platform?".to_string(),
         help_text: Some("e.g., Web, Mobile, Desktop".to_string()),
         required: false,
@@ -634,6 +637,7 @@ mod tests {
 
     for i in 0..100 {
       builder = builder.add_question(Question {
+        id: None,
         text: format!("Question {}", i),
         help_text: Some(format!("Help text for question {}", i)),
         required: i % 2 == 0,
@@ -0,0 +1,39 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Stable, non-cryptographic hashing
+//!
+//! For values that are persisted or sent to clients, such as slugs and
+//! `ETag`s, where `std`'s hasher is unsuitable because its output may change
+//! between releases.
+
+/// 64-bit FNV-1a hash, stable across builds, platforms and releases
+///
+/// A `const fn`, so hashes of embedded data can be computed at compile time.
+#[must_use]
+pub const fn fnv1a(bytes: &[u8]) -> u64 {
+  let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+  let mut i = 0;
+  while i < bytes.len() {
+    hash ^= bytes[i] as u64;
+    hash = hash.wrapping_mul(0x0100_0000_01b3);
+    i += 1;
+  }
+  hash
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_fnv1a_known_values() {
+    assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+    assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    assert_eq!(fnv1a(b"foobar"), 0x8594_4171_f739_67e8);
+  }
+}
@@ -19,10 +19,19 @@
 //! - No unwraps or panics
 //! - Result types for error handling
 
+use crate::progress::{ProgressMetrics, ProgressStatus};
+use crate::types::is_valid_uuid;
+use crate::types::time::InvalidSystemTime;
+pub use crate::types::time::Timestamp;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::fmt::{self, Display};
 use thiserror::Error;
 
+mod store;
+
+pub use store::InterviewStore;
+
 /// Unique identifier for an interview
 ///
 /// Interview IDs are strongly typed wrappers around UUIDs.
@@ -58,6 +67,14 @@ impl InterviewId {
     }
   }
 
+  /// Creates a fresh random (v4) `InterviewId`
+  ///
+  /// Use [`InterviewId::new`] to rehydrate a stored id instead.
+  #[must_use]
+  pub fn generate() -> Self {
+    Self(uuid::Uuid::new_v4().to_string())
+  }
+
   /// Get the underlying UUID string
   #[must_use]
   pub fn as_str(&self) -> &str {
@@ -74,7 +91,9 @@ impl Display for InterviewId {
 /// The state of an interview in its lifecycle
 ///
 /// Interviews follow a strict state machine to prevent invalid transitions.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Serializes as its `Display` form, e.g. `"in_progress"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum InterviewState {
   /// Interview has been created but not started
   Created,
@@ -104,9 +123,28 @@ impl Display for InterviewState {
   }
 }
 
+impl std::str::FromStr for InterviewState {
+  type Err = InterviewError;
+
+  /// Parse a state from its `Display` form, e.g. `in_progress`
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "created" => Ok(Self::Created),
+      "in_progress" => Ok(Self::InProgress),
+      "completed" => Ok(Self::Completed),
+      "failed" => Ok(Self::Failed),
+      "cancelled" => Ok(Self::Cancelled),
+      _ => Err(InterviewError::InvalidState(s.to_string())),
+    }
+  }
+}
+
 /// An interview question with validation
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Question {
+  /// Stable identifier, used as the answer key in `Interview::answers_map`
+  pub id: Option<String>,
+
   /// Question text
   pub text: String,
 
@@ -121,7 +159,10 @@ pub struct Question {
 }
 
 /// Type of interview question
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Serializes as its `Display` form, e.g. `"multiple_choice"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum QuestionType {
   /// Free-form text input
   Text,
@@ -136,11 +177,37 @@ pub enum QuestionType {
   Numeric,
 }
 
+impl QuestionType {
+  /// Empty answer of this type, for pre-filling an input
+  ///
+  /// `Text("")`, `Boolean(false)`, `MultipleChoice(0)` or `Numeric(0)`.
+  #[must_use]
+  pub const fn default_answer(&self) -> AnswerValue {
+    match self {
+      Self::Text => AnswerValue::Text(String::new()),
+      Self::Boolean => AnswerValue::Boolean(false),
+      Self::MultipleChoice => AnswerValue::MultipleChoice(0),
+      Self::Numeric => AnswerValue::Numeric(0),
+    }
+  }
+}
+
+impl Display for QuestionType {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Text => write!(f, "text"),
+      Self::Boolean => write!(f, "boolean"),
+      Self::MultipleChoice => write!(f, "multiple_choice"),
+      Self::Numeric => write!(f, "numeric"),
+    }
+  }
+}
+
 /// An interview in the Clarity system
 ///
 /// Interviews represent structured conversations to gather requirements.
 /// They are immutable snapshots - state transitions create new Interview instances.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Interview {
   /// Unique identifier for this interview
   pub id: InterviewId,
@@ -215,6 +282,32 @@ impl Interview {
     })
   }
 
+  /// Start a fresh interview that asks the same questions as this one
+  ///
+  /// The copy keeps the questions, title and description, but is in the
+  /// `Created` state with no answers, whatever state this interview is in.
+  /// It is for `spec_name` if given, otherwise for this interview's spec.
+  ///
+  /// # Errors
+  ///
+  /// Returns `InterviewError::EmptySpecName` if `spec_name` is empty
+  pub fn template(
+    &self,
+    new_id: InterviewId,
+    now: Timestamp,
+    spec_name: Option<String>,
+  ) -> Result<Self, InterviewError> {
+    let spec_name = spec_name.unwrap_or_else(|| self.spec_name.clone());
+    let fresh = Self::new(new_id, spec_name, now)?;
+
+    Ok(Self {
+      questions: self.questions.clone(),
+      title: self.title.clone(),
+      description: self.description.clone(),
+      ..fresh
+    })
+  }
+
   /// Create a builder for constructing an Interview
   #[must_use]
   pub fn builder() -> InterviewBuilder {
@@ -287,6 +380,269 @@ impl Interview {
   pub const fn is_active(&self) -> bool {
     !self.is_terminal()
   }
+
+  /// Check if the interview is active and has not been updated for more than `ttl_secs`
+  #[must_use]
+  pub const fn is_expired(&self, now: Timestamp, ttl_secs: i64) -> bool {
+    self.is_active() && now.as_secs().saturating_sub(self.updated_at.as_secs()) > ttl_secs
+  }
+
+  /// Cancel the interview if it has expired
+  ///
+  /// An interview that is not expired, including one already in a terminal
+  /// state, is returned unchanged.
+  ///
+  /// # Errors
+  ///
+  /// Returns `InterviewError::InvalidStateTransition` if the interview cannot be cancelled
+  pub fn expire(&self, now: Timestamp, ttl_secs: i64) -> Result<Self, InterviewError> {
+    if self.is_expired(now, ttl_secs) {
+      self.transition_to(InterviewState::Cancelled, now)
+    } else {
+      Ok(self.clone())
+    }
+  }
+
+  /// Record an answer to one of the interview's questions
+  ///
+  /// Any previous answer to the same question is replaced.
+  ///
+  /// # Errors
+  ///
+  /// Returns `InterviewError::NotAcceptingAnswers` if the interview is completed,
+  /// failed or cancelled
+  /// Returns `InterviewError::InvalidQuestionIndex` if no question exists at `question_index`
+  /// Returns `InterviewError::AnswerTypeMismatch` if the value does not match the question type
+  pub fn submit_answer(
+    &self,
+    question_index: usize,
+    value: AnswerValue,
+    updated_at: Timestamp,
+  ) -> Result<Self, InterviewError> {
+    if self.is_terminal() {
+      return Err(InterviewError::NotAcceptingAnswers(self.state));
+    }
+
+    let question = self
+      .questions
+      .get(question_index)
+      .ok_or(InterviewError::InvalidQuestionIndex(question_index))?;
+
+    if !value.matches(question.question_type) {
+      return Err(InterviewError::AnswerTypeMismatch {
+        index: question_index,
+        expected: question.question_type,
+      });
+    }
+
+    let answers = self
+      .answers
+      .iter()
+      .filter(|answer| answer.question_index != question_index)
+      .cloned()
+      .chain(std::iter::once(Answer {
+        question_index,
+        value,
+      }))
+      .collect();
+
+    Ok(Self {
+      answers,
+      updated_at,
+      ..self.clone()
+    })
+  }
+
+  /// Move the question at `from` so it ends up at index `to`
+  ///
+  /// Questions in between shift by one, and every answer's `question_index`
+  /// is remapped so answers stay attached to their questions.
+  ///
+  /// # Errors
+  ///
+  /// Returns `InterviewError::InvalidQuestionIndex` if `from` or `to` is out of range
+  pub fn move_question(&self, from: usize, to: usize) -> Result<Self, InterviewError> {
+    for index in [from, to] {
+      if index >= self.questions.len() {
+        return Err(InterviewError::InvalidQuestionIndex(index));
+      }
+    }
+
+    let mut questions = self.questions.clone();
+    let question = questions.remove(from);
+    questions.insert(to, question);
+
+    let remap = |index: usize| {
+      if index == from {
+        to
+      } else if from < index && index <= to {
+        index - 1
+      } else if to <= index && index < from {
+        index + 1
+      } else {
+        index
+      }
+    };
+    let answers = self
+      .answers
+      .iter()
+      .map(|answer| Answer {
+        question_index: remap(answer.question_index),
+        value: answer.value.clone(),
+      })
+      .collect();
+
+    Ok(Self {
+      questions,
+      answers,
+      ..self.clone()
+    })
+  }
+
+  /// Return the interview with every unanswered question given its type's
+  /// [`QuestionType::default_answer`]
+  ///
+  /// Meant for pre-populating a form or filling optional questions. Don't
+  /// use it to get past [`Self::complete`]: a required question answered
+  /// with a default was never really answered.
+  #[must_use]
+  pub fn with_defaults(&self) -> Self {
+    let defaults = self
+      .questions
+      .iter()
+      .enumerate()
+      .filter(|(index, _)| !self.answers.iter().any(|a| a.question_index == *index))
+      .map(|(question_index, question)| Answer {
+        question_index,
+        value: question.question_type.default_answer(),
+      });
+
+    Self {
+      answers: self.answers.iter().cloned().chain(defaults).collect(),
+      ..self.clone()
+    }
+  }
+
+  /// Answers keyed by question, for filling in templates
+  ///
+  /// Each answer is keyed by its question's `id`, or by the question's index
+  /// when it has no id. Unanswered questions are left out. Interviews from
+  /// [`InterviewBuilder::build`] have unique, non-numeric ids, so no two
+  /// answers share a key.
+  #[must_use]
+  pub fn answers_map(&self) -> HashMap<String, AnswerValue> {
+    self
+      .answers
+      .iter()
+      .filter_map(|answer| {
+        let question = self.questions.get(answer.question_index)?;
+        let key = question
+          .id
+          .clone()
+          .unwrap_or_else(|| answer.question_index.to_string());
+        Some((key, answer.value.clone()))
+      })
+      .collect()
+  }
+
+  /// Render the interview as a Markdown document
+  ///
+  /// The title becomes an H1 and the description a paragraph. Each question is
+  /// a numbered list item, flagged when required, followed by its answer in a
+  /// blockquote or `_unanswered_`. User-supplied text is escaped so it renders
+  /// literally.
+  #[must_use]
+  pub fn to_markdown(&self) -> String {
+    let title = self.title.as_deref().unwrap_or("Untitled Interview");
+    let mut blocks = vec![format!("# {}", escape_markdown(title))];
+
+    if let Some(description) = &self.description {
+      blocks.push(escape_markdown(description));
+    }
+
+    for (index, question) in self.questions.iter().enumerate() {
+      let required = if question.required {
+        " **(required)**"
+      } else {
+        ""
+      };
+      let answer = self
+        .answers
+        .iter()
+        .find(|a| a.question_index == index)
+        .map_or_else(
+          || "   _unanswered_".to_string(),
+          |a| {
+            escape_markdown(&a.value.to_string())
+              .split('\n')
+              .map(|line| format!("   > {line}"))
+              .collect::<Vec<_>>()
+              .join("\n")
+          },
+        );
+      blocks.push(format!(
+        "{}. {}{required}\n\n{answer}",
+        index + 1,
+        escape_markdown(&question.text)
+      ));
+    }
+
+    let mut markdown = blocks.join("\n\n");
+    markdown.push('\n');
+    markdown
+  }
+
+  /// Progress through the questions, counting each answered question as completed
+  #[must_use]
+  pub fn progress(&self) -> ProgressMetrics {
+    let statuses: Vec<ProgressStatus> = (0..self.questions.len())
+      .map(|index| {
+        if self.answers.iter().any(|a| a.question_index == index) {
+          ProgressStatus::Completed
+        } else {
+          ProgressStatus::NotStarted
+        }
+      })
+      .collect();
+
+    ProgressMetrics::from_statuses(&statuses)
+  }
+
+  /// Indices of required questions that have no answer yet, in question order
+  #[must_use]
+  pub fn missing_required_answers(&self) -> Vec<usize> {
+    self
+      .questions
+      .iter()
+      .enumerate()
+      .filter(|(index, question)| {
+        question.required && !self.answers.iter().any(|a| a.question_index == *index)
+      })
+      .map(|(index, _)| index)
+      .collect()
+  }
+
+  /// Check if every required question has been answered
+  #[must_use]
+  pub fn is_complete(&self) -> bool {
+    self.missing_required_answers().is_empty()
+  }
+
+  /// Transition the interview to `Completed`
+  ///
+  /// # Errors
+  ///
+  /// Returns `InterviewError::MissingRequiredAnswers` if any required question is unanswered
+  /// Returns `InterviewError::InvalidStateTransition` if the interview cannot complete
+  /// from its current state
+  pub fn complete(&self, updated_at: Timestamp) -> Result<Self, InterviewError> {
+    let missing = self.missing_required_answers();
+    if !missing.is_empty() {
+      return Err(InterviewError::MissingRequiredAnswers(missing));
+    }
+
+    self.transition_to(InterviewState::Completed, updated_at)
+  }
 }
 
 /// Builder for constructing Interview instances
@@ -309,7 +665,7 @@ impl InterviewBuilder {
     Self::default()
   }
 
-  /// Set the interview ID
+  /// Set the interview ID; a random one is generated if this is not called
   #[must_use]
   pub fn id(mut self, id: String) -> Self {
     self.id = Some(id);
@@ -356,14 +712,15 @@ impl InterviewBuilder {
   /// # Errors
   ///
   /// Returns `InterviewError::MissingField` if required fields are not set
+  /// Returns `InterviewError::InvalidQuestionId` if a question id is all digits,
+  /// which would collide with the index keys of questions without an id
+  /// Returns `InterviewError::DuplicateQuestionId` if two questions share an id
   /// Returns `InterviewError::InvalidIdFormat` if the ID is not a valid UUID
   /// Returns `InterviewError::SystemTimeInvalid` if no timestamp is provided and
   /// the system time is invalid
   /// Returns `InterviewError::EmptySpecName` if `spec_name` is empty
   pub fn build(self) -> Result<Interview, InterviewError> {
-    let id = self
-      .id
-      .ok_or_else(|| InterviewError::MissingField("id".to_string()))?;
+    check_question_ids(&self.questions)?;
     let spec_name = self
       .spec_name
       .ok_or_else(|| InterviewError::MissingField("spec_name".to_string()))?;
@@ -372,7 +729,10 @@ impl InterviewBuilder {
       None => Timestamp::now()?,
     };
 
-    let interview_id = InterviewId::new(id)?;
+    let interview_id = match self.id {
+      Some(id) => InterviewId::new(id)?,
+      None => InterviewId::generate(),
+    };
     let mut interview = Interview::new(interview_id, spec_name, created_at)?;
     interview.title = self.title;
     interview.description = self.description;
@@ -382,8 +742,29 @@ impl InterviewBuilder {
   }
 }
 
+/// Check that every question id is unique and cannot be mistaken for an index
+///
+/// `Interview::answers_map` keys answers by id, or by index for questions
+/// without one, so an id like `"1"` or a repeated id would overwrite another
+/// answer.
+fn check_question_ids(questions: &[Question]) -> Result<(), InterviewError> {
+  let mut seen = HashSet::new();
+  for id in questions
+    .iter()
+    .filter_map(|question| question.id.as_deref())
+  {
+    if !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()) {
+      return Err(InterviewError::InvalidQuestionId(id.to_string()));
+    }
+    if !seen.insert(id) {
+      return Err(InterviewError::DuplicateQuestionId(id.to_string()));
+    }
+  }
+  Ok(())
+}
+
 /// An answer to an interview question
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Answer {
   /// Index of the question this answers
   pub question_index: usize,
@@ -393,7 +774,10 @@ pub struct Answer {
 }
 
 /// The value of an answer
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Serialized as `{"type": "<question type>", "value": <value>}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
 pub enum AnswerValue {
   /// Text answer
   Text(String),
@@ -408,46 +792,65 @@ pub enum AnswerValue {
   Numeric(i64),
 }
 
-/// Timestamp for interview events
-///
-/// Represented as Unix timestamp (seconds since epoch).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Timestamp(i64);
-
-impl Timestamp {
-  /// Create a new Timestamp from seconds since epoch
-  #[must_use]
-  pub const fn from_secs(secs: i64) -> Self {
-    Self(secs)
-  }
-
-  /// Get the current time as a Timestamp
-  ///
-  /// # Errors
-  ///
-  /// Returns `InterviewError::SystemTimeInvalid` if the system time is invalid
-  /// (e.g., due to clock skew or being set before `UNIX_EPOCH`)
-  pub fn now() -> Result<Self, InterviewError> {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    SystemTime::now()
-      .duration_since(UNIX_EPOCH)
-      .map(|d| Self(d.as_secs().cast_signed()))
-      .map_err(|_| InterviewError::SystemTimeInvalid)
-  }
-
-  /// Get the underlying seconds value
+impl AnswerValue {
+  /// Check whether this value is a valid answer for the given question type
   #[must_use]
-  pub const fn as_secs(&self) -> i64 {
-    self.0
+  pub const fn matches(&self, question_type: QuestionType) -> bool {
+    matches!(
+      (self, question_type),
+      (Self::Text(_), QuestionType::Text)
+        | (Self::Boolean(_), QuestionType::Boolean)
+        | (Self::MultipleChoice(_), QuestionType::MultipleChoice)
+        | (Self::Numeric(_), QuestionType::Numeric)
+    )
   }
 }
 
-impl Display for Timestamp {
+impl Display for AnswerValue {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    write!(f, "{}", self.0)
+    match self {
+      Self::Text(text) => write!(f, "{text}"),
+      Self::Boolean(true) => write!(f, "yes"),
+      Self::Boolean(false) => write!(f, "no"),
+      Self::MultipleChoice(choice) => write!(f, "option {choice}"),
+      Self::Numeric(number) => write!(f, "{number}"),
+    }
   }
 }
 
+/// Backslash-escape characters Markdown would otherwise interpret
+///
+/// Inline markup characters are escaped everywhere. At the start of a line,
+/// `-` and `+` (bullet lists), `=` (setext heading underlines) and the `.` or
+/// `)` after a run of digits (ordered lists) are escaped too.
+fn escape_markdown(text: &str) -> String {
+  text
+    .lines()
+    .map(|line| {
+      let digits = line.bytes().take_while(u8::is_ascii_digit).count();
+      let mut escaped = String::with_capacity(line.len());
+      for (position, c) in line.char_indices() {
+        let block_marker = if position == 0 {
+          matches!(c, '-' | '+' | '=')
+        } else {
+          position == digits && matches!(c, '.' | ')')
+        };
+        if block_marker
+          || matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|' | '~'
+          )
+        {
+          escaped.push('\\');
+        }
+        escaped.push(c);
+      }
+      escaped
+    })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
 /// Errors that can occur when working with interviews
 #[derive(Debug, Error, Clone, PartialEq, Eq)]
 pub enum InterviewError {
@@ -455,6 +858,10 @@ pub enum InterviewError {
   #[error("invalid interview ID format: {0}")]
   InvalidIdFormat(String),
 
+  /// A state name that is not one of the `InterviewState` variants
+  #[error("unknown interview state: {0}")]
+  InvalidState(String),
+
   /// Attempted an invalid state transition
   #[error("invalid state transition from {from} to {to}")]
   InvalidStateTransition {
@@ -477,17 +884,43 @@ pub enum InterviewError {
   /// Invalid question index
   #[error("invalid question index: {0}")]
   InvalidQuestionIndex(usize),
+
+  /// Answer value does not match the type of the question it answers
+  #[error("answer to question {index} must be of type {expected}")]
+  AnswerTypeMismatch {
+    index: usize,
+    expected: QuestionType,
+  },
+
+  /// No interview exists with the given id
+  #[error("interview not found: {0}")]
+  NotFound(String),
+
+  /// An interview with the given id already exists
+  #[error("interview already exists: {0}")]
+  AlreadyExists(String),
+
+  /// Interview cannot complete while required questions are unanswered
+  #[error("required questions not answered: {0:?}")]
+  MissingRequiredAnswers(Vec<usize>),
+
+  /// A question id is all digits, so it could collide with an index key
+  #[error("question id {0:?} must not be a number")]
+  InvalidQuestionId(String),
+
+  /// Two questions share the same id
+  #[error("duplicate question id: {0}")]
+  DuplicateQuestionId(String),
+
+  /// Interview has reached a terminal state and no longer takes answers
+  #[error("interview is {0} and no longer accepts answers")]
+  NotAcceptingAnswers(InterviewState),
 }
 
-/// Check if a string is a valid UUID format
-fn is_valid_uuid(s: &str) -> bool {
-  // Simple UUID format validation
-  // UUIDs are 36 characters: 8-4-4-4-12 hex digits
-  s.len() == 36
-    && s.split('-').enumerate().all(|(i, part)| {
-      let expected_len = [8, 4, 4, 4, 12][i];
-      part.len() == expected_len && part.bytes().all(|b| b.is_ascii_hexdigit())
-    })
+impl From<InvalidSystemTime> for InterviewError {
+  fn from(_: InvalidSystemTime) -> Self {
+    Self::SystemTimeInvalid
+  }
 }
 
 /// Check if a state transition is valid
@@ -564,6 +997,31 @@ mod tests {
     assert_eq!(format!("{}", InterviewState::Cancelled), "cancelled");
   }
 
+  #[test]
+  fn test_interview_state_round_trips_through_display() {
+    for state in [
+      InterviewState::Created,
+      InterviewState::InProgress,
+      InterviewState::Completed,
+      InterviewState::Failed,
+      InterviewState::Cancelled,
+    ] {
+      assert_eq!(state.to_string().parse(), Ok(state));
+      assert_eq!(
+        serde_json::to_string(&state).ok(),
+        Some(format!("\"{state}\""))
+      );
+      assert_eq!(
+        serde_json::from_str::<InterviewState>(&format!("\"{state}\"")).ok(),
+        Some(state)
+      );
+    }
+    assert!(matches!(
+      "InProgress".parse::<InterviewState>(),
+      Err(InterviewError::InvalidState(_))
+    ));
+  }
+
   #[test]
   fn test_interview_new() {
     let id_result = InterviewId::new("550e8400-e29b-41d4-a716-446655440000".to_string());
@@ -679,12 +1137,14 @@ mod tests {
       .id("550e8400-e29b-41d4-a716-446655440000".to_string())
       .spec_name("my_spec".to_string())
       .add_question(Question {
+        id: None,
         text: "What is your name?".to_string(),
         help_text: None,
         required: true,
         question_type: QuestionType::Text,
       })
       .add_question(Question {
+        id: None,
         text: "Do you like Rust?".to_string(),
         help_text: Some("Please answer honestly".to_string()),
         required: true,
@@ -704,18 +1164,20 @@ mod tests {
   }
 
   #[test]
-  fn test_interview_builder_missing_id() {
+  fn test_interview_id_generate_is_valid_uuid() {
+    let id = InterviewId::generate();
+
+    assert!(is_valid_uuid(id.as_str()));
+    assert_ne!(id, InterviewId::generate());
+  }
+
+  #[test]
+  fn test_interview_builder_generates_missing_id() {
     let result = Interview::builder()
       .spec_name("my_spec".to_string())
       .build();
 
-    assert!(result.is_err());
-    match result {
-      Err(InterviewError::MissingField(field)) => {
-        assert_eq!(field, "id");
-      }
-      _ => panic!("Expected MissingField error for 'id'"),
-    }
+    assert!(result.is_ok_and(|interview| is_valid_uuid(interview.id.as_str())));
   }
 
   #[test]
@@ -990,7 +1452,8 @@ mod tests {
       Err(_) => panic!("Expected Ok Interview"),
     };
 
-    let failed_result = in_progress.transition_to(InterviewState::Failed, Timestamp::from_secs(1_234_567_892));
+    let failed_result =
+      in_progress.transition_to(InterviewState::Failed, Timestamp::from_secs(1_234_567_892));
 
     assert!(failed_result.is_ok());
     let failed = match failed_result {
@@ -1045,7 +1508,8 @@ mod tests {
     };
     assert!(completed.is_terminal());
 
-    let failed_result = in_progress.transition_to(InterviewState::Failed, Timestamp::from_secs(1_234_567_892));
+    let failed_result =
+      in_progress.transition_to(InterviewState::Failed, Timestamp::from_secs(1_234_567_892));
 
     assert!(failed_result.is_ok());
     let failed = match failed_result {
@@ -1194,23 +1658,6 @@ mod tests {
     assert_eq!(format!("{error}"), "invalid question index: 42");
   }
 
-  #[test]
-  fn test_is_valid_uuid_valid() {
-    assert!(is_valid_uuid("550e8400-e29b-41d4-a716-446655440000"));
-    assert!(is_valid_uuid("00000000-0000-0000-0000-000000000000"));
-    assert!(is_valid_uuid("ffffffff-ffff-ffff-ffff-ffffffffffff"));
-  }
-
-  #[test]
-  fn test_is_valid_uuid_invalid() {
-    assert!(!is_valid_uuid("not-a-uuid"));
-    assert!(!is_valid_uuid(""));
-    assert!(!is_valid_uuid("550e8400-e29b-41d4-a716"));
-    assert!(!is_valid_uuid("550e8400-e29b-41d4-a716-446655440000-extra"));
-    assert!(!is_valid_uuid("550e8400-e29b-41d4-a716-44665544000g"));
-    assert!(!is_valid_uuid("550e8400e29b41d4a716446655440000"));
-  }
-
   #[test]
   fn test_is_valid_transition_same_state() {
     assert!(is_valid_transition(
@@ -1313,6 +1760,7 @@ mod tests {
   #[test]
   fn test_question_creation() {
     let question = Question {
+      id: None,
       text: "What is your name?".to_string(),
       help_text: Some("Enter your full name".to_string()),
       required: true,
@@ -1335,4 +1783,507 @@ mod tests {
     assert_eq!(answer.question_index, 0);
     assert_eq!(answer.value, AnswerValue::Text("Alice".to_string()));
   }
+
+  fn interview_with_questions() -> Interview {
+    let result = Interview::builder()
+      .id("550e8400-e29b-41d4-a716-446655440000".to_string())
+      .spec_name("my_spec".to_string())
+      .created_at(Timestamp::from_secs(1_000))
+      .add_question(Question {
+        id: None,
+        text: "What is your name?".to_string(),
+        help_text: None,
+        required: true,
+        question_type: QuestionType::Text,
+      })
+      .add_question(Question {
+        id: None,
+        text: "Do you like Rust?".to_string(),
+        help_text: None,
+        required: false,
+        question_type: QuestionType::Boolean,
+      })
+      .build();
+
+    match result {
+      Ok(i) => i,
+      Err(_) => panic!("Expected Ok Interview"),
+    }
+  }
+
+  #[test]
+  fn test_answers_map_keys_by_id_or_index() {
+    let mut interview = interview_with_questions();
+    interview.questions[0].id = Some("name".to_string());
+    interview.questions.push(Question {
+      id: Some("editor".to_string()),
+      text: "Which editor do you use?".to_string(),
+      help_text: None,
+      required: false,
+      question_type: QuestionType::Text,
+    });
+
+    let result = interview
+      .submit_answer(
+        0,
+        AnswerValue::Text("Ada".to_string()),
+        Timestamp::from_secs(2_000),
+      )
+      .and_then(|i| i.submit_answer(1, AnswerValue::Boolean(true), Timestamp::from_secs(2_001)));
+    let updated = match result {
+      Ok(i) => i,
+      Err(e) => panic!("Expected Ok Interview, got {e}"),
+    };
+
+    let map = updated.answers_map();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get("name"), Some(&AnswerValue::Text("Ada".to_string())));
+    assert_eq!(map.get("1"), Some(&AnswerValue::Boolean(true)));
+    assert!(!map.contains_key("editor"));
+  }
+
+  fn question_with_id(id: Option<&str>) -> Question {
+    Question {
+      id: id.map(str::to_string),
+      text: "Question".to_string(),
+      help_text: None,
+      required: false,
+      question_type: QuestionType::Text,
+    }
+  }
+
+  #[test]
+  fn test_builder_rejects_colliding_question_ids() {
+    let build = |ids: &[Option<&str>]| {
+      ids
+        .iter()
+        .fold(
+          Interview::builder()
+            .spec_name("my_spec".to_string())
+            .created_at(Timestamp::from_secs(1_000)),
+          |builder, id| builder.add_question(question_with_id(*id)),
+        )
+        .build()
+        .map(|interview| interview.questions.len())
+    };
+
+    assert_eq!(
+      build(&[Some("name"), None, Some("name")]),
+      Err(InterviewError::DuplicateQuestionId("name".to_string()))
+    );
+    assert_eq!(
+      build(&[Some("1"), None]),
+      Err(InterviewError::InvalidQuestionId("1".to_string()))
+    );
+    assert_eq!(build(&[Some("name"), None, Some("q1")]), Ok(3));
+  }
+
+  #[test]
+  fn test_to_markdown_renders_questions_and_answers() {
+    let mut interview = interview_with_questions();
+    interview.title = Some("Onboarding *draft*".to_string());
+    interview.description = Some("Collects [basic] details".to_string());
+
+    let result = interview.submit_answer(
+      0,
+      AnswerValue::Text("Ada_Lovelace".to_string()),
+      Timestamp::from_secs(2_000),
+    );
+    let updated = match result {
+      Ok(i) => i,
+      Err(e) => panic!("Expected Ok Interview, got {e}"),
+    };
+
+    assert_eq!(
+      updated.to_markdown(),
+      "# Onboarding \\*draft\\*\n\n\
+       Collects \\[basic\\] details\n\n\
+       1. What is your name? **(required)**\n\n   > Ada\\_Lovelace\n\n\
+       2. Do you like Rust?\n\n   _unanswered_\n"
+    );
+  }
+
+  #[test]
+  fn test_to_markdown_escapes_block_markers_at_line_start() {
+    let mut interview = interview_with_questions();
+    interview.title = Some("2024. A year".to_string());
+    interview.description = Some("1. foo\n2) bar\nUnderlined\n===\n- item\nv1.2 x)".to_string());
+
+    let markdown = interview.to_markdown();
+    assert!(markdown.starts_with(
+      "# 2024\\. A year\n\n\
+       1\\. foo\n2\\) bar\nUnderlined\n\\===\n\\- item\nv1.2 x)\n\n"
+    ));
+  }
+
+  #[test]
+  fn test_move_question_keeps_answers_attached() {
+    let mut interview = interview_with_questions();
+    interview.questions.push(Question {
+      id: None,
+      text: "Which editor do you use?".to_string(),
+      help_text: None,
+      required: false,
+      question_type: QuestionType::Text,
+    });
+
+    let result = interview
+      .submit_answer(1, AnswerValue::Boolean(true), Timestamp::from_secs(2_000))
+      .and_then(|i| {
+        i.submit_answer(
+          2,
+          AnswerValue::Text("helix".to_string()),
+          Timestamp::from_secs(2_001),
+        )
+      })
+      .and_then(|i| i.move_question(0, 2));
+    let moved = match result {
+      Ok(i) => i,
+      Err(e) => panic!("Expected Ok Interview, got {e}"),
+    };
+
+    let texts: Vec<&str> = moved.questions.iter().map(|q| q.text.as_str()).collect();
+    assert_eq!(
+      texts,
+      vec![
+        "Do you like Rust?",
+        "Which editor do you use?",
+        "What is your name?"
+      ]
+    );
+    assert_eq!(
+      moved.answers_map().get("0"),
+      Some(&AnswerValue::Boolean(true))
+    );
+    assert_eq!(
+      moved.answers_map().get("1"),
+      Some(&AnswerValue::Text("helix".to_string()))
+    );
+    assert_eq!(moved.missing_required_answers(), vec![2]);
+  }
+
+  #[test]
+  fn test_move_question_rejects_out_of_range_index() {
+    let interview = interview_with_questions();
+
+    assert_eq!(
+      interview.move_question(0, 2),
+      Err(InterviewError::InvalidQuestionIndex(2))
+    );
+    assert_eq!(
+      interview.move_question(5, 0),
+      Err(InterviewError::InvalidQuestionIndex(5))
+    );
+  }
+
+  #[test]
+  fn test_with_defaults_fills_unanswered_questions() {
+    let interview = interview_with_questions();
+    assert_eq!(
+      QuestionType::Boolean.default_answer(),
+      AnswerValue::Boolean(false)
+    );
+
+    let result = interview.submit_answer(
+      0,
+      AnswerValue::Text("Ada".to_string()),
+      Timestamp::from_secs(2_000),
+    );
+    let answered = match result {
+      Ok(i) => i,
+      Err(e) => panic!("Expected Ok Interview, got {e}"),
+    };
+
+    let filled = answered.with_defaults();
+    let map = filled.answers_map();
+    assert_eq!(map.get("0"), Some(&AnswerValue::Text("Ada".to_string())));
+    assert_eq!(map.get("1"), Some(&AnswerValue::Boolean(false)));
+    assert_eq!(filled.answers.len(), 2);
+  }
+
+  #[test]
+  fn test_template_starts_fresh_from_completed_interview() {
+    let mut source = interview_with_questions();
+    source.title = Some("Onboarding".to_string());
+    let result = source
+      .submit_answer(
+        0,
+        AnswerValue::Text("Ada".to_string()),
+        Timestamp::from_secs(2_000),
+      )
+      .and_then(|i| i.transition_to(InterviewState::InProgress, Timestamp::from_secs(2_001)))
+      .and_then(|i| i.complete(Timestamp::from_secs(2_002)));
+    let completed = match result {
+      Ok(i) => i,
+      Err(e) => panic!("Expected Ok Interview, got {e}"),
+    };
+    assert_eq!(completed.state, InterviewState::Completed);
+
+    let new_id = InterviewId::generate();
+    let result = completed.template(
+      new_id.clone(),
+      Timestamp::from_secs(3_000),
+      Some("other_spec".to_string()),
+    );
+    let fresh = match result {
+      Ok(i) => i,
+      Err(e) => panic!("Expected Ok Interview, got {e}"),
+    };
+
+    assert_eq!(fresh.id, new_id);
+    assert_eq!(fresh.state, InterviewState::Created);
+    assert!(fresh.answers.is_empty());
+    assert_eq!(fresh.questions, completed.questions);
+    assert_eq!(fresh.title.as_deref(), Some("Onboarding"));
+    assert_eq!(fresh.spec_name, "other_spec");
+    assert_eq!(fresh.created_at, Timestamp::from_secs(3_000));
+    assert_eq!(fresh.updated_at, Timestamp::from_secs(3_000));
+  }
+
+  #[test]
+  fn test_submit_answer_records_answer() {
+    let interview = interview_with_questions();
+
+    let result =
+      interview.submit_answer(1, AnswerValue::Boolean(true), Timestamp::from_secs(2_000));
+    let updated = match result {
+      Ok(i) => i,
+      Err(e) => panic!("Expected Ok Interview, got {e}"),
+    };
+
+    assert_eq!(
+      updated.answers,
+      vec![Answer {
+        question_index: 1,
+        value: AnswerValue::Boolean(true),
+      }]
+    );
+    assert_eq!(updated.updated_at, Timestamp::from_secs(2_000));
+    assert!(interview.answers.is_empty());
+  }
+
+  #[test]
+  fn test_submit_answer_replaces_previous_answer() {
+    let interview = interview_with_questions();
+
+    let result = interview
+      .submit_answer(
+        0,
+        AnswerValue::Text("Alice".to_string()),
+        Timestamp::from_secs(2_000),
+      )
+      .and_then(|i| {
+        i.submit_answer(
+          0,
+          AnswerValue::Text("Bob".to_string()),
+          Timestamp::from_secs(3_000),
+        )
+      });
+    let updated = match result {
+      Ok(i) => i,
+      Err(e) => panic!("Expected Ok Interview, got {e}"),
+    };
+
+    assert_eq!(updated.answers.len(), 1);
+    assert_eq!(
+      updated.answers[0].value,
+      AnswerValue::Text("Bob".to_string())
+    );
+  }
+
+  #[test]
+  fn test_submit_answer_rejected_once_terminal() {
+    for state in [
+      InterviewState::Completed,
+      InterviewState::Failed,
+      InterviewState::Cancelled,
+    ] {
+      let interview = Interview {
+        state,
+        ..interview_with_questions()
+      };
+
+      let result =
+        interview.submit_answer(1, AnswerValue::Boolean(true), Timestamp::from_secs(2_000));
+      assert_eq!(result, Err(InterviewError::NotAcceptingAnswers(state)));
+    }
+  }
+
+  #[test]
+  fn test_submit_answer_invalid_index() {
+    let interview = interview_with_questions();
+
+    let result =
+      interview.submit_answer(2, AnswerValue::Boolean(true), Timestamp::from_secs(2_000));
+    assert_eq!(result, Err(InterviewError::InvalidQuestionIndex(2)));
+  }
+
+  #[test]
+  fn test_submit_answer_type_mismatch() {
+    let interview = interview_with_questions();
+
+    let result = interview.submit_answer(
+      1,
+      AnswerValue::Text("yes".to_string()),
+      Timestamp::from_secs(2_000),
+    );
+    assert_eq!(
+      result,
+      Err(InterviewError::AnswerTypeMismatch {
+        index: 1,
+        expected: QuestionType::Boolean,
+      })
+    );
+  }
+
+  #[test]
+  fn test_interview_error_answer_type_mismatch_display() {
+    let error = InterviewError::AnswerTypeMismatch {
+      index: 3,
+      expected: QuestionType::MultipleChoice,
+    };
+    assert_eq!(
+      format!("{error}"),
+      "answer to question 3 must be of type multiple_choice"
+    );
+  }
+
+  #[test]
+  fn test_progress_counts_answered_questions() {
+    let interview = interview_with_questions();
+    assert_eq!(interview.progress().completed, 0);
+    assert_eq!(interview.progress().total, 2);
+
+    let result = interview.submit_answer(
+      0,
+      AnswerValue::Text("Alice".to_string()),
+      Timestamp::from_secs(2_000),
+    );
+    let updated = match result {
+      Ok(i) => i,
+      Err(e) => panic!("Expected Ok Interview, got {e}"),
+    };
+
+    let progress = updated.progress();
+    assert_eq!(progress.completed, 1);
+    assert_eq!(progress.not_started, 1);
+    assert!((progress.completion_percentage - 50.0).abs() < f64::EPSILON);
+  }
+
+  #[test]
+  fn test_answer_value_serde_representation() {
+    let json = serde_json::to_value(AnswerValue::Boolean(true));
+    assert_eq!(
+      json.ok(),
+      Some(serde_json::json!({"type": "boolean", "value": true}))
+    );
+
+    let parsed: Result<AnswerValue, _> =
+      serde_json::from_value(serde_json::json!({"type": "multiple_choice", "value": 2}));
+    assert_eq!(parsed.ok(), Some(AnswerValue::MultipleChoice(2)));
+  }
+
+  #[test]
+  fn test_missing_required_answers_lists_unanswered() {
+    let interview = interview_with_questions();
+
+    assert_eq!(interview.missing_required_answers(), vec![0]);
+    assert!(!interview.is_complete());
+  }
+
+  #[test]
+  fn test_is_complete_ignores_optional_questions() {
+    let interview = interview_with_questions();
+
+    let result = interview.submit_answer(
+      0,
+      AnswerValue::Text("Ada".to_string()),
+      Timestamp::from_secs(2_000),
+    );
+
+    match result {
+      Ok(updated) => {
+        assert!(updated.missing_required_answers().is_empty());
+        assert!(updated.is_complete());
+      }
+      Err(e) => panic!("Expected Ok Interview, got {e}"),
+    }
+  }
+
+  const DAY: i64 = 24 * 60 * 60;
+
+  #[test]
+  fn test_is_expired_boundary() {
+    let interview = interview_with_questions();
+    let updated = interview.updated_at.as_secs();
+
+    assert!(!interview.is_expired(Timestamp::from_secs(updated + DAY), DAY));
+    assert!(interview.is_expired(Timestamp::from_secs(updated + DAY + 1), DAY));
+  }
+
+  #[test]
+  fn test_expire_cancels_stale_interview() {
+    let interview = interview_with_questions();
+    let now = Timestamp::from_secs(interview.updated_at.as_secs() + DAY + 1);
+
+    match interview.expire(now, DAY) {
+      Ok(expired) => {
+        assert_eq!(expired.state, InterviewState::Cancelled);
+        assert_eq!(expired.updated_at, now);
+      }
+      Err(e) => panic!("Expected Ok Interview, got {e}"),
+    }
+  }
+
+  #[test]
+  fn test_expire_leaves_fresh_and_terminal_interviews() {
+    let interview = interview_with_questions();
+    let later = Timestamp::from_secs(interview.updated_at.as_secs() + 2 * DAY);
+
+    assert_eq!(
+      interview.expire(
+        Timestamp::from_secs(interview.updated_at.as_secs() + DAY),
+        DAY
+      ),
+      Ok(interview.clone())
+    );
+
+    let cancelled = match interview.transition_to(InterviewState::Cancelled, interview.updated_at) {
+      Ok(i) => i,
+      Err(e) => panic!("Expected Ok Interview, got {e}"),
+    };
+    assert!(!cancelled.is_expired(later, DAY));
+    assert_eq!(cancelled.expire(later, DAY), Ok(cancelled));
+  }
+
+  #[test]
+  fn test_complete_with_missing_answers() {
+    let result = interview_with_questions()
+      .transition_to(InterviewState::InProgress, Timestamp::from_secs(2_000))
+      .and_then(|i| i.complete(Timestamp::from_secs(3_000)));
+
+    assert_eq!(result, Err(InterviewError::MissingRequiredAnswers(vec![0])));
+  }
+
+  #[test]
+  fn test_complete_when_all_required_answered() {
+    let result = interview_with_questions()
+      .transition_to(InterviewState::InProgress, Timestamp::from_secs(2_000))
+      .and_then(|i| {
+        i.submit_answer(
+          0,
+          AnswerValue::Text("Ada".to_string()),
+          Timestamp::from_secs(3_000),
+        )
+      })
+      .and_then(|i| i.complete(Timestamp::from_secs(4_000)));
+
+    match result {
+      Ok(completed) => {
+        assert_eq!(completed.state, InterviewState::Completed);
+        assert_eq!(completed.updated_at, Timestamp::from_secs(4_000));
+      }
+      Err(e) => panic!("Expected Ok Interview, got {e}"),
+    }
+  }
 }
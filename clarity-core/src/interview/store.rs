@@ -0,0 +1,270 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+
+//! In-memory interview store
+//!
+//! Holds interviews behind a shared lock so concurrent callers see each
+//! update atomically. Every operation reads, transforms and writes back the
+//! interview while holding the write lock, so no update is lost.
+
+use super::{AnswerValue, Interview, InterviewError, InterviewId, InterviewState, Timestamp};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Thread-safe in-memory store of interviews
+///
+/// Cloning the store is cheap and yields a handle to the same interviews.
+#[derive(Debug, Clone, Default)]
+pub struct InterviewStore {
+  interviews: Arc<RwLock<HashMap<InterviewId, Interview>>>,
+}
+
+impl InterviewStore {
+  /// Create an empty store
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Add a new interview to the store
+  ///
+  /// # Errors
+  ///
+  /// Returns `InterviewError::AlreadyExists` if an interview with the same id is stored
+  pub async fn create(&self, interview: Interview) -> Result<Interview, InterviewError> {
+    let mut interviews = self.interviews.write().await;
+    if interviews.contains_key(&interview.id) {
+      return Err(InterviewError::AlreadyExists(interview.id.to_string()));
+    }
+
+    interviews.insert(interview.id.clone(), interview.clone());
+    drop(interviews);
+    Ok(interview)
+  }
+
+  /// Get a snapshot of an interview
+  ///
+  /// # Errors
+  ///
+  /// Returns `InterviewError::NotFound` if no interview has the given id
+  pub async fn get(&self, id: &InterviewId) -> Result<Interview, InterviewError> {
+    self
+      .interviews
+      .read()
+      .await
+      .get(id)
+      .cloned()
+      .ok_or_else(|| InterviewError::NotFound(id.to_string()))
+  }
+
+  /// Get a snapshot of every stored interview, in no particular order
+  pub async fn list(&self) -> Vec<Interview> {
+    self.interviews.read().await.values().cloned().collect()
+  }
+
+  /// Record an answer on a stored interview
+  ///
+  /// # Errors
+  ///
+  /// Returns `InterviewError::NotFound` if no interview has the given id
+  /// Returns any error from [`Interview::submit_answer`]
+  pub async fn submit_answer(
+    &self,
+    id: &InterviewId,
+    question_index: usize,
+    value: AnswerValue,
+    updated_at: Timestamp,
+  ) -> Result<Interview, InterviewError> {
+    self
+      .update(id, |interview| {
+        interview.submit_answer(question_index, value, updated_at)
+      })
+      .await
+  }
+
+  /// Transition a stored interview to a new state
+  ///
+  /// # Errors
+  ///
+  /// Returns `InterviewError::NotFound` if no interview has the given id
+  /// Returns `InterviewError::InvalidStateTransition` if the transition is not allowed
+  pub async fn transition(
+    &self,
+    id: &InterviewId,
+    new_state: InterviewState,
+    updated_at: Timestamp,
+  ) -> Result<Interview, InterviewError> {
+    self
+      .update(id, |interview| {
+        interview.transition_to(new_state, updated_at)
+      })
+      .await
+  }
+
+  /// Complete a stored interview
+  ///
+  /// # Errors
+  ///
+  /// Returns `InterviewError::NotFound` if no interview has the given id
+  /// Returns any error from [`Interview::complete`]
+  pub async fn complete(
+    &self,
+    id: &InterviewId,
+    updated_at: Timestamp,
+  ) -> Result<Interview, InterviewError> {
+    self
+      .update(id, |interview| interview.complete(updated_at))
+      .await
+  }
+
+  /// Apply `operation` to a stored interview and store the result, under one write lock
+  async fn update<F>(&self, id: &InterviewId, operation: F) -> Result<Interview, InterviewError>
+  where
+    F: FnOnce(&Interview) -> Result<Interview, InterviewError>,
+  {
+    let mut interviews = self.interviews.write().await;
+    let current = interviews
+      .get(id)
+      .ok_or_else(|| InterviewError::NotFound(id.to_string()))?;
+
+    let updated = operation(current)?;
+    interviews.insert(id.clone(), updated.clone());
+    drop(interviews);
+    Ok(updated)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::interview::{Question, QuestionType};
+
+  const ID: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+  fn interview_id() -> InterviewId {
+    match InterviewId::new(ID.to_string()) {
+      Ok(id) => id,
+      Err(e) => panic!("Expected valid id, got {e}"),
+    }
+  }
+
+  fn boolean_interview(question_count: usize) -> Interview {
+    let builder = (0..question_count).fold(
+      Interview::builder()
+        .id(ID.to_string())
+        .spec_name("my_spec".to_string())
+        .created_at(Timestamp::from_secs(1_000)),
+      |builder, index| {
+        builder.add_question(Question {
+          id: None,
+          text: format!("Question {index}?"),
+          help_text: None,
+          required: true,
+          question_type: QuestionType::Boolean,
+        })
+      },
+    );
+
+    match builder.build() {
+      Ok(interview) => interview,
+      Err(e) => panic!("Expected Ok Interview, got {e}"),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_create_and_get() {
+    let store = InterviewStore::new();
+    let created = store.create(boolean_interview(1)).await;
+    assert!(created.is_ok());
+
+    let fetched = store.get(&interview_id()).await;
+    assert_eq!(fetched, created);
+  }
+
+  #[tokio::test]
+  async fn test_create_duplicate() {
+    let store = InterviewStore::new();
+    assert!(store.create(boolean_interview(1)).await.is_ok());
+
+    let result = store.create(boolean_interview(1)).await;
+    assert_eq!(result, Err(InterviewError::AlreadyExists(ID.to_string())));
+  }
+
+  #[tokio::test]
+  async fn test_get_missing() {
+    let store = InterviewStore::new();
+
+    let result = store.get(&interview_id()).await;
+    assert_eq!(result, Err(InterviewError::NotFound(ID.to_string())));
+  }
+
+  #[tokio::test]
+  async fn test_failed_operation_leaves_interview_unchanged() {
+    let store = InterviewStore::new();
+    let original = match store.create(boolean_interview(1)).await {
+      Ok(interview) => interview,
+      Err(e) => panic!("Expected Ok Interview, got {e}"),
+    };
+
+    let result = store
+      .transition(
+        &interview_id(),
+        InterviewState::Completed,
+        Timestamp::from_secs(2_000),
+      )
+      .await;
+    assert!(matches!(
+      result,
+      Err(InterviewError::InvalidStateTransition { .. })
+    ));
+    assert_eq!(store.get(&interview_id()).await, Ok(original));
+  }
+
+  #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+  async fn test_concurrent_answers_are_not_lost() {
+    const QUESTIONS: usize = 32;
+
+    let store = InterviewStore::new();
+    assert!(store.create(boolean_interview(QUESTIONS)).await.is_ok());
+
+    let tasks: Vec<_> = (0..QUESTIONS)
+      .map(|index| {
+        let store = store.clone();
+        tokio::spawn(async move {
+          store
+            .submit_answer(
+              &interview_id(),
+              index,
+              AnswerValue::Boolean(index % 2 == 0),
+              Timestamp::from_secs(2_000),
+            )
+            .await
+        })
+      })
+      .collect();
+
+    for task in tasks {
+      match task.await {
+        Ok(Ok(_)) => {}
+        Ok(Err(e)) => panic!("Expected answer to be recorded, got {e}"),
+        Err(e) => panic!("Task failed: {e}"),
+      }
+    }
+
+    let interview = match store.get(&interview_id()).await {
+      Ok(interview) => interview,
+      Err(e) => panic!("Expected Ok Interview, got {e}"),
+    };
+    assert_eq!(interview.answers.len(), QUESTIONS);
+    assert_eq!(interview.progress().completed, QUESTIONS);
+    assert!(interview.is_complete());
+    assert!(interview
+      .answers
+      .iter()
+      .all(|answer| { answer.value == AnswerValue::Boolean(answer.question_index % 2 == 0) }));
+  }
+}
@@ -201,6 +201,97 @@ impl JsonFormatter {
   }
 }
 
+/// Options for `format_json`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonFormatOptions {
+  /// Put each array element and object member on its own line
+  pub pretty: bool,
+  /// Emit object keys in lexicographic order, at every level
+  pub sort_keys: bool,
+  /// Spaces per nesting level when `pretty` is set
+  pub indent: usize,
+}
+
+impl Default for JsonFormatOptions {
+  fn default() -> Self {
+    Self {
+      pretty: false,
+      sort_keys: false,
+      indent: 2,
+    }
+  }
+}
+
+/// Formats a JSON value as text
+///
+/// With `sort_keys` the output depends only on the value's content, not on
+/// the order keys were inserted, which makes it suitable for golden files.
+#[must_use]
+pub fn format_json(value: &serde_json::Value, opts: JsonFormatOptions) -> String {
+  let mut out = String::new();
+  write_json(&mut out, value, opts, 0);
+  out
+}
+
+fn write_json(out: &mut String, value: &serde_json::Value, opts: JsonFormatOptions, depth: usize) {
+  match value {
+    serde_json::Value::Array(items) if !items.is_empty() => {
+      out.push('[');
+      for (i, item) in items.iter().enumerate() {
+        write_separator(out, opts, depth + 1, i == 0);
+        write_json(out, item, opts, depth + 1);
+      }
+      write_separator(out, opts, depth, true);
+      out.push(']');
+    }
+    serde_json::Value::Object(map) if !map.is_empty() => {
+      let mut members: Vec<_> = map.iter().collect();
+      if opts.sort_keys {
+        members.sort_by_key(|(key, _)| *key);
+      }
+      out.push('{');
+      for (i, (key, member)) in members.into_iter().enumerate() {
+        write_separator(out, opts, depth + 1, i == 0);
+        out.push_str(&serde_json::Value::String(key.clone()).to_string());
+        out.push_str(if opts.pretty { ": " } else { ":" });
+        write_json(out, member, opts, depth + 1);
+      }
+      write_separator(out, opts, depth, true);
+      out.push('}');
+    }
+    _ => out.push_str(&value.to_string()),
+  }
+}
+
+/// Writes the comma (unless `first`) and, when pretty, the line break and
+/// indentation that precede an element at `depth`
+fn write_separator(out: &mut String, opts: JsonFormatOptions, depth: usize, first: bool) {
+  if !first {
+    out.push(',');
+  }
+  if opts.pretty {
+    out.push('\n');
+    out.push_str(&" ".repeat(opts.indent * depth));
+  }
+}
+
+/// Looks up a value by RFC 6901 JSON Pointer, e.g. `/items/0/name`
+///
+/// `~1` and `~0` in a token stand for `/` and `~`. The empty pointer selects
+/// the whole value. Returns `None` for a missing key, an out-of-range index or
+/// a pointer that does not start with `/`.
+#[must_use]
+pub fn get_path<'a>(value: &'a serde_json::Value, pointer: &str) -> Option<&'a serde_json::Value> {
+  value.pointer(pointer)
+}
+
+/// Looks up a string by JSON Pointer, returning `None` if the target is
+/// missing or not a JSON string
+#[must_use]
+pub fn get_path_str<'a>(value: &'a serde_json::Value, pointer: &str) -> Option<&'a str> {
+  get_path(value, pointer).and_then(serde_json::Value::as_str)
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -380,4 +471,80 @@ mod tests {
       Some(&"Check format".to_string())
     );
   }
+
+  #[test]
+  fn test_format_json_sorted_keys_ignore_insertion_order() {
+    let mut first = serde_json::Map::new();
+    first.insert(
+      "zeta".to_string(),
+      serde_json::json!({"b": 1, "a": [true, null]}),
+    );
+    first.insert("alpha".to_string(), serde_json::json!("x"));
+    let mut second = serde_json::Map::new();
+    second.insert("alpha".to_string(), serde_json::json!("x"));
+    second.insert(
+      "zeta".to_string(),
+      serde_json::json!({"a": [true, null], "b": 1}),
+    );
+
+    let opts = JsonFormatOptions {
+      sort_keys: true,
+      ..JsonFormatOptions::default()
+    };
+    let first = format_json(&serde_json::Value::Object(first), opts);
+    let second = format_json(&serde_json::Value::Object(second), opts);
+
+    assert_eq!(first, second);
+    assert_eq!(first, r#"{"alpha":"x","zeta":{"a":[true,null],"b":1}}"#);
+  }
+
+  #[test]
+  fn test_format_json_pretty_indent() {
+    let value = serde_json::json!({"name": "spec", "tags": ["a"], "empty": {}});
+    let opts = JsonFormatOptions {
+      pretty: true,
+      sort_keys: true,
+      indent: 4,
+    };
+
+    assert_eq!(
+      format_json(&value, opts),
+      "{\n    \"empty\": {},\n    \"name\": \"spec\",\n    \"tags\": [\n        \"a\"\n    ]\n}"
+    );
+  }
+
+  #[test]
+  fn test_format_json_compact_matches_serde_json() {
+    let value = serde_json::json!({"quote": "say \"hi\"", "n": 1.5, "list": []});
+    assert_eq!(
+      format_json(&value, JsonFormatOptions::default()),
+      value.to_string()
+    );
+  }
+
+  #[test]
+  fn test_get_path_nested_objects_and_arrays() {
+    let value = serde_json::json!({
+      "data": {"beads": [{"id": "b1", "tags": ["x", "y"]}, {"id": "b2", "priority": 1}]}
+    });
+
+    assert_eq!(get_path(&value, ""), Some(&value));
+    assert_eq!(get_path_str(&value, "/data/beads/0/id"), Some("b1"));
+    assert_eq!(get_path_str(&value, "/data/beads/0/tags/1"), Some("y"));
+    assert_eq!(
+      get_path(&value, "/data/beads/1/priority"),
+      Some(&serde_json::json!(1))
+    );
+    assert_eq!(get_path_str(&value, "/data/beads/1/priority"), None);
+    assert_eq!(get_path(&value, "/data/beads/2"), None);
+    assert_eq!(get_path(&value, "/data/missing"), None);
+    assert_eq!(get_path(&value, "data"), None);
+  }
+
+  #[test]
+  fn test_get_path_escaped_tokens() {
+    let value = serde_json::json!({"a/b": {"m~n": "found"}});
+    assert_eq!(get_path_str(&value, "/a~1b/m~0n"), Some("found"));
+    assert_eq!(get_path_str(&value, "/a/b/m~n"), None);
+  }
 }
@@ -21,27 +21,67 @@
 
 //! Core functionality for the Clarity application
 
+pub mod analysis;
 pub mod db;
 pub mod error;
 pub mod formatter;
+pub mod hash;
 pub mod interview;
 pub mod json_formatter;
 pub mod path_utils;
 pub mod progress;
-// pub mod schema_registry;
+pub mod quality;
+pub mod schema_registry;
 pub mod session;
 pub mod types;
 pub mod validation;
+pub mod workitem;
 
-pub use error::{map_db_error, map_validation_error, ExitCode, ExitCodeError};
+pub use error::{
+  map_db_error, map_interview_error, map_schema_registry_error, map_session_error,
+  map_validation_error, ExitCode, ExitCodeError,
+};
 pub use path_utils::PathError;
-// pub use schema_registry::{Schema, SchemaId, SchemaRegistry, SchemaRegistryError, SchemaVersion};
-pub use types::{HttpMethod, HttpMethodError, SpecName, SpecNameError, Url, UrlError};
+pub use schema_registry::{
+  CompatibilityReport, Schema, SchemaChange, SchemaId, SchemaRegistry, SchemaRegistryError,
+  SchemaVersion,
+};
+use tracing_subscriber::EnvFilter;
+pub use types::{
+  HttpMethod, HttpMethodError, SpecName, SpecNameError, SpecNameRegistry, Url, UrlError,
+};
 
-/// A simple function to demonstrate core functionality
+/// Name and version of the Clarity build, e.g. `clarity-core 0.1.0`
+///
+/// Shared by the server and client so `--version` output is consistent.
 #[must_use]
-pub fn greet(name: &str) -> String {
-  format!("Hello, {name}!")
+pub fn banner() -> String {
+  format!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+}
+
+/// Log filter used when `RUST_LOG` is unset or cannot be parsed
+pub const DEFAULT_LOG_FILTER: &str = "info";
+
+/// Build a log filter from a `RUST_LOG`-style directive string
+///
+/// Falls back to [`DEFAULT_LOG_FILTER`] when `directives` is `None` or invalid,
+/// so a typo in the environment never stops the process from starting.
+#[must_use]
+pub fn log_filter(directives: Option<&str>) -> EnvFilter {
+  directives
+    .and_then(|directives| EnvFilter::try_new(directives).ok())
+    .unwrap_or_else(|| EnvFilter::new(DEFAULT_LOG_FILTER))
+}
+
+/// Install the global `tracing` subscriber, filtered by `RUST_LOG`
+///
+/// Shared by the server and the native client so both log the same way.
+/// Does nothing if a subscriber is already installed.
+pub fn init_tracing() {
+  let directives = std::env::var(EnvFilter::DEFAULT_ENV).ok();
+  let _ = tracing_subscriber::fmt()
+    .with_env_filter(log_filter(directives.as_deref()))
+    .try_init();
 }
 
 #[cfg(test)]
@@ -49,7 +89,20 @@ mod tests {
   use super::*;
 
   #[test]
-  fn test_greet() {
-    assert_eq!(greet("World"), "Hello, World!");
+  fn test_banner() {
+    assert_eq!(
+      banner(),
+      format!("clarity-core {}", env!("CARGO_PKG_VERSION"))
+    );
+  }
+
+  #[test]
+  fn test_log_filter_falls_back_to_default() {
+    assert_eq!(log_filter(None).to_string(), DEFAULT_LOG_FILTER);
+    assert_eq!(
+      log_filter(Some("clarity=notalevel")).to_string(),
+      DEFAULT_LOG_FILTER
+    );
+    assert_eq!(log_filter(Some("debug")).to_string(), "debug");
   }
 }
@@ -10,7 +10,7 @@
 //! This module provides safe, functional utilities for common file path operations.
 //! All functions return `Result` types and follow zero-panic principles.
 
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 /// Errors that can occur during path operations
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -31,6 +31,12 @@ pub enum PathError {
   MissingExtension(PathBuf),
   /// Invalid UTF-8 in path
   InvalidUtf8,
+  /// Path would resolve outside the directory it must stay in
+  Traversal(String),
+  /// Path refers to an environment variable that is not set
+  UndefinedVariable(String),
+  /// Path starts with `~` but the home directory is unknown
+  HomeNotFound,
 }
 
 impl std::fmt::Display for PathError {
@@ -44,6 +50,9 @@ impl std::fmt::Display for PathError {
       Self::NotAbsolute(path) => write!(f, "Path is not absolute: {}", path.display()),
       Self::MissingExtension(path) => write!(f, "Path missing extension: {}", path.display()),
       Self::InvalidUtf8 => write!(f, "Path contains invalid UTF-8"),
+      Self::Traversal(path) => write!(f, "Path escapes its base directory: {path}"),
+      Self::UndefinedVariable(name) => write!(f, "Environment variable not set: {name}"),
+      Self::HomeNotFound => write!(f, "Home directory could not be determined"),
     }
   }
 }
@@ -216,6 +225,121 @@ pub fn is_absolute(path: &str) -> bool {
   Path::new(path).is_absolute()
 }
 
+/// Join an untrusted relative path onto `base`, refusing to leave `base`
+///
+/// `.` and `..` are resolved lexically, so `a/../b` is accepted while any path
+/// that climbs above `base` or is absolute is rejected. The file system is not
+/// consulted, so symlinks inside `base` are not followed.
+///
+/// # Errors
+/// - Returns `PathError::EmptyPath` if the candidate is empty
+/// - Returns `PathError::InvalidCharacters` if the candidate contains null bytes
+/// - Returns `PathError::Traversal` if the candidate is absolute or escapes `base`
+///
+/// # Examples
+/// ```
+/// use clarity_core::path_utils::sanitize_relative;
+/// use std::path::Path;
+///
+/// let base = Path::new("/srv/specs");
+/// assert_eq!(
+///   sanitize_relative(base, "team/api.yaml").unwrap(),
+///   Path::new("/srv/specs/team/api.yaml")
+/// );
+/// assert!(sanitize_relative(base, "../../etc/passwd").is_err());
+/// ```
+pub fn sanitize_relative(base: &Path, candidate: &str) -> Result<PathBuf, PathError> {
+  validate_path_chars(candidate)?;
+
+  let mut relative = PathBuf::new();
+  for component in Path::new(candidate).components() {
+    match component {
+      Component::Normal(part) => relative.push(part),
+      Component::CurDir => {}
+      Component::ParentDir => {
+        if !relative.pop() {
+          return Err(PathError::Traversal(candidate.to_string()));
+        }
+      }
+      Component::RootDir | Component::Prefix(_) => {
+        return Err(PathError::Traversal(candidate.to_string()));
+      }
+    }
+  }
+
+  Ok(base.join(relative))
+}
+
+/// Expand a leading `~` and `$VAR` / `${VAR}` references in a path
+///
+/// `~` is replaced only when it is the whole first component (`~` or `~/...`)
+/// and resolves to `$HOME`, or `%USERPROFILE%` where `HOME` is unset. A `$`
+/// that does not start a variable name is kept as written.
+///
+/// # Errors
+/// - Returns `PathError::EmptyPath` if the input is empty
+/// - Returns `PathError::HomeNotFound` if `~` is used and no home directory is set
+/// - Returns `PathError::UndefinedVariable` if a referenced variable is not set
+pub fn expand(input: &str) -> Result<PathBuf, PathError> {
+  expand_with(input, |name| std::env::var(name).ok())
+}
+
+/// `expand` with variables looked up through `lookup` instead of the environment
+fn expand_with(input: &str, lookup: impl Fn(&str) -> Option<String>) -> Result<PathBuf, PathError> {
+  if input.is_empty() {
+    return Err(PathError::EmptyPath);
+  }
+
+  let mut expanded = String::with_capacity(input.len());
+  let mut rest = input;
+  if let Some(after) = input.strip_prefix('~') {
+    if after.is_empty() || after.starts_with('/') {
+      let home = lookup("HOME")
+        .or_else(|| lookup("USERPROFILE"))
+        .ok_or(PathError::HomeNotFound)?;
+      expanded.push_str(&home);
+      rest = after;
+    }
+  }
+
+  while let Some(dollar) = rest.find('$') {
+    expanded.push_str(&rest[..dollar]);
+    let after = &rest[dollar + 1..];
+    let (name, remainder) = split_variable(after);
+
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+      expanded.push('$');
+      rest = after;
+      continue;
+    }
+    let value = lookup(name).ok_or_else(|| PathError::UndefinedVariable(name.to_string()))?;
+    expanded.push_str(&value);
+    rest = remainder;
+  }
+  expanded.push_str(rest);
+
+  Ok(PathBuf::from(expanded))
+}
+
+/// Split the text after a `$` into a variable name and the text following it
+///
+/// The name is empty when no `NAME` or complete `{NAME}` follows.
+fn split_variable(after: &str) -> (&str, &str) {
+  after.strip_prefix('{').map_or_else(
+    || {
+      let end = after
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(after.len());
+      after.split_at(end)
+    },
+    |braced| {
+      braced
+        .find('}')
+        .map_or(("", after), |end| (&braced[..end], &braced[end + 1..]))
+    },
+  )
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -450,4 +574,99 @@ mod tests {
   fn test_is_absolute_empty() {
     assert!(!is_absolute(""));
   }
+
+  // sanitize_relative tests
+  #[test]
+  fn test_sanitize_relative_nested_path() {
+    assert_eq!(
+      sanitize_relative(Path::new("/srv/specs"), "team/./drafts/../api.yaml"),
+      Ok(PathBuf::from("/srv/specs/team/api.yaml"))
+    );
+  }
+
+  #[test]
+  fn test_sanitize_relative_rejects_parent_traversal() {
+    assert_eq!(
+      sanitize_relative(Path::new("/srv/specs"), "../../etc/passwd"),
+      Err(PathError::Traversal("../../etc/passwd".to_string()))
+    );
+    assert!(matches!(
+      sanitize_relative(Path::new("/srv/specs"), "team/../../secrets"),
+      Err(PathError::Traversal(_))
+    ));
+  }
+
+  #[test]
+  fn test_sanitize_relative_rejects_absolute_path() {
+    assert_eq!(
+      sanitize_relative(Path::new("/srv/specs"), "/etc/passwd"),
+      Err(PathError::Traversal("/etc/passwd".to_string()))
+    );
+  }
+
+  #[test]
+  fn test_sanitize_relative_empty() {
+    assert_eq!(
+      sanitize_relative(Path::new("/srv/specs"), ""),
+      Err(PathError::EmptyPath)
+    );
+  }
+
+  // expand tests
+  fn lookup(name: &str) -> Option<String> {
+    match name {
+      "HOME" => Some("/home/ada".to_string()),
+      "PROJECT" => Some("clarity".to_string()),
+      _ => None,
+    }
+  }
+
+  #[test]
+  fn test_expand_tilde() {
+    assert_eq!(
+      expand_with("~/foo", lookup),
+      Ok(PathBuf::from("/home/ada/foo"))
+    );
+    assert_eq!(expand_with("~", lookup), Ok(PathBuf::from("/home/ada")));
+    assert_eq!(
+      expand_with("~other/foo", lookup),
+      Ok(PathBuf::from("~other/foo"))
+    );
+  }
+
+  #[test]
+  fn test_expand_variables() {
+    assert_eq!(
+      expand_with("$HOME/bar", lookup),
+      Ok(PathBuf::from("/home/ada/bar"))
+    );
+    assert_eq!(
+      expand_with("/srv/${PROJECT}_data/$PROJECT", lookup),
+      Ok(PathBuf::from("/srv/clarity_data/clarity"))
+    );
+    assert_eq!(
+      expand_with("/cost/$5/$/${", lookup),
+      Ok(PathBuf::from("/cost/$5/$/${"))
+    );
+  }
+
+  #[test]
+  fn test_expand_undefined_variable() {
+    assert_eq!(
+      expand_with("$MISSING/bar", lookup),
+      Err(PathError::UndefinedVariable("MISSING".to_string()))
+    );
+    assert_eq!(expand_with("~/foo", |_| None), Err(PathError::HomeNotFound));
+  }
+
+  #[test]
+  fn test_expand_from_environment() {
+    match std::env::var("HOME") {
+      Ok(home) => assert_eq!(expand("~/foo"), Ok(Path::new(&home).join("foo"))),
+      Err(_) => assert_eq!(
+        expand("$HOME"),
+        Err(PathError::UndefinedVariable("HOME".to_string()))
+      ),
+    }
+  }
 }
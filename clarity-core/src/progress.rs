@@ -21,6 +21,7 @@
 //! - Immutable data structures
 //! - No unwraps or panics
 
+use crate::db::models::BeadStatus;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
@@ -101,6 +102,22 @@ impl Display for ProgressStatus {
   }
 }
 
+impl From<BeadStatus> for ProgressStatus {
+  /// Place a bead's lifecycle status on the progress scale
+  ///
+  /// Open beads have not started and closed beads are completed; the other
+  /// statuses map onto their namesakes.
+  fn from(status: BeadStatus) -> Self {
+    match status {
+      BeadStatus::Open => Self::NotStarted,
+      BeadStatus::InProgress => Self::InProgress,
+      BeadStatus::Blocked => Self::Blocked,
+      BeadStatus::Deferred => Self::Deferred,
+      BeadStatus::Closed => Self::Completed,
+    }
+  }
+}
+
 /// Progress metrics for a collection of items
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProgressMetrics {
@@ -628,6 +645,120 @@ pub fn format_progress(
   }
 }
 
+/// Furthest ahead `project_eta` will project, in seconds (ten years)
+pub const MAX_ETA_HORIZON_SECS: i64 = 10 * 365 * 24 * 60 * 60;
+
+/// Project when progress will reach 100% from timestamped snapshots
+///
+/// Fits a least-squares line to completed items over time (epoch seconds) to
+/// get a completion rate in items per second, then projects the remaining
+/// items of the latest snapshot at that rate. Snapshots may be in any order.
+///
+/// Returns the latest timestamp if the latest snapshot is already complete.
+/// Returns `None` with fewer than two snapshots, when the rate is not
+/// positive, or when the projection lies more than [`MAX_ETA_HORIZON_SECS`]
+/// past the latest snapshot.
+///
+/// # Examples
+///
+/// ```
+/// use clarity_core::progress::{project_eta, ProgressMetrics};
+///
+/// let history = vec![
+///     (0, ProgressMetrics::new(4, 0, 0, 0, 0, 4).unwrap()),
+///     (100, ProgressMetrics::new(4, 1, 0, 0, 0, 3).unwrap()),
+/// ];
+/// assert_eq!(project_eta(&history), Some(400));
+/// ```
+#[must_use]
+pub fn project_eta(history: &[(i64, ProgressMetrics)]) -> Option<i64> {
+  let mut points: Vec<&(i64, ProgressMetrics)> = history.iter().collect();
+  points.sort_by_key(|(ts, _)| *ts);
+  let (first_ts, _) = points.first()?;
+  let (last_ts, last) = points.last()?;
+  if points.len() < 2 {
+    return None;
+  }
+  if last.remaining_items() == 0 {
+    return Some(*last_ts);
+  }
+
+  // Offsets from the first snapshot keep the sums small enough for f64
+  #[allow(clippy::cast_precision_loss)]
+  let samples: Vec<(f64, f64)> = points
+    .iter()
+    .map(|(ts, metrics)| ((ts - first_ts) as f64, metrics.completed as f64))
+    .collect();
+  #[allow(clippy::cast_precision_loss)]
+  let count = samples.len() as f64;
+  let mean_t = samples.iter().map(|(t, _)| t).sum::<f64>() / count;
+  let mean_c = samples.iter().map(|(_, c)| c).sum::<f64>() / count;
+  let (covariance, variance) = samples.iter().fold((0.0, 0.0), |(cov, var), (t, c)| {
+    let dt = t - mean_t;
+    (dt.mul_add(c - mean_c, cov), dt.mul_add(dt, var))
+  });
+  if variance <= 0.0 {
+    return None;
+  }
+
+  let rate = covariance / variance;
+  if rate <= 0.0 {
+    return None;
+  }
+
+  #[allow(clippy::cast_precision_loss)]
+  let seconds_left = (last.remaining_items() as f64 / rate).ceil();
+  #[allow(clippy::cast_precision_loss)]
+  let horizon = MAX_ETA_HORIZON_SECS as f64;
+  if seconds_left > horizon {
+    return None;
+  }
+
+  #[allow(clippy::cast_possible_truncation)]
+  last_ts.checked_add(seconds_left as i64)
+}
+
+/// Remaining items over time, for plotting a burndown chart
+///
+/// Returns one `(timestamp, remaining_items)` pair per distinct timestamp,
+/// sorted by timestamp. When several snapshots share a timestamp the last one
+/// in `history` wins.
+///
+/// # Examples
+///
+/// ```
+/// use clarity_core::progress::{burndown, ProgressMetrics};
+///
+/// let history = vec![
+///     (200, ProgressMetrics::new(4, 3, 0, 0, 0, 1).unwrap()),
+///     (100, ProgressMetrics::new(4, 1, 0, 0, 0, 3).unwrap()),
+/// ];
+/// assert_eq!(burndown(&history), vec![(100, 3), (200, 1)]);
+/// ```
+#[must_use]
+pub fn burndown(history: &[(i64, ProgressMetrics)]) -> Vec<(i64, usize)> {
+  history
+    .iter()
+    .map(|(ts, metrics)| (*ts, metrics.remaining_items()))
+    .collect::<std::collections::BTreeMap<_, _>>()
+    .into_iter()
+    .collect()
+}
+
+/// The ideal burndown line from `total` items at `start_ts` to none at `end_ts`
+///
+/// A straight line needs only its two endpoints, so that is all this returns.
+/// Returns a single point at `start_ts` when `end_ts` is not after it.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn ideal_burndown(start_ts: i64, end_ts: i64, total: usize) -> Vec<(i64, f64)> {
+  if end_ts > start_ts {
+    vec![(start_ts, total as f64), (end_ts, 0.0)]
+  } else {
+    vec![(start_ts, total as f64)]
+  }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 #[allow(clippy::expect_used)]
@@ -693,6 +824,42 @@ mod tests {
     assert_eq!(format!("{}", ProgressStatus::Deferred), "deferred");
   }
 
+  #[test]
+  fn test_progress_status_from_bead_status() {
+    assert_eq!(
+      ProgressStatus::from(BeadStatus::Open),
+      ProgressStatus::NotStarted
+    );
+    assert_eq!(
+      ProgressStatus::from(BeadStatus::InProgress),
+      ProgressStatus::InProgress
+    );
+    assert_eq!(
+      ProgressStatus::from(BeadStatus::Blocked),
+      ProgressStatus::Blocked
+    );
+    assert_eq!(
+      ProgressStatus::from(BeadStatus::Deferred),
+      ProgressStatus::Deferred
+    );
+    assert_eq!(
+      ProgressStatus::from(BeadStatus::Closed),
+      ProgressStatus::Completed
+    );
+  }
+
+  #[test]
+  fn test_calculate_progress_from_bead_statuses() {
+    let statuses: Vec<ProgressStatus> = [BeadStatus::Closed, BeadStatus::Open, BeadStatus::Closed]
+      .into_iter()
+      .map(ProgressStatus::from)
+      .collect();
+
+    let metrics = calculate_progress(&statuses);
+    assert_eq!(metrics.completed, 2);
+    assert_eq!(metrics.not_started, 1);
+  }
+
   #[test]
   fn test_progress_metrics_new_valid() {
     let metrics = ProgressMetrics::new(10, 7, 2, 0, 1, 0).unwrap();
@@ -973,6 +1140,87 @@ mod tests {
     assert!(!display.contains("Category Breakdown"));
   }
 
+  const DAY: i64 = 24 * 60 * 60;
+
+  #[test]
+  fn test_project_eta_steady_rate() {
+    // 10% of 20 items per day: half done after five days, done after ten
+    let start = 1_700_000_000;
+    let history: Vec<(i64, ProgressMetrics)> = (0..=5)
+      .map(|day| {
+        let completed = 2 * day;
+        (
+          start + i64::try_from(day).unwrap() * DAY,
+          ProgressMetrics::new(20, completed, 0, 0, 0, 20 - completed).unwrap(),
+        )
+      })
+      .rev()
+      .collect();
+
+    assert_eq!(project_eta(&history), Some(start + 10 * DAY));
+  }
+
+  #[test]
+  fn test_project_eta_needs_progress() {
+    let one = ProgressMetrics::new(10, 1, 0, 0, 0, 9).unwrap();
+    assert_eq!(project_eta(&[(0, one.clone())]), None);
+    assert_eq!(project_eta(&[(0, one.clone()), (DAY, one.clone())]), None);
+
+    let less = ProgressMetrics::new(10, 0, 0, 0, 0, 10).unwrap();
+    assert_eq!(project_eta(&[(0, one), (DAY, less)]), None);
+  }
+
+  #[test]
+  fn test_project_eta_caps_far_projections() {
+    let history = [
+      (
+        0,
+        ProgressMetrics::new(1_000_000, 0, 0, 0, 0, 1_000_000).unwrap(),
+      ),
+      (
+        DAY,
+        ProgressMetrics::new(1_000_000, 1, 0, 0, 0, 999_999).unwrap(),
+      ),
+    ];
+    assert_eq!(project_eta(&history), None);
+  }
+
+  #[test]
+  fn test_project_eta_already_complete() {
+    let history = [
+      (0, ProgressMetrics::new(2, 1, 0, 0, 0, 1).unwrap()),
+      (DAY, ProgressMetrics::new(2, 2, 0, 0, 0, 0).unwrap()),
+    ];
+    assert_eq!(project_eta(&history), Some(DAY));
+  }
+
+  #[test]
+  fn test_burndown_descending_series() {
+    let history = [
+      (DAY, ProgressMetrics::new(6, 2, 1, 0, 0, 3).unwrap()),
+      (0, ProgressMetrics::new(6, 0, 0, 0, 0, 6).unwrap()),
+      (2 * DAY, ProgressMetrics::new(6, 5, 1, 0, 0, 0).unwrap()),
+    ];
+
+    assert_eq!(burndown(&history), vec![(0, 6), (DAY, 4), (2 * DAY, 1)]);
+  }
+
+  #[test]
+  fn test_burndown_keeps_last_snapshot_per_instant() {
+    let history = [
+      (0, ProgressMetrics::new(6, 0, 0, 0, 0, 6).unwrap()),
+      (0, ProgressMetrics::new(6, 1, 0, 0, 0, 5).unwrap()),
+    ];
+
+    assert_eq!(burndown(&history), vec![(0, 5)]);
+  }
+
+  #[test]
+  fn test_ideal_burndown() {
+    assert_eq!(ideal_burndown(0, DAY, 6), vec![(0, 6.0), (DAY, 0.0)]);
+    assert_eq!(ideal_burndown(DAY, 0, 6), vec![(DAY, 6.0)]);
+  }
+
   #[test]
   fn test_all_statuses_covered() {
     let statuses = ProgressStatus::all();
@@ -0,0 +1,624 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Quality reporting for Clarity
+//!
+//! This module provides:
+//! - Severity levels for findings (ordered `Info < Warning < Error`)
+//! - Validation messages keyed by field path
+//! - Validation reports that aggregate messages from several checks, rendered
+//!   as text, JSON or Markdown
+//!
+//! Field paths are dotted with array indices in brackets (`user.tags[0]`);
+//! the empty path refers to the value being validated itself.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use thiserror::Error;
+
+/// How serious a validation finding is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+  /// Informational only
+  Info,
+  /// Worth attention but does not fail validation
+  Warning,
+  /// Fails validation
+  Error,
+}
+
+impl Display for Severity {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Info => write!(f, "info"),
+      Self::Warning => write!(f, "warning"),
+      Self::Error => write!(f, "error"),
+    }
+  }
+}
+
+/// An overall quality score from 0 to 100
+///
+/// Serialized as the bare number; deserializing a value above 100 fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub struct QualityScore(u8);
+
+impl QualityScore {
+  /// The highest possible score
+  pub const MAX: u8 = 100;
+
+  /// Create a score, checking it is at most [`Self::MAX`]
+  ///
+  /// # Errors
+  ///
+  /// Returns `ScoreOutOfRange` if `value` is above 100
+  pub const fn new(value: u8) -> Result<Self, ScoreOutOfRange> {
+    if value > Self::MAX {
+      Err(ScoreOutOfRange(value))
+    } else {
+      Ok(Self(value))
+    }
+  }
+
+  /// The score as a number from 0 to 100
+  #[must_use]
+  pub const fn value(self) -> u8 {
+    self.0
+  }
+}
+
+impl Display for QualityScore {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}/{}", self.0, Self::MAX)
+  }
+}
+
+impl TryFrom<u8> for QualityScore {
+  type Error = ScoreOutOfRange;
+
+  fn try_from(value: u8) -> Result<Self, Self::Error> {
+    Self::new(value)
+  }
+}
+
+impl From<QualityScore> for u8 {
+  fn from(score: QualityScore) -> Self {
+    score.0
+  }
+}
+
+/// A quality score above 100 was given
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+#[error("quality score must be at most 100, got {0}")]
+pub struct ScoreOutOfRange(pub u8);
+
+/// A single validation finding
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationMessage {
+  /// Path of the field the finding is about
+  pub field_path: String,
+  /// How serious the finding is
+  pub severity: Severity,
+  /// Human-readable description
+  pub message: String,
+}
+
+impl ValidationMessage {
+  /// Create a new validation message
+  pub fn new(
+    field_path: impl Into<String>,
+    severity: Severity,
+    message: impl Into<String>,
+  ) -> Self {
+    Self {
+      field_path: field_path.into(),
+      severity,
+      message: message.into(),
+    }
+  }
+
+  /// Create an error-severity message
+  pub fn error(field_path: impl Into<String>, message: impl Into<String>) -> Self {
+    Self::new(field_path, Severity::Error, message)
+  }
+
+  /// Create a warning-severity message
+  pub fn warning(field_path: impl Into<String>, message: impl Into<String>) -> Self {
+    Self::new(field_path, Severity::Warning, message)
+  }
+
+  /// Create an info-severity message
+  pub fn info(field_path: impl Into<String>, message: impl Into<String>) -> Self {
+    Self::new(field_path, Severity::Info, message)
+  }
+}
+
+impl Display for ValidationMessage {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if self.field_path.is_empty() {
+      write!(f, "[{}] {}", self.severity, self.message)
+    } else {
+      write!(
+        f,
+        "[{}] {}: {}",
+        self.severity, self.field_path, self.message
+      )
+    }
+  }
+}
+
+/// The outcome of a validation run
+///
+/// A report is valid when it holds no error-severity messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ValidationReport {
+  messages: Vec<ValidationMessage>,
+  is_valid: bool,
+}
+
+impl Default for ValidationReport {
+  fn default() -> Self {
+    Self::valid()
+  }
+}
+
+impl ValidationReport {
+  /// Create a report from its messages
+  #[must_use]
+  pub fn from_messages(messages: Vec<ValidationMessage>) -> Self {
+    let is_valid = !messages.iter().any(|m| m.severity == Severity::Error);
+    Self { messages, is_valid }
+  }
+
+  /// Create a report with no findings
+  #[must_use]
+  pub fn valid() -> Self {
+    Self::from_messages(Vec::new())
+  }
+
+  /// Combine several reports into one, keeping message order
+  #[must_use]
+  pub fn aggregate(reports: impl IntoIterator<Item = Self>) -> Self {
+    Self::from_messages(reports.into_iter().flat_map(|r| r.messages).collect())
+  }
+
+  /// Return a new report with `message` appended
+  #[must_use]
+  pub fn with_message(self, message: ValidationMessage) -> Self {
+    Self::from_messages(self.messages.into_iter().chain([message]).collect())
+  }
+
+  /// Return a new report with every field path scoped under `prefix`
+  ///
+  /// `zip` becomes `address.zip`, and messages about the value itself (the
+  /// empty path) become `address`. Use this before [`Self::aggregate`] to
+  /// combine child reports under their parent field name.
+  #[must_use]
+  pub fn with_prefix(self, prefix: &str) -> Self {
+    let messages = self
+      .messages
+      .into_iter()
+      .map(|message| ValidationMessage {
+        field_path: prefixed_path(prefix, &message.field_path),
+        ..message
+      })
+      .collect();
+    Self::from_messages(messages)
+  }
+
+  /// Return a report keeping only messages at or above `min` severity
+  #[must_use]
+  pub fn filter_by_severity(&self, min: Severity) -> Self {
+    self.retain(|m| m.severity >= min)
+  }
+
+  /// Return a report keeping only messages about `field_prefix` or its children
+  ///
+  /// `address` matches `address`, `address.zip` and `address[0]`, but not
+  /// `addresses`.
+  #[must_use]
+  pub fn for_field(&self, field_prefix: &str) -> Self {
+    self.retain(|m| is_within_field(&m.field_path, field_prefix))
+  }
+
+  /// Return a report with every warning raised to an error, for strict checks
+  ///
+  /// Info messages are left as they are.
+  #[must_use]
+  pub fn escalate_warnings_to_errors(&self) -> Self {
+    self.with_severities(|severity| match severity {
+      Severity::Warning => Severity::Error,
+      other => other,
+    })
+  }
+
+  /// Return a report with every error lowered to a warning, for lenient checks
+  ///
+  /// The result is always valid. Info messages are left as they are.
+  #[must_use]
+  pub fn demote_errors_to_warnings(&self) -> Self {
+    self.with_severities(|severity| match severity {
+      Severity::Error => Severity::Warning,
+      other => other,
+    })
+  }
+
+  /// Return a report with each severity replaced by `map`, with validity recomputed
+  fn with_severities(&self, map: impl Fn(Severity) -> Severity) -> Self {
+    Self::from_messages(
+      self
+        .messages
+        .iter()
+        .map(|m| ValidationMessage {
+          severity: map(m.severity),
+          ..m.clone()
+        })
+        .collect(),
+    )
+  }
+
+  /// Return a report of the messages matching `keep`, with validity recomputed
+  fn retain(&self, keep: impl Fn(&ValidationMessage) -> bool) -> Self {
+    Self::from_messages(self.messages.iter().filter(|m| keep(m)).cloned().collect())
+  }
+
+  /// Whether the report holds no error-severity messages
+  #[must_use]
+  pub const fn is_valid(&self) -> bool {
+    self.is_valid
+  }
+
+  /// All messages in the report
+  #[must_use]
+  pub fn messages(&self) -> &[ValidationMessage] {
+    &self.messages
+  }
+
+  /// Messages with error severity
+  pub fn errors(&self) -> impl Iterator<Item = &ValidationMessage> {
+    self
+      .messages
+      .iter()
+      .filter(|m| m.severity == Severity::Error)
+  }
+}
+
+/// Join `prefix` and `path`, keeping array indices attached to their field
+fn prefixed_path(prefix: &str, path: &str) -> String {
+  if path.is_empty() {
+    prefix.to_string()
+  } else if prefix.is_empty() || path.starts_with('[') {
+    format!("{prefix}{path}")
+  } else {
+    format!("{prefix}.{path}")
+  }
+}
+
+/// Whether `path` is `field` itself or one of its nested fields
+fn is_within_field(path: &str, field: &str) -> bool {
+  if field.is_empty() {
+    return true;
+  }
+  path
+    .strip_prefix(field)
+    .is_some_and(|rest| rest.is_empty() || rest.starts_with('.') || rest.starts_with('['))
+}
+
+/// Output format for [`ValidationReport::render`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReportFormat {
+  /// Human-readable text, as used by `Display`
+  Text,
+  /// JSON object with `messages` and `is_valid`
+  Json,
+  /// Markdown tables of messages, one per severity
+  Markdown,
+}
+
+impl Display for ReportFormat {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Text => write!(f, "text"),
+      Self::Json => write!(f, "json"),
+      Self::Markdown => write!(f, "markdown"),
+    }
+  }
+}
+
+impl ValidationReport {
+  /// Render the report in the given format
+  ///
+  /// # Errors
+  ///
+  /// Returns the `serde_json` error if the report cannot be serialized as JSON
+  pub fn render(&self, format: ReportFormat) -> Result<String, serde_json::Error> {
+    match format {
+      ReportFormat::Text => Ok(self.render_text()),
+      ReportFormat::Json => self.to_json(),
+      ReportFormat::Markdown => Ok(self.render_markdown()),
+    }
+  }
+
+  /// Render the report as a JSON object
+  ///
+  /// # Errors
+  ///
+  /// Returns the `serde_json` error if the report cannot be serialized
+  pub fn to_json(&self) -> Result<String, serde_json::Error> {
+    serde_json::to_string(self)
+  }
+
+  const fn status(&self) -> &'static str {
+    if self.is_valid {
+      "valid"
+    } else {
+      "invalid"
+    }
+  }
+
+  fn render_text(&self) -> String {
+    if self.messages.is_empty() {
+      return "valid: no findings".to_string();
+    }
+
+    let mut lines = vec![format!(
+      "{}: {} finding(s)",
+      self.status(),
+      self.messages.len()
+    )];
+    lines.extend(self.messages.iter().map(|message| format!("  {message}")));
+    lines.join("\n")
+  }
+
+  fn render_markdown(&self) -> String {
+    if self.messages.is_empty() {
+      return "**valid**: no findings\n".to_string();
+    }
+
+    let mut blocks = vec![format!(
+      "**{}**: {} finding(s)",
+      self.status(),
+      self.messages.len()
+    )];
+    for (severity, heading) in [
+      (Severity::Error, "Errors"),
+      (Severity::Warning, "Warnings"),
+      (Severity::Info, "Info"),
+    ] {
+      let rows: Vec<String> = self
+        .messages
+        .iter()
+        .filter(|m| m.severity == severity)
+        .map(|m| {
+          let field = if m.field_path.is_empty() {
+            "(root)".to_string()
+          } else {
+            format!("`{}`", m.field_path)
+          };
+          format!("| {} | {} |", field, m.message.replace('|', "\\|"))
+        })
+        .collect();
+      if !rows.is_empty() {
+        blocks.push(format!(
+          "### {heading}\n\n| Field | Message |\n| --- | --- |\n{}",
+          rows.join("\n")
+        ));
+      }
+    }
+
+    let mut markdown = blocks.join("\n\n");
+    markdown.push('\n');
+    markdown
+  }
+}
+
+impl Display for ValidationReport {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.render_text())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_severity_ordering() {
+    assert!(Severity::Info < Severity::Warning);
+    assert!(Severity::Warning < Severity::Error);
+  }
+
+  #[test]
+  fn test_quality_score_range() {
+    assert_eq!(QualityScore::new(100).map(QualityScore::value), Ok(100));
+    assert_eq!(QualityScore::new(101), Err(ScoreOutOfRange(101)));
+    assert!(serde_json::from_str::<QualityScore>("101").is_err());
+    assert_eq!(
+      QualityScore::new(85).map(|score| score.to_string()),
+      Ok("85/100".to_string())
+    );
+  }
+
+  #[test]
+  fn test_report_validity_depends_on_errors() {
+    let warnings_only = ValidationReport::from_messages(vec![
+      ValidationMessage::info("name", "looks fine"),
+      ValidationMessage::warning("email", "unusual domain"),
+    ]);
+    assert!(warnings_only.is_valid());
+
+    let with_error =
+      warnings_only.with_message(ValidationMessage::error("age", "must be positive"));
+    assert!(!with_error.is_valid());
+    assert_eq!(with_error.errors().count(), 1);
+  }
+
+  #[test]
+  fn test_aggregate_keeps_order() {
+    let report = ValidationReport::aggregate([
+      ValidationReport::from_messages(vec![ValidationMessage::warning("a", "first")]),
+      ValidationReport::valid(),
+      ValidationReport::from_messages(vec![ValidationMessage::error("b", "second")]),
+    ]);
+
+    let paths: Vec<&str> = report
+      .messages()
+      .iter()
+      .map(|m| m.field_path.as_str())
+      .collect();
+    assert_eq!(paths, vec!["a", "b"]);
+    assert!(!report.is_valid());
+  }
+
+  #[test]
+  fn test_with_prefix_scopes_paths() {
+    let address = ValidationReport::from_messages(vec![
+      ValidationMessage::error("zip", "must be 5 digits"),
+      ValidationMessage::warning("", "no country given"),
+    ]);
+    let report = ValidationReport::aggregate([
+      address.with_prefix("address").with_prefix("user"),
+      ValidationReport::from_messages(vec![ValidationMessage::info("[0]", "first tag")])
+        .with_prefix("tags"),
+    ]);
+
+    let paths: Vec<&str> = report
+      .messages()
+      .iter()
+      .map(|m| m.field_path.as_str())
+      .collect();
+    assert_eq!(paths, vec!["user.address.zip", "user.address", "tags[0]"]);
+    assert!(!report.is_valid());
+  }
+
+  #[test]
+  fn test_filter_by_severity_recomputes_validity() {
+    let report = ValidationReport::from_messages(vec![
+      ValidationMessage::info("name", "looks fine"),
+      ValidationMessage::warning("email", "unusual domain"),
+      ValidationMessage::error("age", "must be positive"),
+    ]);
+
+    let warnings_up = report.filter_by_severity(Severity::Warning);
+    let severities: Vec<Severity> = warnings_up.messages().iter().map(|m| m.severity).collect();
+    assert_eq!(severities, vec![Severity::Warning, Severity::Error]);
+    assert!(!warnings_up.is_valid());
+
+    let without_errors = ValidationReport::from_messages(vec![
+      ValidationMessage::info("name", "looks fine"),
+      ValidationMessage::warning("email", "unusual domain"),
+    ])
+    .filter_by_severity(Severity::Warning);
+    assert_eq!(without_errors.messages().len(), 1);
+    assert!(without_errors.is_valid());
+
+    assert!(report
+      .filter_by_severity(Severity::Error)
+      .errors()
+      .eq(report.errors()));
+  }
+
+  #[test]
+  fn test_for_field_matches_nested_paths() {
+    let report = ValidationReport::from_messages(vec![
+      ValidationMessage::error("address", "missing"),
+      ValidationMessage::error("address.zip", "must be 5 digits"),
+      ValidationMessage::warning("address[1]", "duplicate"),
+      ValidationMessage::error("addresses", "too many"),
+      ValidationMessage::info("name", "looks fine"),
+    ]);
+
+    let paths: Vec<String> = report
+      .for_field("address")
+      .messages()
+      .iter()
+      .map(|m| m.field_path.clone())
+      .collect();
+    assert_eq!(paths, vec!["address", "address.zip", "address[1]"]);
+
+    let name = report.for_field("name");
+    assert_eq!(name.messages().len(), 1);
+    assert!(name.is_valid());
+    assert_eq!(report.for_field(""), report);
+  }
+
+  #[test]
+  fn test_severity_adjustments_recompute_validity() {
+    let report = ValidationReport::from_messages(vec![
+      ValidationMessage::warning("name", "too short"),
+      ValidationMessage::info("tags", "empty"),
+    ]);
+    assert!(report.is_valid());
+
+    let strict = report.escalate_warnings_to_errors();
+    assert!(!strict.is_valid());
+    assert_eq!(strict.messages()[0].severity, Severity::Error);
+    assert_eq!(strict.messages()[1].severity, Severity::Info);
+
+    let lenient = strict.demote_errors_to_warnings();
+    assert!(lenient.is_valid());
+    assert_eq!(lenient, report);
+  }
+
+  #[test]
+  fn test_report_display() {
+    let report = ValidationReport::from_messages(vec![
+      ValidationMessage::error("", "expected object"),
+      ValidationMessage::warning("name", "too short"),
+    ]);
+
+    assert_eq!(
+      report.to_string(),
+      "invalid: 2 finding(s)\n  [error] expected object\n  [warning] name: too short"
+    );
+    assert_eq!(ValidationReport::valid().to_string(), "valid: no findings");
+  }
+
+  #[test]
+  fn test_render_formats() {
+    let report = ValidationReport::from_messages(vec![
+      ValidationMessage::error("", "expected object"),
+      ValidationMessage::info("tags", "a | b"),
+      ValidationMessage::warning("name", "too short"),
+    ]);
+
+    assert_eq!(
+      report.render(ReportFormat::Text).ok(),
+      Some(report.to_string())
+    );
+    assert_eq!(
+      report.render(ReportFormat::Text).ok().as_deref(),
+      Some("invalid: 3 finding(s)\n  [error] expected object\n  [info] tags: a | b\n  [warning] name: too short")
+    );
+
+    let json: serde_json::Value = match report.to_json().map(|json| serde_json::from_str(&json)) {
+      Ok(Ok(json)) => json,
+      Ok(Err(e)) => panic!("Expected valid JSON, got {e}"),
+      Err(e) => panic!("Expected the report to serialize, got {e}"),
+    };
+    let rendered = match report.render(ReportFormat::Json) {
+      Ok(json) => json,
+      Err(e) => panic!("Expected valid JSON, got {e}"),
+    };
+    assert_eq!(report.to_json().ok(), Some(rendered));
+    assert_eq!(json["is_valid"], false);
+    assert_eq!(json["messages"][1]["severity"], "info");
+    assert_eq!(json["messages"][1]["field_path"], "tags");
+
+    assert_eq!(
+      report.render(ReportFormat::Markdown).ok().as_deref(),
+      Some(
+        "**invalid**: 3 finding(s)\n\n\
+       ### Errors\n\n| Field | Message |\n| --- | --- |\n| (root) | expected object |\n\n\
+       ### Warnings\n\n| Field | Message |\n| --- | --- |\n| `name` | too short |\n\n\
+       ### Info\n\n| Field | Message |\n| --- | --- |\n| `tags` | a \\| b |\n"
+      )
+    );
+  }
+}
@@ -10,7 +10,8 @@
 //! Provides schema storage, retrieval, and validation functionality.
 //! All functions return Result<T, E> - no unwraps, no panics.
 
-use std::collections::HashMap;
+use crate::quality::{ValidationMessage, ValidationReport};
+use std::collections::{BTreeSet, HashMap};
 use std::sync::Arc;
 use thiserror::Error;
 
@@ -19,7 +20,7 @@ use thiserror::Error;
 pub struct SchemaId(String);
 
 impl SchemaId {
-  /// Create a new SchemaId
+  /// Create a new `SchemaId`
   ///
   /// # Errors
   ///
@@ -48,11 +49,14 @@ impl SchemaId {
 }
 
 /// Schema version following semantic versioning
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+///
+/// Versions order semantically: major, then minor, then patch, comparing
+/// numeric components as numbers so `1.2.0 < 1.10.0`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SchemaVersion(String);
 
 impl SchemaVersion {
-  /// Create a new SchemaVersion
+  /// Create a new `SchemaVersion`
   ///
   /// # Errors
   ///
@@ -79,6 +83,42 @@ impl SchemaVersion {
   pub const fn as_str(&self) -> &str {
     self.0.as_str()
   }
+
+  /// Dot-separated components, numeric where they parse as numbers
+  fn components(&self) -> Vec<VersionComponent<'_>> {
+    self
+      .0
+      .split('.')
+      .map(|part| {
+        part
+          .parse::<u64>()
+          .map_or(VersionComponent::Text(part), VersionComponent::Number)
+      })
+      .collect()
+  }
+}
+
+/// One component of a `SchemaVersion`; numbers sort before text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum VersionComponent<'a> {
+  Number(u64),
+  Text(&'a str),
+}
+
+impl Ord for SchemaVersion {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    // Fall back to the raw string so the ordering stays consistent with `Eq`
+    self
+      .components()
+      .cmp(&other.components())
+      .then_with(|| self.0.cmp(&other.0))
+  }
+}
+
+impl PartialOrd for SchemaVersion {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
 }
 
 /// JSON schema with metadata
@@ -96,6 +136,80 @@ pub struct Schema {
   pub schema: serde_json::Value,
   /// Creation timestamp
   pub created_at: chrono::DateTime<chrono::Utc>,
+  /// Whether this version has been deprecated
+  pub deprecated: bool,
+  /// Why this version was deprecated, if it was
+  pub deprecation_note: Option<String>,
+}
+
+impl Schema {
+  /// Validate a JSON value against this schema
+  ///
+  /// Checks `type`, `required` and `properties` on objects, `items` on arrays,
+  /// and the simple constraints `enum`, `minimum`/`maximum`,
+  /// `minLength`/`maxLength` and `minItems`/`maxItems`. Every violation becomes an
+  /// error-severity message keyed by the JSON pointer to the offending value.
+  #[must_use]
+  pub fn validate_value(&self, value: &serde_json::Value) -> ValidationReport {
+    let mut messages = Vec::new();
+    check_value("", &self.schema, value, &mut messages);
+    ValidationReport::from_messages(messages)
+  }
+}
+
+/// A single difference between two versions of a schema
+///
+/// Paths are JSON pointers (`/property/nested`), the same form as validation
+/// errors, with `/` for the schema root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaChange {
+  /// A property was added
+  PropertyAdded { path: String, required: bool },
+  /// A property was removed
+  PropertyRemoved { path: String, required: bool },
+  /// An existing property became required
+  PropertyMadeRequired { path: String },
+  /// A required property became optional
+  PropertyMadeOptional { path: String },
+  /// The declared type changed
+  TypeChanged {
+    path: String,
+    from: String,
+    to: String,
+  },
+}
+
+impl SchemaChange {
+  /// Check if data valid under the old schema may be rejected by the new one
+  #[must_use]
+  pub const fn is_breaking(&self) -> bool {
+    match self {
+      Self::PropertyAdded { required, .. } | Self::PropertyRemoved { required, .. } => *required,
+      Self::PropertyMadeRequired { .. } | Self::TypeChanged { .. } => true,
+      Self::PropertyMadeOptional { .. } => false,
+    }
+  }
+
+  /// Get the path of the property this change applies to
+  #[must_use]
+  pub fn path(&self) -> &str {
+    match self {
+      Self::PropertyAdded { path, .. }
+      | Self::PropertyRemoved { path, .. }
+      | Self::PropertyMadeRequired { path }
+      | Self::PropertyMadeOptional { path }
+      | Self::TypeChanged { path, .. } => path,
+    }
+  }
+}
+
+/// Result of comparing a candidate schema against the latest registered version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompatibilityReport {
+  /// Whether the candidate is backward-compatible (no breaking changes)
+  pub compatible: bool,
+  /// Every detected change, ordered by path and then by the order checks run
+  pub changes: Vec<SchemaChange>,
 }
 
 /// Schema registry for managing schemas
@@ -163,18 +277,98 @@ impl SchemaRegistry {
   ///
   /// Returns `SchemaRegistryError::NotFound` if no versions of the schema exist
   pub fn get_latest(&self, id: &SchemaId) -> Result<Schema, SchemaRegistryError> {
+    self
+      .latest(id)
+      .cloned()
+      .ok_or_else(|| SchemaRegistryError::NotFound {
+        id: id.as_str().to_string(),
+        version: "any".to_string(),
+      })
+  }
+
+  /// Get the highest registered version of a schema, if any
+  #[must_use]
+  pub fn latest(&self, id: &SchemaId) -> Option<&Schema> {
     self
       .schemas
       .iter()
       .filter(|((schema_id, _), _)| schema_id == id)
-      .max_by_key(|((_, version), _)| version)
-      .map(|(_, schema)| schema.clone())
-      .ok_or_else(|| SchemaRegistryError::NotFound {
+      .max_by(|((_, a), _), ((_, b), _)| a.cmp(b))
+      .map(|(_, schema)| schema)
+  }
+
+  /// Get the highest non-deprecated version of a schema, if any
+  #[must_use]
+  pub fn latest_active(&self, id: &SchemaId) -> Option<&Schema> {
+    self
+      .schemas
+      .iter()
+      .filter(|((schema_id, _), schema)| schema_id == id && !schema.deprecated)
+      .max_by(|((_, a), _), ((_, b), _)| a.cmp(b))
+      .map(|(_, schema)| schema)
+  }
+
+  /// Mark a registered schema version as deprecated
+  ///
+  /// Deprecated versions stay registered: `get` and `latest` still return
+  /// them, only `latest_active` skips them.
+  ///
+  /// # Errors
+  ///
+  /// Returns `SchemaRegistryError::VersionNotFound` if the version is not registered
+  pub fn deprecate(
+    &mut self,
+    id: &SchemaId,
+    version: SchemaVersion,
+    note: String,
+  ) -> Result<(), SchemaRegistryError> {
+    let schemas = Arc::make_mut(&mut self.schemas);
+    let key = (id.clone(), version);
+
+    let schema = schemas
+      .get_mut(&key)
+      .ok_or_else(|| SchemaRegistryError::VersionNotFound {
+        id: key.0.as_str().to_string(),
+        version: key.1.as_str().to_string(),
+      })?;
+    schema.deprecated = true;
+    schema.deprecation_note = Some(note);
+    Ok(())
+  }
+
+  /// Check whether a registered schema version is deprecated
+  ///
+  /// # Errors
+  ///
+  /// Returns `SchemaRegistryError::VersionNotFound` if the version is not registered
+  pub fn is_deprecated(
+    &self,
+    id: &SchemaId,
+    version: &SchemaVersion,
+  ) -> Result<bool, SchemaRegistryError> {
+    self
+      .schemas
+      .get(&(id.clone(), version.clone()))
+      .map(|schema| schema.deprecated)
+      .ok_or_else(|| SchemaRegistryError::VersionNotFound {
         id: id.as_str().to_string(),
-        version: "any".to_string(),
+        version: version.as_str().to_string(),
       })
   }
 
+  /// List the registered versions of a schema, sorted ascending
+  #[must_use]
+  pub fn versions(&self, id: &SchemaId) -> Vec<SchemaVersion> {
+    let mut versions: Vec<SchemaVersion> = self
+      .schemas
+      .keys()
+      .filter(|(schema_id, _)| schema_id == id)
+      .map(|(_, version)| version.clone())
+      .collect();
+    versions.sort();
+    versions
+  }
+
   /// List all schemas
   #[must_use]
   pub fn list_all(&self) -> Vec<Schema> {
@@ -192,6 +386,31 @@ impl SchemaRegistry {
       .collect()
   }
 
+  /// Check whether a candidate schema is backward-compatible with the latest version
+  ///
+  /// Removing or adding required properties and changing types are breaking;
+  /// adding optional properties or relaxing requirements is additive. The order of
+  /// `changes` is deterministic for a given pair of schemas.
+  ///
+  /// # Errors
+  ///
+  /// Returns `SchemaRegistryError::NotFound` if no versions of the schema exist
+  pub fn check_compatibility(
+    &self,
+    id: &SchemaId,
+    candidate: &Schema,
+  ) -> Result<CompatibilityReport, SchemaRegistryError> {
+    let latest = self.get_latest(id)?;
+
+    let mut changes = Vec::new();
+    diff_schemas("", &latest.schema, &candidate.schema, &mut changes);
+
+    Ok(CompatibilityReport {
+      compatible: !changes.iter().any(SchemaChange::is_breaking),
+      changes,
+    })
+  }
+
   /// Validate JSON data against a schema
   ///
   /// # Errors
@@ -209,12 +428,11 @@ impl SchemaRegistry {
     // Perform basic JSON schema validation
     // For now, we'll do a simplified check - full JSON schema validation
     // would require the `jsonschema` crate
-    self.validate_against_schema(&schema.schema, data)
+    Self::validate_against_schema(&schema.schema, data)
   }
 
   /// Internal validation logic
   fn validate_against_schema(
-    &self,
     schema: &serde_json::Value,
     data: &serde_json::Value,
   ) -> Result<(), SchemaRegistryError> {
@@ -222,7 +440,7 @@ impl SchemaRegistry {
     let schema_type = schema.get("type").and_then(|v| v.as_str()).ok_or_else(|| {
       SchemaRegistryError::ValidationError {
         message: "Schema must have a 'type' field".to_string(),
-        path: "".to_string(),
+        path: "/".to_string(),
       }
     })?;
 
@@ -231,7 +449,7 @@ impl SchemaRegistry {
       "object" => {
         if !data.is_object() {
           return Err(SchemaRegistryError::ValidationError {
-            message: format!("Expected object, got {}", self.get_type_name(data)),
+            message: format!("Expected object, got {}", json_type_name(data)),
             path: "/".to_string(),
           });
         }
@@ -253,7 +471,7 @@ impl SchemaRegistry {
       "string" => {
         if !data.is_string() {
           return Err(SchemaRegistryError::ValidationError {
-            message: format!("Expected string, got {}", self.get_type_name(data)),
+            message: format!("Expected string, got {}", json_type_name(data)),
             path: "/".to_string(),
           });
         }
@@ -261,7 +479,7 @@ impl SchemaRegistry {
       "number" | "integer" => {
         if !data.is_number() {
           return Err(SchemaRegistryError::ValidationError {
-            message: format!("Expected number, got {}", self.get_type_name(data)),
+            message: format!("Expected number, got {}", json_type_name(data)),
             path: "/".to_string(),
           });
         }
@@ -269,7 +487,7 @@ impl SchemaRegistry {
       "boolean" => {
         if !data.is_boolean() {
           return Err(SchemaRegistryError::ValidationError {
-            message: format!("Expected boolean, got {}", self.get_type_name(data)),
+            message: format!("Expected boolean, got {}", json_type_name(data)),
             path: "/".to_string(),
           });
         }
@@ -277,7 +495,7 @@ impl SchemaRegistry {
       "array" => {
         if !data.is_array() {
           return Err(SchemaRegistryError::ValidationError {
-            message: format!("Expected array, got {}", self.get_type_name(data)),
+            message: format!("Expected array, got {}", json_type_name(data)),
             path: "/".to_string(),
           });
         }
@@ -292,20 +510,261 @@ impl SchemaRegistry {
 
     Ok(())
   }
+}
+
+/// Collect the changes between two schema nodes at `path`, recursing into properties
+fn diff_schemas(
+  path: &str,
+  old: &serde_json::Value,
+  new: &serde_json::Value,
+  changes: &mut Vec<SchemaChange>,
+) {
+  let old_type = old.get("type");
+  let new_type = new.get("type");
+  if old_type != new_type {
+    changes.push(SchemaChange::TypeChanged {
+      path: pointer(path),
+      from: type_label(old_type),
+      to: type_label(new_type),
+    });
+    return;
+  }
+
+  let old_required = required_names(old);
+  let new_required = required_names(new);
+  let old_properties = old.get("properties").and_then(serde_json::Value::as_object);
+  let new_properties = new.get("properties").and_then(serde_json::Value::as_object);
+
+  let names: BTreeSet<&str> = old_properties
+    .into_iter()
+    .chain(new_properties)
+    .flat_map(|properties| properties.keys().map(String::as_str))
+    .chain(old_required.iter().copied())
+    .chain(new_required.iter().copied())
+    .collect();
+
+  for name in names {
+    let property_path = child_path(path, name);
+    let was_required = old_required.contains(name);
+    let is_required = new_required.contains(name);
+    let old_property = old_properties.and_then(|p| p.get(name));
+    let new_property = new_properties.and_then(|p| p.get(name));
+    let was_declared = old_property.is_some() || was_required;
+    let is_declared = new_property.is_some() || is_required;
+
+    match (was_declared, is_declared) {
+      (false, true) => changes.push(SchemaChange::PropertyAdded {
+        path: property_path,
+        required: is_required,
+      }),
+      (true, false) => changes.push(SchemaChange::PropertyRemoved {
+        path: property_path,
+        required: was_required,
+      }),
+      _ => {
+        if !was_required && is_required {
+          changes.push(SchemaChange::PropertyMadeRequired {
+            path: property_path.clone(),
+          });
+        } else if was_required && !is_required {
+          changes.push(SchemaChange::PropertyMadeOptional {
+            path: property_path.clone(),
+          });
+        }
 
-  /// Get the type name of a JSON value
-  fn get_type_name(&self, value: &serde_json::Value) -> &str {
-    match value {
-      serde_json::Value::Null => "null",
-      serde_json::Value::Bool(_) => "boolean",
-      serde_json::Value::Number(_) => "number",
-      serde_json::Value::String(_) => "string",
-      serde_json::Value::Array(_) => "array",
-      serde_json::Value::Object(_) => "object",
+        if let (Some(old_property), Some(new_property)) = (old_property, new_property) {
+          diff_schemas(&property_path, old_property, new_property, changes);
+        }
+      }
     }
   }
 }
 
+/// Collect violations of `schema` by `value` at `path`, recursing into children
+///
+/// `path` is a JSON pointer with the root as `""`; messages report the root as `/`.
+fn check_value(
+  path: &str,
+  schema: &serde_json::Value,
+  value: &serde_json::Value,
+  messages: &mut Vec<ValidationMessage>,
+) {
+  if let Some(expected) = schema.get("type").and_then(serde_json::Value::as_str) {
+    if !matches_type(expected, value) {
+      messages.push(ValidationMessage::error(
+        pointer(path),
+        format!("expected {expected}, got {}", json_type_name(value)),
+      ));
+      return;
+    }
+  }
+
+  if let Some(allowed) = schema.get("enum").and_then(serde_json::Value::as_array) {
+    if !allowed.contains(value) {
+      messages.push(ValidationMessage::error(
+        pointer(path),
+        format!("value {value} is not one of the allowed values"),
+      ));
+    }
+  }
+
+  match value {
+    serde_json::Value::Object(object) => {
+      for name in required_names(schema) {
+        if !object.contains_key(name) {
+          messages.push(ValidationMessage::error(
+            child_path(path, name),
+            format!("missing required property: {name}"),
+          ));
+        }
+      }
+
+      if let Some(properties) = schema
+        .get("properties")
+        .and_then(serde_json::Value::as_object)
+      {
+        for (name, property_schema) in properties {
+          if let Some(property) = object.get(name) {
+            check_value(&child_path(path, name), property_schema, property, messages);
+          }
+        }
+      }
+    }
+    serde_json::Value::Array(items) => {
+      let bounds = ("minItems", "maxItems");
+      if let Some(message) = length_violation(schema, bounds, items.len(), "items") {
+        messages.push(ValidationMessage::error(pointer(path), message));
+      }
+
+      if let Some(item_schema) = schema.get("items") {
+        for (index, item) in items.iter().enumerate() {
+          check_value(
+            &child_path(path, &index.to_string()),
+            item_schema,
+            item,
+            messages,
+          );
+        }
+      }
+    }
+    serde_json::Value::String(text) => {
+      let length = text.chars().count();
+      let bounds = ("minLength", "maxLength");
+      if let Some(message) = length_violation(schema, bounds, length, "characters") {
+        messages.push(ValidationMessage::error(pointer(path), message));
+      }
+    }
+    serde_json::Value::Number(number) => {
+      if let Some(number) = number.as_f64() {
+        let minimum = schema.get("minimum").and_then(serde_json::Value::as_f64);
+        let maximum = schema.get("maximum").and_then(serde_json::Value::as_f64);
+        if let Some(minimum) = minimum.filter(|minimum| number < *minimum) {
+          messages.push(ValidationMessage::error(
+            pointer(path),
+            format!("value {number} is less than minimum {minimum}"),
+          ));
+        }
+        if let Some(maximum) = maximum.filter(|maximum| number > *maximum) {
+          messages.push(ValidationMessage::error(
+            pointer(path),
+            format!("value {number} is greater than maximum {maximum}"),
+          ));
+        }
+      }
+    }
+    serde_json::Value::Null | serde_json::Value::Bool(_) => {}
+  }
+}
+
+/// Describe how `length` breaks the optional `(min, max)` bound keywords in `schema`
+fn length_violation(
+  schema: &serde_json::Value,
+  (min_key, max_key): (&str, &str),
+  length: usize,
+  unit: &str,
+) -> Option<String> {
+  let bound = |key: &str| {
+    schema
+      .get(key)
+      .and_then(serde_json::Value::as_u64)
+      .and_then(|bound| usize::try_from(bound).ok())
+  };
+
+  let too_short = bound(min_key)
+    .filter(|min| length < *min)
+    .map(|min| format!("expected at least {min} {unit}, got {length}"));
+  let too_long = bound(max_key)
+    .filter(|max| length > *max)
+    .map(|max| format!("expected at most {max} {unit}, got {length}"));
+
+  too_short.or(too_long)
+}
+
+/// Whether `value` satisfies the JSON schema type name `expected`
+fn matches_type(expected: &str, value: &serde_json::Value) -> bool {
+  match expected {
+    "object" => value.is_object(),
+    "array" => value.is_array(),
+    "string" => value.is_string(),
+    "number" => value.is_number(),
+    "integer" => value.is_i64() || value.is_u64(),
+    "boolean" => value.is_boolean(),
+    "null" => value.is_null(),
+    _ => true,
+  }
+}
+
+/// JSON type name of a value, for messages
+const fn json_type_name(value: &serde_json::Value) -> &'static str {
+  match value {
+    serde_json::Value::Null => "null",
+    serde_json::Value::Bool(_) => "boolean",
+    serde_json::Value::Number(_) => "number",
+    serde_json::Value::String(_) => "string",
+    serde_json::Value::Array(_) => "array",
+    serde_json::Value::Object(_) => "object",
+  }
+}
+
+/// JSON pointer to the child `name` of the value at `parent`
+///
+/// `~` and `/` in the name are escaped as `~0` and `~1`.
+fn child_path(parent: &str, name: &str) -> String {
+  format!("{parent}/{}", name.replace('~', "~0").replace('/', "~1"))
+}
+
+/// A JSON pointer as reported to callers, with the root shown as `/`
+fn pointer(path: &str) -> String {
+  if path.is_empty() {
+    "/".to_string()
+  } else {
+    path.to_string()
+  }
+}
+
+/// Names listed in a schema's `required` array
+fn required_names(schema: &serde_json::Value) -> BTreeSet<&str> {
+  schema
+    .get("required")
+    .and_then(serde_json::Value::as_array)
+    .map(|required| {
+      required
+        .iter()
+        .filter_map(serde_json::Value::as_str)
+        .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Human-readable form of a schema `type` field
+fn type_label(schema_type: Option<&serde_json::Value>) -> String {
+  match schema_type {
+    Some(serde_json::Value::String(name)) => name.clone(),
+    Some(other) => other.to_string(),
+    None => "none".to_string(),
+  }
+}
+
 /// Errors that can occur in the schema registry
 #[derive(Debug, Error, Clone, PartialEq, Eq)]
 pub enum SchemaRegistryError {
@@ -325,6 +784,10 @@ pub enum SchemaRegistryError {
   #[error("Schema not found: {id} version {version}")]
   NotFound { id: String, version: String },
 
+  /// The given version of a schema is not registered
+  #[error("Schema version not registered: {id} version {version}")]
+  VersionNotFound { id: String, version: String },
+
   /// Validation failed
   #[error("Validation failed at {path}: {message}")]
   ValidationError { message: String, path: String },
@@ -332,6 +795,7 @@ pub enum SchemaRegistryError {
 
 #[cfg(test)]
 #[allow(clippy::disallowed_methods)]
+#[allow(clippy::unwrap_used)]
 #[allow(clippy::panic)]
 mod tests {
   use super::*;
@@ -350,6 +814,8 @@ mod tests {
       description: None,
       schema: schema_def,
       created_at: Utc::now(),
+      deprecated: false,
+      deprecation_note: None,
     }
   }
 
@@ -459,4 +925,355 @@ mod tests {
     assert!(result.is_err());
     assert!(matches!(result, Err(SchemaRegistryError::ValidationError { .. })));
   }
+
+  fn registry_with_user_schema() -> (SchemaRegistry, SchemaId) {
+    let mut registry = SchemaRegistry::new();
+    let schema = create_test_schema(
+      "user",
+      "1.0.0",
+      "User Schema",
+      serde_json::json!({
+        "type": "object",
+        "required": ["name", "email"],
+        "properties": {
+          "name": {"type": "string"},
+          "email": {"type": "string"},
+          "age": {"type": "integer"}
+        }
+      }),
+    );
+    let id = schema.id.clone();
+    registry.register(schema).unwrap();
+    (registry, id)
+  }
+
+  #[test]
+  fn test_check_compatibility_additive_change() {
+    let (registry, id) = registry_with_user_schema();
+    let candidate = create_test_schema(
+      "user",
+      "1.1.0",
+      "User Schema",
+      serde_json::json!({
+        "type": "object",
+        "required": ["name"],
+        "properties": {
+          "name": {"type": "string"},
+          "email": {"type": "string"},
+          "age": {"type": "integer"},
+          "nickname": {"type": "string"}
+        }
+      }),
+    );
+
+    let report = registry.check_compatibility(&id, &candidate).unwrap();
+
+    assert!(report.compatible);
+    assert_eq!(
+      report.changes,
+      vec![
+        SchemaChange::PropertyMadeOptional {
+          path: "/email".to_string()
+        },
+        SchemaChange::PropertyAdded {
+          path: "/nickname".to_string(),
+          required: false
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_check_compatibility_breaking_changes_in_path_order() {
+    let (registry, id) = registry_with_user_schema();
+    let candidate = create_test_schema(
+      "user",
+      "2.0.0",
+      "User Schema",
+      serde_json::json!({
+        "type": "object",
+        "required": ["name", "age"],
+        "properties": {
+          "name": {"type": "string"},
+          "age": {"type": "string"}
+        }
+      }),
+    );
+
+    let report = registry.check_compatibility(&id, &candidate).unwrap();
+
+    assert!(!report.compatible);
+    assert_eq!(
+      report.changes,
+      vec![
+        SchemaChange::PropertyMadeRequired {
+          path: "/age".to_string()
+        },
+        SchemaChange::TypeChanged {
+          path: "/age".to_string(),
+          from: "integer".to_string(),
+          to: "string".to_string()
+        },
+        SchemaChange::PropertyRemoved {
+          path: "/email".to_string(),
+          required: true
+        },
+      ]
+    );
+    assert!(report.changes.iter().all(SchemaChange::is_breaking));
+  }
+
+  #[test]
+  fn test_check_compatibility_root_type_change() {
+    let (registry, id) = registry_with_user_schema();
+    let candidate = create_test_schema(
+      "user",
+      "2.0.0",
+      "User Schema",
+      serde_json::json!({"type": "array"}),
+    );
+
+    let report = registry.check_compatibility(&id, &candidate).unwrap();
+
+    assert!(!report.compatible);
+    assert_eq!(report.changes.len(), 1);
+    assert_eq!(report.changes[0].path(), "/");
+  }
+
+  #[test]
+  fn test_check_compatibility_unknown_schema() {
+    let registry = SchemaRegistry::new();
+    let candidate = create_test_schema(
+      "user",
+      "1.0.0",
+      "User Schema",
+      serde_json::json!({"type": "object"}),
+    );
+
+    let result = registry.check_compatibility(&candidate.id, &candidate);
+    assert!(matches!(result, Err(SchemaRegistryError::NotFound { .. })));
+  }
+
+  fn version(version: &str) -> SchemaVersion {
+    SchemaVersion::new(version.to_string()).unwrap()
+  }
+
+  #[test]
+  fn test_schema_version_orders_semantically() {
+    assert!(version("1.0.0") < version("1.2.0"));
+    assert!(version("1.2.0") < version("1.10.0"));
+    assert!(version("1.10.0") < version("2.0.0"));
+    assert!(version("1.0") < version("1.0.0"));
+  }
+
+  #[test]
+  fn test_latest_and_versions() {
+    let mut registry = SchemaRegistry::new();
+    for v in ["2.0.0", "1.0.0", "1.2.0"] {
+      registry
+        .register(create_test_schema(
+          "user",
+          v,
+          "User Schema",
+          serde_json::json!({"type": "object"}),
+        ))
+        .unwrap();
+    }
+    let id = SchemaId::new("user".to_string()).unwrap();
+
+    assert_eq!(
+      registry.versions(&id),
+      vec![version("1.0.0"), version("1.2.0"), version("2.0.0")]
+    );
+    assert_eq!(
+      registry.latest(&id).map(|schema| schema.version.clone()),
+      Some(version("2.0.0"))
+    );
+  }
+
+  #[test]
+  fn test_latest_unknown_schema() {
+    let registry = SchemaRegistry::new();
+    let id = SchemaId::new("user".to_string()).unwrap();
+
+    assert!(registry.latest(&id).is_none());
+    assert!(registry.versions(&id).is_empty());
+  }
+
+  fn address_schema() -> Schema {
+    create_test_schema(
+      "customer",
+      "1.0.0",
+      "Customer Schema",
+      serde_json::json!({
+        "type": "object",
+        "required": ["name", "address"],
+        "properties": {
+          "name": {"type": "string", "minLength": 1},
+          "age": {"type": "integer", "minimum": 0},
+          "address": {
+            "type": "object",
+            "required": ["zip"],
+            "properties": {"zip": {"type": "string"}}
+          },
+          "tags": {"type": "array", "items": {"type": "string"}}
+        }
+      }),
+    )
+  }
+
+  #[test]
+  fn test_validate_value_valid() {
+    let report = address_schema().validate_value(&serde_json::json!({
+      "name": "Ada",
+      "age": 36,
+      "address": {"zip": "12345"},
+      "tags": ["vip"]
+    }));
+
+    assert!(report.is_valid());
+    assert!(report.messages().is_empty());
+  }
+
+  #[test]
+  fn test_validate_value_missing_required_field() {
+    let report = address_schema().validate_value(&serde_json::json!({
+      "name": "Ada",
+      "address": {}
+    }));
+
+    assert!(!report.is_valid());
+    assert_eq!(
+      report.messages(),
+      &[ValidationMessage::error(
+        "/address/zip",
+        "missing required property: zip"
+      )]
+    );
+    assert_eq!(
+      report.messages()[0].severity,
+      crate::quality::Severity::Error
+    );
+  }
+
+  #[test]
+  fn test_validate_value_nested_arrays_and_constraints() {
+    let report = address_schema().validate_value(&serde_json::json!({
+      "name": "",
+      "age": -1,
+      "address": {"zip": 12345},
+      "tags": ["ok", 7]
+    }));
+
+    let paths: Vec<&str> = report
+      .messages()
+      .iter()
+      .map(|m| m.field_path.as_str())
+      .collect();
+    assert_eq!(paths, vec!["/address/zip", "/age", "/name", "/tags/1"]);
+  }
+
+  #[test]
+  fn test_validate_value_wrong_root_type() {
+    let report = address_schema().validate_value(&serde_json::json!([]));
+
+    assert_eq!(report.messages().len(), 1);
+    assert_eq!(report.messages()[0].field_path, "/");
+    assert_eq!(report.messages()[0].message, "expected object, got array");
+  }
+
+  #[test]
+  fn test_validate_value_escapes_pointer_tokens() {
+    let schema = create_test_schema(
+      "paths",
+      "1.0.0",
+      "Paths Schema",
+      serde_json::json!({
+        "type": "object",
+        "required": ["a/b", "c~d"]
+      }),
+    );
+
+    let report = schema.validate_value(&serde_json::json!({}));
+
+    let paths: Vec<&str> = report
+      .messages()
+      .iter()
+      .map(|m| m.field_path.as_str())
+      .collect();
+    assert_eq!(paths, vec!["/a~1b", "/c~0d"]);
+  }
+
+  fn registry_with_versions(versions: &[&str]) -> (SchemaRegistry, SchemaId) {
+    let mut registry = SchemaRegistry::new();
+    for v in versions {
+      registry
+        .register(create_test_schema(
+          "user",
+          v,
+          "User Schema",
+          serde_json::json!({"type": "object"}),
+        ))
+        .unwrap();
+    }
+    (registry, SchemaId::new("user".to_string()).unwrap())
+  }
+
+  #[test]
+  fn test_deprecate_version() {
+    let (mut registry, id) = registry_with_versions(&["1.0.0", "2.0.0"]);
+
+    registry
+      .deprecate(
+        &id,
+        version("2.0.0"),
+        "use 1.0.0 until 3.0.0 ships".to_string(),
+      )
+      .unwrap();
+
+    assert_eq!(registry.is_deprecated(&id, &version("2.0.0")), Ok(true));
+    assert_eq!(registry.is_deprecated(&id, &version("1.0.0")), Ok(false));
+    let deprecated = registry.get(&id, &version("2.0.0")).unwrap();
+    assert_eq!(
+      deprecated.deprecation_note.as_deref(),
+      Some("use 1.0.0 until 3.0.0 ships")
+    );
+  }
+
+  #[test]
+  fn test_latest_active_skips_deprecated() {
+    let (mut registry, id) = registry_with_versions(&["1.0.0", "1.2.0", "2.0.0"]);
+    registry
+      .deprecate(&id, version("2.0.0"), "broken".to_string())
+      .unwrap();
+
+    assert_eq!(
+      registry.latest(&id).map(|schema| schema.version.clone()),
+      Some(version("2.0.0"))
+    );
+    assert_eq!(
+      registry
+        .latest_active(&id)
+        .map(|schema| schema.version.clone()),
+      Some(version("1.2.0"))
+    );
+  }
+
+  #[test]
+  fn test_deprecate_unknown_version() {
+    let (mut registry, id) = registry_with_versions(&["1.0.0"]);
+
+    let result = registry.deprecate(&id, version("9.9.9"), "gone".to_string());
+    assert_eq!(
+      result,
+      Err(SchemaRegistryError::VersionNotFound {
+        id: "user".to_string(),
+        version: "9.9.9".to_string()
+      })
+    );
+    assert!(matches!(
+      registry.is_deprecated(&id, &version("9.9.9")),
+      Err(SchemaRegistryError::VersionNotFound { .. })
+    ));
+  }
 }
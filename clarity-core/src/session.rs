@@ -20,6 +20,12 @@
 //! - No unwraps or panics
 //! - Result types for error handling
 
+use crate::analysis::AnalysisResult;
+use crate::interview::{Interview, InterviewId};
+use crate::types::is_valid_uuid;
+use crate::types::time::InvalidSystemTime;
+pub use crate::types::time::Timestamp;
+use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display};
 use thiserror::Error;
 
@@ -59,6 +65,14 @@ impl SessionId {
     }
   }
 
+  /// Creates a fresh random (v4) `SessionId`
+  ///
+  /// Use [`SessionId::new`] to rehydrate a stored id instead.
+  #[must_use]
+  pub fn generate() -> Self {
+    Self(uuid::Uuid::new_v4().to_string())
+  }
+
   /// Get the underlying UUID string
   #[must_use]
   pub fn as_str(&self) -> &str {
@@ -97,10 +111,26 @@ impl Display for SessionKind {
   }
 }
 
+impl std::str::FromStr for SessionKind {
+  type Err = SessionError;
+
+  /// Parse a kind from its `Display` form, e.g. `interview`
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "interview" => Ok(Self::Interview),
+      "analysis" => Ok(Self::Analysis),
+      "planning" => Ok(Self::Planning),
+      _ => Err(SessionError::InvalidKind(s.to_string())),
+    }
+  }
+}
+
 /// The state of a session in its lifecycle
 ///
 /// Sessions follow a strict state machine to prevent invalid transitions.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Serializes as its `Display` form, e.g. `"in_progress"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SessionState {
   /// Session has been created but not started
   Created,
@@ -118,6 +148,20 @@ pub enum SessionState {
   Cancelled,
 }
 
+impl SessionState {
+  /// Every state, in workflow order
+  #[must_use]
+  pub const fn all() -> [Self; 5] {
+    [
+      Self::Created,
+      Self::InProgress,
+      Self::Completed,
+      Self::Failed,
+      Self::Cancelled,
+    ]
+  }
+}
+
 impl Display for SessionState {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     match self {
@@ -130,6 +174,48 @@ impl Display for SessionState {
   }
 }
 
+impl std::str::FromStr for SessionState {
+  type Err = SessionError;
+
+  /// Parse a state from its `Display` form, e.g. `in_progress`
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "created" => Ok(Self::Created),
+      "in_progress" => Ok(Self::InProgress),
+      "completed" => Ok(Self::Completed),
+      "failed" => Ok(Self::Failed),
+      "cancelled" => Ok(Self::Cancelled),
+      _ => Err(SessionError::InvalidState(s.to_string())),
+    }
+  }
+}
+
+/// The output a session produced
+///
+/// Each variant belongs to one `SessionKind`. There is no variant for
+/// planning sessions yet, since plans have no type of their own.
+///
+/// Serializes as `{"kind": "interview", "value": { ... }}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum SessionResult {
+  /// The completed interview, with its answers
+  Interview(Interview),
+  /// The findings of an analysis
+  Analysis(AnalysisResult),
+}
+
+impl SessionResult {
+  /// The kind of session that produces this result
+  #[must_use]
+  pub const fn kind(&self) -> SessionKind {
+    match self {
+      Self::Interview(_) => SessionKind::Interview,
+      Self::Analysis(_) => SessionKind::Analysis,
+    }
+  }
+}
+
 /// A session in the Clarity system
 ///
 /// Sessions represent discrete units of work: interviews, analyses, or planning activities.
@@ -142,6 +228,9 @@ pub struct Session {
   /// The type of session
   pub kind: SessionKind,
 
+  /// The interview this session runs (present only for interview sessions)
+  pub interview_id: Option<InterviewId>,
+
   /// Current state of the session
   pub state: SessionState,
 
@@ -156,6 +245,12 @@ pub struct Session {
 
   /// Optional description of the session
   pub description: Option<String>,
+
+  /// When the session was soft-deleted, if it has been
+  pub deleted_at: Option<Timestamp>,
+
+  /// What the session produced, set when it completes via `complete_with`
+  pub result: Option<SessionResult>,
 }
 
 impl Session {
@@ -163,16 +258,22 @@ impl Session {
   ///
   /// # Errors
   ///
-  /// Returns `SessionError::InvalidIdFormat` if the ID is not a valid UUID
+  /// Returns `SessionError::MissingField` if an interview session has no interview id
+  /// Returns `SessionError::UnexpectedInterviewId` if a non-interview session has one
   ///
   /// # Examples
   ///
   /// ```rust
+  /// use clarity_core::interview::InterviewId;
   /// use clarity_core::session::{Session, SessionKind, SessionState, Timestamp};
   ///
   /// let session = Session::builder()
   ///     .id("550e8400-e29b-41d4-a716-446655440000".to_string())
   ///     .kind(SessionKind::Interview)
+  ///     .interview_id(
+  ///         InterviewId::new("6ba7b810-9dad-11d1-80b4-00c04fd430c8".to_string())
+  ///             .expect("valid interview ID"),
+  ///     )
   ///     .title("User Requirements Interview".to_string())
   ///     .build()
   ///     .expect("valid session ID and kind provided");
@@ -183,36 +284,22 @@ impl Session {
   pub fn new(
     id: SessionId,
     kind: SessionKind,
+    interview_id: Option<InterviewId>,
     created_at: Timestamp,
   ) -> Result<Self, SessionError> {
+    validate_interview_link(kind, interview_id.as_ref())?;
+
     Ok(Self {
       id,
       kind,
+      interview_id,
       state: SessionState::Created,
       created_at,
       updated_at: created_at,
       title: None,
       description: None,
-    })
-  }
-
-  /// Private constructor that accepts all fields for builder use
-  #[allow(clippy::unnecessary_wraps)]
-  fn with_optional_fields(
-    id: SessionId,
-    kind: SessionKind,
-    created_at: Timestamp,
-    title: Option<String>,
-    description: Option<String>,
-  ) -> Result<Self, SessionError> {
-    Ok(Self {
-      id,
-      kind,
-      state: SessionState::Created,
-      created_at,
-      updated_at: created_at,
-      title,
-      description,
+      deleted_at: None,
+      result: None,
     })
   }
 
@@ -233,11 +320,16 @@ impl Session {
   /// # Examples
   ///
   /// ```rust
+  /// use clarity_core::interview::InterviewId;
   /// use clarity_core::session::{Session, SessionKind, SessionState, Timestamp};
   ///
   /// let session = Session::builder()
   ///     .id("550e8400-e29b-41d4-a716-446655440000".to_string())
   ///     .kind(SessionKind::Interview)
+  ///     .interview_id(
+  ///         InterviewId::new("6ba7b810-9dad-11d1-80b4-00c04fd430c8".to_string())
+  ///             .expect("valid interview ID"),
+  ///     )
   ///     .build()
   ///     .expect("valid session ID and kind provided");
   ///
@@ -272,11 +364,14 @@ impl Session {
       Ok(Self {
         id: self.id.clone(),
         kind: self.kind,
+        interview_id: self.interview_id.clone(),
         state: new_state,
         created_at: self.created_at,
         updated_at,
         title: self.title.clone(),
         description: self.description.clone(),
+        deleted_at: self.deleted_at,
+        result: self.result.clone(),
       })
     } else {
       Err(SessionError::InvalidStateTransition {
@@ -286,6 +381,56 @@ impl Session {
     }
   }
 
+  /// Complete the session and attach what it produced
+  ///
+  /// A session completes once; completing it again is rejected rather than
+  /// replacing the stored result.
+  ///
+  /// # Errors
+  ///
+  /// Returns `SessionError::ResultKindMismatch` if `result` is not the kind this session produces
+  /// Returns `SessionError::InvalidStateTransition` if the session is not in progress
+  pub fn complete_with(&self, result: SessionResult, at: Timestamp) -> Result<Self, SessionError> {
+    if self.state != SessionState::InProgress {
+      return Err(SessionError::InvalidStateTransition {
+        from: self.state,
+        to: SessionState::Completed,
+      });
+    }
+    if result.kind() != self.kind {
+      return Err(SessionError::ResultKindMismatch {
+        kind: self.kind,
+        result: result.kind(),
+      });
+    }
+    let completed = self.transition_to(SessionState::Completed, at)?;
+    Ok(Self {
+      result: Some(result),
+      ..completed
+    })
+  }
+
+  /// Soft-delete the session by cancelling it and recording when
+  ///
+  /// Deleting an already deleted session keeps the original `deleted_at`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `SessionError::InvalidStateTransition` if the session cannot be cancelled
+  pub fn soft_delete(&self, deleted_at: Timestamp) -> Result<Self, SessionError> {
+    let cancelled = self.transition_to(SessionState::Cancelled, deleted_at)?;
+    Ok(Self {
+      deleted_at: self.deleted_at.or(Some(deleted_at)),
+      ..cancelled
+    })
+  }
+
+  /// Check if the session has been soft-deleted
+  #[must_use]
+  pub const fn is_deleted(&self) -> bool {
+    self.deleted_at.is_some()
+  }
+
   /// Check if the session is in a terminal state (completed, failed, or cancelled)
   #[must_use]
   pub const fn is_terminal(&self) -> bool {
@@ -300,6 +445,12 @@ impl Session {
   pub const fn is_active(&self) -> bool {
     !self.is_terminal()
   }
+
+  /// Get the interview this session runs, if it is an interview session
+  #[must_use]
+  pub const fn interview_id(&self) -> Option<&InterviewId> {
+    self.interview_id.as_ref()
+  }
 }
 
 /// Builder for constructing Session instances
@@ -309,6 +460,7 @@ impl Session {
 pub struct SessionBuilder {
   id: Option<String>,
   kind: Option<SessionKind>,
+  interview_id: Option<InterviewId>,
   created_at: Option<Timestamp>,
   title: Option<String>,
   description: Option<String>,
@@ -321,7 +473,7 @@ impl SessionBuilder {
     Self::default()
   }
 
-  /// Set the session ID
+  /// Set the session ID; a random one is generated if this is not called
   #[must_use]
   pub fn id(mut self, id: String) -> Self {
     self.id = Some(id);
@@ -335,6 +487,13 @@ impl SessionBuilder {
     self
   }
 
+  /// Set the interview this session runs (required for interview sessions)
+  #[must_use]
+  pub fn interview_id(mut self, interview_id: InterviewId) -> Self {
+    self.interview_id = Some(interview_id);
+    self
+  }
+
   /// Set the creation timestamp
   #[must_use]
   pub fn created_at(mut self, timestamp: Timestamp) -> Self {
@@ -364,10 +523,9 @@ impl SessionBuilder {
   /// Returns `SessionError::InvalidIdFormat` if the ID is not a valid UUID
   /// Returns `SessionError::SystemTimeInvalid` if no timestamp is provided and
   /// the system time is invalid
+  /// Returns `SessionError::MissingField` if an interview session has no interview id
+  /// Returns `SessionError::UnexpectedInterviewId` if a non-interview session has one
   pub fn build(self) -> Result<Session, SessionError> {
-    let id = self
-      .id
-      .ok_or_else(|| SessionError::MissingField("id".to_string()))?;
     let kind = self
       .kind
       .ok_or_else(|| SessionError::MissingField("kind".to_string()))?;
@@ -376,48 +534,15 @@ impl SessionBuilder {
       None => Timestamp::now()?,
     };
 
-    let session_id = SessionId::new(id)?;
-    Session::with_optional_fields(session_id, kind, created_at, self.title, self.description)
-  }
-}
-
-/// Timestamp for session events
-///
-/// Represented as Unix timestamp (seconds since epoch).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Timestamp(i64);
-
-impl Timestamp {
-  /// Create a new Timestamp from seconds since epoch
-  #[must_use]
-  pub const fn from_secs(secs: i64) -> Self {
-    Self(secs)
-  }
-
-  /// Get the current time as a Timestamp
-  ///
-  /// # Errors
-  ///
-  /// Returns `SessionError::SystemTimeInvalid` if the system time is invalid
-  /// (e.g., due to clock skew or being set before `UNIX_EPOCH`)
-  pub fn now() -> Result<Self, SessionError> {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    SystemTime::now()
-      .duration_since(UNIX_EPOCH)
-      .map(|d| Self(d.as_secs().cast_signed()))
-      .map_err(|_| SessionError::SystemTimeInvalid)
-  }
-
-  /// Get the underlying seconds value
-  #[must_use]
-  pub const fn as_secs(&self) -> i64 {
-    self.0
-  }
-}
-
-impl Display for Timestamp {
-  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    write!(f, "{}", self.0)
+    let session_id = match self.id {
+      Some(id) => SessionId::new(id)?,
+      None => SessionId::generate(),
+    };
+    Ok(Session {
+      title: self.title,
+      description: self.description,
+      ..Session::new(session_id, kind, self.interview_id, created_at)?
+    })
   }
 }
 
@@ -428,6 +553,14 @@ pub enum SessionError {
   #[error("invalid session ID format: {0}")]
   InvalidIdFormat(String),
 
+  /// A state name that is not one of the `SessionState` variants
+  #[error("unknown session state: {0}")]
+  InvalidState(String),
+
+  /// A kind name that is not one of the `SessionKind` variants
+  #[error("unknown session kind: {0}")]
+  InvalidKind(String),
+
   /// Attempted an invalid state transition
   #[error("invalid state transition from {from} to {to}")]
   InvalidStateTransition {
@@ -442,17 +575,49 @@ pub enum SessionError {
   /// System time is invalid (clock skew or other time-related error)
   #[error("system time is invalid, cannot create timestamp")]
   SystemTimeInvalid,
+
+  /// Only interview sessions may reference an interview
+  #[error("{kind} sessions cannot reference an interview")]
+  UnexpectedInterviewId { kind: SessionKind },
+
+  /// A session was completed with a result another kind of session produces
+  #[error("{kind} sessions cannot complete with an {result} result")]
+  ResultKindMismatch {
+    kind: SessionKind,
+    result: SessionKind,
+  },
+
+  /// No session exists with the given id
+  #[error("session not found: {0}")]
+  NotFound(String),
+
+  /// A session with the given id is already stored
+  #[error("session already exists: {0}")]
+  AlreadyExists(String),
+
+  /// The stored session changed after the caller read it
+  #[error("session {0} was modified by another request")]
+  StaleUpdate(String),
 }
 
-/// Check if a string is a valid UUID format
-fn is_valid_uuid(s: &str) -> bool {
-  // Simple UUID format validation
-  // UUIDs are 36 characters: 8-4-4-4-12 hex digits
-  s.len() == 36
-    && s.split('-').enumerate().all(|(i, part)| {
-      let expected_len = [8, 4, 4, 4, 12][i];
-      part.len() == expected_len && part.bytes().all(|b| b.is_ascii_hexdigit())
-    })
+impl From<InvalidSystemTime> for SessionError {
+  fn from(_: InvalidSystemTime) -> Self {
+    Self::SystemTimeInvalid
+  }
+}
+
+/// Check that a session references an interview exactly when it is an interview session
+fn validate_interview_link(
+  kind: SessionKind,
+  interview_id: Option<&InterviewId>,
+) -> Result<(), SessionError> {
+  match (kind, interview_id) {
+    (SessionKind::Interview, None) => Err(SessionError::MissingField("interview_id".to_string())),
+    (SessionKind::Analysis | SessionKind::Planning, Some(_)) => {
+      Err(SessionError::UnexpectedInterviewId { kind })
+    }
+    _ => Ok(()),
+  }
 }
 
 /// Check if a state transition is valid
@@ -477,6 +642,14 @@ fn is_valid_transition(from: SessionState, to: SessionState) -> bool {
 mod tests {
   use super::*;
 
+  #[allow(clippy::panic)]
+  fn test_interview_id() -> InterviewId {
+    match InterviewId::new("6ba7b810-9dad-11d1-80b4-00c04fd430c8".to_string()) {
+      Ok(id) => id,
+      Err(e) => panic!("Expected valid interview ID, got {e}"),
+    }
+  }
+
   #[allow(clippy::unwrap_used)]
   #[test]
   fn test_session_id_new_valid_uuid() {
@@ -499,6 +672,15 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_session_id_new_invalid_version_nibble() {
+    let id = "550e8400-e29b-01d4-a716-446655440000".to_string();
+    assert_eq!(
+      SessionId::new(id.clone()),
+      Err(SessionError::InvalidIdFormat(id))
+    );
+  }
+
   #[test]
   fn test_session_id_new_empty_string() {
     let result = SessionId::new(String::new());
@@ -537,6 +719,31 @@ mod tests {
     assert_eq!(format!("{}", SessionState::Cancelled), "cancelled");
   }
 
+  #[test]
+  fn test_session_state_round_trips_through_display() {
+    for state in [
+      SessionState::Created,
+      SessionState::InProgress,
+      SessionState::Completed,
+      SessionState::Failed,
+      SessionState::Cancelled,
+    ] {
+      assert_eq!(state.to_string().parse(), Ok(state));
+      assert_eq!(
+        serde_json::to_string(&state).ok(),
+        Some(format!("\"{state}\""))
+      );
+      assert_eq!(
+        serde_json::from_str::<SessionState>(&format!("\"{state}\"")).ok(),
+        Some(state)
+      );
+    }
+    assert!(matches!(
+      "InProgress".parse::<SessionState>(),
+      Err(SessionError::InvalidState(_))
+    ));
+  }
+
   #[allow(clippy::unwrap_used)]
   #[test]
   fn test_session_new() {
@@ -544,10 +751,11 @@ mod tests {
     let kind = SessionKind::Interview;
     let created_at = Timestamp::from_secs(1_234_567_890);
 
-    let session = Session::new(id.clone(), kind, created_at).unwrap();
+    let session = Session::new(id.clone(), kind, Some(test_interview_id()), created_at).unwrap();
 
     assert_eq!(session.id, id);
     assert_eq!(session.kind, SessionKind::Interview);
+    assert_eq!(session.interview_id(), Some(&test_interview_id()));
     assert_eq!(session.state, SessionState::Created);
     assert_eq!(session.created_at, created_at);
     assert_eq!(session.updated_at, created_at);
@@ -588,18 +796,19 @@ mod tests {
     );
   }
 
-  #[allow(clippy::panic)]
   #[test]
-  fn test_session_builder_missing_id() {
-    let result = Session::builder().kind(SessionKind::Interview).build();
+  fn test_session_id_generate_is_valid_uuid() {
+    let id = SessionId::generate();
 
-    assert!(result.is_err());
-    match result {
-      Err(SessionError::MissingField(field)) => {
-        assert_eq!(field, "id");
-      }
-      _ => panic!("Expected MissingField error for 'id'"),
-    }
+    assert!(is_valid_uuid(id.as_str()));
+    assert_ne!(id, SessionId::generate());
+  }
+
+  #[test]
+  fn test_session_builder_generates_missing_id() {
+    let result = Session::builder().kind(SessionKind::Analysis).build();
+
+    assert!(result.is_ok_and(|session| is_valid_uuid(session.id.as_str())));
   }
 
   #[allow(clippy::panic)]
@@ -641,6 +850,7 @@ mod tests {
     let session = Session::builder()
       .id("550e8400-e29b-41d4-a716-446655440000".to_string())
       .kind(SessionKind::Interview)
+      .interview_id(test_interview_id())
       .build()
       .unwrap();
 
@@ -662,6 +872,7 @@ mod tests {
     let session = Session::builder()
       .id("550e8400-e29b-41d4-a716-446655440000".to_string())
       .kind(SessionKind::Interview)
+      .interview_id(test_interview_id())
       .build()
       .unwrap();
 
@@ -685,6 +896,7 @@ mod tests {
     let session = Session::builder()
       .id("550e8400-e29b-41d4-a716-446655440000".to_string())
       .kind(SessionKind::Interview)
+      .interview_id(test_interview_id())
       .build()
       .unwrap();
 
@@ -708,6 +920,7 @@ mod tests {
     let session = Session::builder()
       .id("550e8400-e29b-41d4-a716-446655440000".to_string())
       .kind(SessionKind::Interview)
+      .interview_id(test_interview_id())
       .build()
       .unwrap();
 
@@ -725,6 +938,7 @@ mod tests {
     let session = Session::builder()
       .id("550e8400-e29b-41d4-a716-446655440000".to_string())
       .kind(SessionKind::Interview)
+      .interview_id(test_interview_id())
       .build()
       .unwrap();
 
@@ -747,6 +961,7 @@ mod tests {
     let session = Session::builder()
       .id("550e8400-e29b-41d4-a716-446655440000".to_string())
       .kind(SessionKind::Interview)
+      .interview_id(test_interview_id())
       .build()
       .unwrap();
 
@@ -773,6 +988,7 @@ mod tests {
     let session = Session::builder()
       .id("550e8400-e29b-41d4-a716-446655440000".to_string())
       .kind(SessionKind::Interview)
+      .interview_id(test_interview_id())
       .build()
       .unwrap();
 
@@ -799,6 +1015,7 @@ mod tests {
     let session = Session::builder()
       .id("550e8400-e29b-41d4-a716-446655440000".to_string())
       .kind(SessionKind::Interview)
+      .interview_id(test_interview_id())
       .build()
       .unwrap();
 
@@ -828,12 +1045,63 @@ mod tests {
     assert!(cancelled.is_terminal());
   }
 
+  #[allow(clippy::unwrap_used)]
+  #[test]
+  fn test_session_soft_delete() {
+    let session = Session::builder()
+      .id("550e8400-e29b-41d4-a716-446655440000".to_string())
+      .kind(SessionKind::Analysis)
+      .build()
+      .unwrap();
+    assert!(!session.is_deleted());
+
+    let deleted = session
+      .soft_delete(Timestamp::from_secs(1_234_567_891))
+      .unwrap();
+    assert_eq!(deleted.state, SessionState::Cancelled);
+    assert_eq!(
+      deleted.deleted_at,
+      Some(Timestamp::from_secs(1_234_567_891))
+    );
+
+    let again = deleted
+      .soft_delete(Timestamp::from_secs(1_234_567_892))
+      .unwrap();
+    assert_eq!(again.deleted_at, Some(Timestamp::from_secs(1_234_567_891)));
+  }
+
+  #[allow(clippy::unwrap_used)]
+  #[test]
+  fn test_session_soft_delete_rejects_completed() {
+    let completed = Session::builder()
+      .id("550e8400-e29b-41d4-a716-446655440000".to_string())
+      .kind(SessionKind::Analysis)
+      .build()
+      .unwrap()
+      .transition_to(
+        SessionState::InProgress,
+        Timestamp::from_secs(1_234_567_891),
+      )
+      .unwrap()
+      .transition_to(SessionState::Completed, Timestamp::from_secs(1_234_567_892))
+      .unwrap();
+
+    assert_eq!(
+      completed.soft_delete(Timestamp::from_secs(1_234_567_893)),
+      Err(SessionError::InvalidStateTransition {
+        from: SessionState::Completed,
+        to: SessionState::Cancelled,
+      })
+    );
+  }
+
   #[allow(clippy::unwrap_used)]
   #[test]
   fn test_session_is_active() {
     let session = Session::builder()
       .id("550e8400-e29b-41d4-a716-446655440000".to_string())
       .kind(SessionKind::Interview)
+      .interview_id(test_interview_id())
       .build()
       .unwrap();
 
@@ -924,23 +1192,6 @@ mod tests {
     assert!(result.is_ok());
   }
 
-  #[test]
-  fn test_is_valid_uuid_valid() {
-    assert!(is_valid_uuid("550e8400-e29b-41d4-a716-446655440000"));
-    assert!(is_valid_uuid("00000000-0000-0000-0000-000000000000"));
-    assert!(is_valid_uuid("ffffffff-ffff-ffff-ffff-ffffffffffff"));
-  }
-
-  #[test]
-  fn test_is_valid_uuid_invalid() {
-    assert!(!is_valid_uuid("not-a-uuid"));
-    assert!(!is_valid_uuid(""));
-    assert!(!is_valid_uuid("550e8400-e29b-41d4-a716"));
-    assert!(!is_valid_uuid("550e8400-e29b-41d4-a716-446655440000-extra"));
-    assert!(!is_valid_uuid("550e8400-e29b-41d4-a716-44665544000g"));
-    assert!(!is_valid_uuid("550e8400e29b41d4a716446655440000"));
-  }
-
   #[test]
   fn test_is_valid_transition_same_state() {
     assert!(is_valid_transition(
@@ -1016,4 +1267,169 @@ mod tests {
       SessionState::Failed
     ));
   }
+
+  #[allow(clippy::panic)]
+  #[test]
+  fn test_interview_session_requires_interview_id() {
+    let result = Session::builder()
+      .id("550e8400-e29b-41d4-a716-446655440000".to_string())
+      .kind(SessionKind::Interview)
+      .build();
+
+    match result {
+      Err(SessionError::MissingField(field)) => assert_eq!(field, "interview_id"),
+      _ => panic!("Expected MissingField error for 'interview_id'"),
+    }
+  }
+
+  #[test]
+  fn test_non_interview_sessions_reject_interview_id() {
+    for kind in [SessionKind::Analysis, SessionKind::Planning] {
+      let result = Session::builder()
+        .id("550e8400-e29b-41d4-a716-446655440000".to_string())
+        .kind(kind)
+        .interview_id(test_interview_id())
+        .build();
+
+      assert_eq!(result, Err(SessionError::UnexpectedInterviewId { kind }));
+    }
+  }
+
+  #[allow(clippy::unwrap_used)]
+  #[test]
+  fn test_interview_id_survives_transition() {
+    let session = Session::builder()
+      .id("550e8400-e29b-41d4-a716-446655440000".to_string())
+      .kind(SessionKind::Interview)
+      .interview_id(test_interview_id())
+      .build()
+      .unwrap();
+
+    let in_progress = session
+      .transition_to(
+        SessionState::InProgress,
+        Timestamp::from_secs(1_234_567_891),
+      )
+      .unwrap();
+    assert_eq!(in_progress.interview_id(), Some(&test_interview_id()));
+  }
+
+  #[test]
+  fn test_analysis_session_has_no_interview_id() {
+    let result = Session::new(
+      SessionId("550e8400-e29b-41d4-a716-446655440000".to_string()),
+      SessionKind::Analysis,
+      None,
+      Timestamp::from_secs(1_234_567_890),
+    );
+
+    assert!(result.is_ok_and(|session| session.interview_id().is_none()));
+  }
+
+  #[test]
+  fn test_session_error_unexpected_interview_id_display() {
+    let error = SessionError::UnexpectedInterviewId {
+      kind: SessionKind::Planning,
+    };
+    assert_eq!(
+      format!("{error}"),
+      "planning sessions cannot reference an interview"
+    );
+  }
+
+  #[allow(clippy::unwrap_used)]
+  #[test]
+  fn test_planning_session_rejects_interview_result() {
+    let session = Session::builder()
+      .kind(SessionKind::Planning)
+      .created_at(Timestamp::from_secs(1_000))
+      .build()
+      .unwrap()
+      .transition_to(SessionState::InProgress, Timestamp::from_secs(1_500))
+      .unwrap();
+    let interview = Interview::new(
+      test_interview_id(),
+      "petstore".to_string(),
+      Timestamp::from_secs(1_000),
+    )
+    .unwrap();
+
+    assert_eq!(
+      session.complete_with(
+        SessionResult::Interview(interview),
+        Timestamp::from_secs(2_000)
+      ),
+      Err(SessionError::ResultKindMismatch {
+        kind: SessionKind::Planning,
+        result: SessionKind::Interview,
+      })
+    );
+  }
+
+  #[allow(clippy::unwrap_used)]
+  #[test]
+  fn test_complete_with_stores_result() {
+    use crate::analysis::AnalysisId;
+    use crate::quality::QualityScore;
+
+    let session = Session::builder()
+      .kind(SessionKind::Analysis)
+      .created_at(Timestamp::from_secs(1_000))
+      .build()
+      .unwrap()
+      .transition_to(SessionState::InProgress, Timestamp::from_secs(1_500))
+      .unwrap();
+    let analysis = AnalysisResult::new(
+      AnalysisId::generate(),
+      "petstore",
+      QualityScore::new(90).unwrap(),
+      Timestamp::from_secs(1_900),
+    );
+
+    let completed = session
+      .complete_with(
+        SessionResult::Analysis(analysis.clone()),
+        Timestamp::from_secs(2_000),
+      )
+      .unwrap();
+    assert_eq!(completed.state, SessionState::Completed);
+    assert_eq!(completed.updated_at, Timestamp::from_secs(2_000));
+    assert_eq!(completed.result, Some(SessionResult::Analysis(analysis)));
+  }
+
+  #[allow(clippy::unwrap_used)]
+  #[test]
+  fn test_complete_with_rejects_completed_session() {
+    use crate::analysis::AnalysisId;
+    use crate::quality::QualityScore;
+
+    let analysis = |score| {
+      SessionResult::Analysis(AnalysisResult::new(
+        AnalysisId::generate(),
+        "petstore",
+        QualityScore::new(score).unwrap(),
+        Timestamp::from_secs(1_900),
+      ))
+    };
+    let first = analysis(90);
+    let completed = Session::builder()
+      .kind(SessionKind::Analysis)
+      .created_at(Timestamp::from_secs(1_000))
+      .build()
+      .unwrap()
+      .transition_to(SessionState::InProgress, Timestamp::from_secs(1_500))
+      .unwrap()
+      .complete_with(first.clone(), Timestamp::from_secs(2_000))
+      .unwrap();
+
+    assert_eq!(
+      completed.complete_with(analysis(10), Timestamp::from_secs(3_000)),
+      Err(SessionError::InvalidStateTransition {
+        from: SessionState::Completed,
+        to: SessionState::Completed,
+      })
+    );
+    assert_eq!(completed.result, Some(first));
+    assert_eq!(completed.updated_at, Timestamp::from_secs(2_000));
+  }
 }
@@ -5,10 +5,16 @@
 #![warn(clippy::nursery)]
 #![forbid(unsafe_code)]
 
+use crate::hash::fnv1a;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 
 /// HTTP method with validation
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+///
+/// Serializes as the uppercase method name, as it appears on the wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
 pub enum HttpMethod {
   Get,
   Post,
@@ -61,8 +67,32 @@ impl HttpMethod {
   /// Check if this method has a body
   #[must_use]
   pub const fn has_body(&self) -> bool {
+    self.has_request_body()
+  }
+
+  /// Check if requests with this method carry a body (POST, PUT and PATCH)
+  #[must_use]
+  pub const fn has_request_body(&self) -> bool {
     matches!(self, Self::Post | Self::Put | Self::Patch)
   }
+
+  /// Check if repeating a request with this method has the same effect as
+  /// sending it once, so it is safe to retry
+  #[must_use]
+  pub const fn is_idempotent(&self) -> bool {
+    matches!(
+      self,
+      Self::Get | Self::Head | Self::Put | Self::Delete | Self::Options
+    )
+  }
+}
+
+impl std::str::FromStr for HttpMethod {
+  type Err = HttpMethodError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::from_str(s)
+  }
 }
 
 impl fmt::Display for HttpMethod {
@@ -133,6 +163,33 @@ impl SpecName {
   pub fn from_str(s: &str) -> Result<Self, SpecNameError> {
     Self::new(s.to_string())
   }
+
+  /// Get a lowercase, filesystem-safe form of the name for paths and URLs
+  ///
+  /// Letters and digits, including non-ASCII ones, are lowercased; every run
+  /// of `_` and `-` becomes a single `-`, and leading and trailing hyphens are
+  /// dropped, so `"User_Auth-API"` becomes `"user-auth-api"` and `"café_menu"`
+  /// becomes `"café-menu"`. A name with no letters or digits falls back to
+  /// `spec-` followed by a hash of the name. Non-ASCII letters are kept rather
+  /// than dropped, so URLs built from a slug must percent-encode it.
+  #[must_use]
+  pub fn slug(&self) -> String {
+    let mut slug = String::with_capacity(self.0.len());
+    for c in self.0.chars() {
+      if c.is_alphanumeric() {
+        slug.extend(c.to_lowercase());
+      } else if !slug.is_empty() && !slug.ends_with('-') {
+        slug.push('-');
+      }
+    }
+    let slug = slug.trim_end_matches('-');
+
+    if slug.is_empty() {
+      format!("spec-{:016x}", fnv1a(self.0.as_bytes()))
+    } else {
+      slug.to_string()
+    }
+  }
 }
 
 impl fmt::Display for SpecName {
@@ -147,6 +204,7 @@ pub enum SpecNameError {
   Empty,
   TooLong(usize),
   InvalidCharacters(String),
+  Duplicate(String),
 }
 
 impl fmt::Display for SpecNameError {
@@ -159,12 +217,61 @@ impl fmt::Display for SpecNameError {
       Self::InvalidCharacters(name) => {
         write!(f, "Spec name contains invalid characters: {name}")
       }
+      Self::Duplicate(name) => {
+        write!(f, "Spec name is already registered: {name}")
+      }
     }
   }
 }
 
 impl std::error::Error for SpecNameError {}
 
+/// Registered spec names, unique regardless of case
+///
+/// Names are keyed by their lowercase form, so `MySpec` and `myspec`
+/// collide, but each keeps the casing it was registered with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpecNameRegistry {
+  names: BTreeMap<String, SpecName>,
+}
+
+impl SpecNameRegistry {
+  /// Create an empty registry
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Register a spec name
+  ///
+  /// # Errors
+  /// - Returns `SpecNameError::Duplicate` if a name differing only in case is already registered
+  pub fn register(&mut self, name: SpecName) -> Result<(), SpecNameError> {
+    let key = canonical(&name);
+    if let Some(existing) = self.names.get(&key) {
+      return Err(SpecNameError::Duplicate(existing.to_string()));
+    }
+    self.names.insert(key, name);
+    Ok(())
+  }
+
+  /// Whether a name matching `name` in any case is registered
+  #[must_use]
+  pub fn contains(&self, name: &SpecName) -> bool {
+    self.names.contains_key(&canonical(name))
+  }
+
+  /// Every registered name in its original casing, ordered case-insensitively
+  pub fn all(&self) -> impl Iterator<Item = &SpecName> {
+    self.names.values()
+  }
+}
+
+/// The case-insensitive registry key for a spec name
+fn canonical(name: &SpecName) -> String {
+  name.as_str().to_lowercase()
+}
+
 /// URL with validation
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Url(String);
@@ -310,6 +417,32 @@ impl Url {
 
     Self::new(new_url)
   }
+
+  /// Append a query parameter, keeping any existing query and fragment
+  ///
+  /// Key and value are percent-encoded, so they may contain `&`, `=` or
+  /// spaces. The parameter is inserted before any `#fragment`.
+  ///
+  /// # Errors
+  /// - Returns a `UrlError` if the resulting URL is invalid
+  pub fn with_query_param(self, key: &str, value: &str) -> Result<Self, UrlError> {
+    let mut url = self.parse_url()?;
+    url.query_pairs_mut().append_pair(key, value);
+    Self::new(url.into())
+  }
+
+  /// Get the decoded query parameters, in order
+  #[must_use]
+  pub fn query_params(&self) -> Vec<(String, String)> {
+    self.parse_url().map_or_else(
+      |_| Vec::new(),
+      |u| {
+        u.query_pairs()
+          .map(|(key, value)| (key.into_owned(), value.into_owned()))
+          .collect()
+      },
+    )
+  }
 }
 
 impl fmt::Display for Url {
@@ -342,9 +475,25 @@ impl fmt::Display for UrlError {
 
 impl std::error::Error for UrlError {}
 
+/// Whether `s` is a hyphenated UUID with a known version and RFC 4122 variant
+///
+/// The nil and max UUIDs are accepted too. Session and interview ids are
+/// validated with this.
+pub(crate) fn is_valid_uuid(s: &str) -> bool {
+  s.len() == 36
+    && uuid::Uuid::parse_str(s).is_ok_and(|id| match id.get_version() {
+      Some(uuid::Version::Nil | uuid::Version::Max) => true,
+      Some(_) => id.get_variant() == uuid::Variant::RFC4122,
+      None => false,
+    })
+}
+
 /// Question types for surveys and forms
 pub mod question;
 
+/// Timestamps shared by sessions and interviews
+pub mod time;
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -369,6 +518,18 @@ mod tests {
     assert_eq!(format!("{}", HttpMethod::Put), "put");
   }
 
+  #[test]
+  fn test_http_method_serializes_uppercase() {
+    assert_eq!(
+      serde_json::to_string(&HttpMethod::Delete).ok().as_deref(),
+      Some("\"DELETE\"")
+    );
+    assert_eq!(
+      serde_json::from_str::<HttpMethod>("\"PATCH\"").ok(),
+      Some(HttpMethod::Patch)
+    );
+  }
+
   #[test]
   fn test_http_method_is_safe() {
     assert!(HttpMethod::Get.is_safe());
@@ -388,21 +549,85 @@ mod tests {
   }
 
   #[test]
-  fn test_spec_name_new_valid() {
-    assert_eq!(
-      SpecName::new("test_spec".to_string()),
-      Ok(SpecName("test_spec".to_string()))
-    );
-    assert_eq!(
-      SpecName::new("spec_123".to_string()),
-      Ok(SpecName("spec_123".to_string()))
-    );
+  fn test_http_method_has_request_body() {
+    assert!(HttpMethod::Post.has_request_body());
+    assert!(HttpMethod::Put.has_request_body());
+    assert!(HttpMethod::Patch.has_request_body());
+    assert!(!HttpMethod::Get.has_request_body());
+    assert!(!HttpMethod::Head.has_request_body());
+    assert!(!HttpMethod::Delete.has_request_body());
+    assert!(!HttpMethod::Options.has_request_body());
+  }
+
+  #[test]
+  fn test_http_method_is_idempotent() {
+    assert!(HttpMethod::Get.is_idempotent());
+    assert!(HttpMethod::Head.is_idempotent());
+    assert!(HttpMethod::Put.is_idempotent());
+    assert!(HttpMethod::Delete.is_idempotent());
+    assert!(HttpMethod::Options.is_idempotent());
+    assert!(!HttpMethod::Post.is_idempotent());
+    assert!(!HttpMethod::Patch.is_idempotent());
+  }
+
+  #[test]
+  fn test_http_method_parse_trait() {
+    assert_eq!("gEt".parse::<HttpMethod>(), Ok(HttpMethod::Get));
     assert_eq!(
-      SpecName::new("spec-456".to_string()),
-      Ok(SpecName("spec-456".to_string()))
+      "BREW".parse::<HttpMethod>(),
+      Err(HttpMethodError::InvalidMethod("BREW".to_string()))
     );
   }
 
+  #[test]
+  fn test_is_valid_uuid_valid() {
+    assert!(is_valid_uuid("550e8400-e29b-41d4-a716-446655440000"));
+    assert!(is_valid_uuid("6ba7b810-9dad-11d1-80b4-00c04fd430c8"));
+    assert!(is_valid_uuid("00000000-0000-0000-0000-000000000000"));
+    assert!(is_valid_uuid("ffffffff-ffff-ffff-ffff-ffffffffffff"));
+  }
+
+  #[test]
+  fn test_is_valid_uuid_invalid() {
+    assert!(!is_valid_uuid("not-a-uuid"));
+    assert!(!is_valid_uuid(""));
+    assert!(!is_valid_uuid("550e8400-e29b-41d4-a716"));
+    assert!(!is_valid_uuid("550e8400-e29b-41d4-a716-446655440000-extra"));
+    assert!(!is_valid_uuid("550e8400-e29b-41d4-a716-44665544000g"));
+    assert!(!is_valid_uuid("550e8400e29b41d4a716446655440000"));
+    assert!(!is_valid_uuid("{550e8400-e29b-41d4-a716-446655440000}"));
+  }
+
+  #[test]
+  fn test_is_valid_uuid_rejects_bad_version_or_variant() {
+    // Version nibble 0 on a non-nil UUID, and 9, are not defined
+    assert!(!is_valid_uuid("550e8400-e29b-01d4-a716-446655440000"));
+    assert!(!is_valid_uuid("550e8400-e29b-91d4-a716-446655440000"));
+    // Variant nibble 0-7 is the reserved NCS variant
+    assert!(!is_valid_uuid("550e8400-e29b-41d4-2716-446655440000"));
+  }
+
+  /// A spec name built through the validating constructor
+  #[allow(clippy::panic)]
+  fn spec_name(name: &str) -> SpecName {
+    match SpecName::new(name.to_string()) {
+      Ok(name) => name,
+      Err(e) => panic!("Expected valid spec name {name:?}, got {e}"),
+    }
+  }
+
+  #[test]
+  fn test_spec_name_new_valid() {
+    for name in ["test_spec", "spec_123", "spec-456", "café_menu"] {
+      assert_eq!(
+        SpecName::new(name.to_string())
+          .as_ref()
+          .map(SpecName::as_str),
+        Ok(name)
+      );
+    }
+  }
+
   #[test]
   fn test_spec_name_empty() {
     assert!(SpecName::new(String::new()).is_err());
@@ -422,10 +647,46 @@ mod tests {
 
   #[test]
   fn test_spec_name_from_str() {
+    assert_eq!(SpecName::from_str("test_spec"), Ok(spec_name("test_spec")));
+  }
+
+  #[test]
+  fn test_spec_name_registry_rejects_case_insensitive_duplicates() {
+    let mut registry = SpecNameRegistry::new();
+    assert_eq!(registry.register(spec_name("MySpec")), Ok(()));
     assert_eq!(
-      SpecName::from_str("test_spec"),
-      Ok(SpecName("test_spec".to_string()))
+      registry.register(spec_name("myspec")),
+      Err(SpecNameError::Duplicate("MySpec".to_string()))
     );
+    assert_eq!(registry.register(spec_name("other")), Ok(()));
+
+    assert!(registry.contains(&spec_name("MYSPEC")));
+    let names: Vec<&str> = registry.all().map(SpecName::as_str).collect();
+    assert_eq!(names, vec!["MySpec", "other"]);
+  }
+
+  #[test]
+  fn test_spec_name_slug() {
+    assert_eq!(spec_name("My_Spec-v2").slug(), "my-spec-v2");
+    assert_eq!(spec_name("user_auth-API").slug(), "user-auth-api");
+    assert_eq!(spec_name("--a__b--").slug(), "a-b");
+  }
+
+  #[test]
+  fn test_spec_name_slug_unicode() {
+    assert_eq!(spec_name("café_menu").slug(), "café-menu");
+    assert_ne!(spec_name("café_menu").slug(), spec_name("caf_menu").slug());
+    assert_eq!(spec_name("Ünïcode").slug(), "ünïcode");
+    assert_eq!(spec_name("日本語").slug(), "日本語");
+    assert_ne!(spec_name("日本語").slug(), spec_name("中文").slug());
+  }
+
+  #[test]
+  fn test_spec_name_slug_without_letters_is_hashed() {
+    let slug = spec_name("___").slug();
+    assert_eq!(slug, format!("spec-{:016x}", fnv1a(b"___")));
+    assert_eq!(slug, spec_name("___").slug());
+    assert_ne!(slug, spec_name("-_-").slug());
   }
 
   #[test]
@@ -515,6 +776,50 @@ mod tests {
     assert!(new_url.as_str().contains("?key=value"));
   }
 
+  #[allow(clippy::unwrap_used)]
+  #[allow(clippy::panic)]
+  #[test]
+  fn test_url_with_query_param() {
+    let url = Url::new("http://example.com/beads".to_string()).unwrap();
+    let url = url
+      .with_query_param("status", "open")
+      .unwrap()
+      .with_query_param("q", "a&b=c d")
+      .unwrap();
+    assert_eq!(
+      url.as_str(),
+      "http://example.com/beads?status=open&q=a%26b%3Dc+d"
+    );
+    assert_eq!(
+      url.query_params(),
+      vec![
+        ("status".to_string(), "open".to_string()),
+        ("q".to_string(), "a&b=c d".to_string()),
+      ]
+    );
+  }
+
+  #[allow(clippy::unwrap_used)]
+  #[allow(clippy::panic)]
+  #[test]
+  fn test_url_with_query_param_before_fragment() {
+    let url = Url::new("http://example.com/path?page=2#results".to_string()).unwrap();
+    let url = url.with_query_param("limit", "10").unwrap();
+    assert_eq!(
+      url.as_str(),
+      "http://example.com/path?page=2&limit=10#results"
+    );
+    assert_eq!(url.fragment(), Some("results".to_string()));
+  }
+
+  #[allow(clippy::unwrap_used)]
+  #[allow(clippy::panic)]
+  #[test]
+  fn test_url_query_params_empty() {
+    let url = Url::new("http://example.com/path".to_string()).unwrap();
+    assert!(url.query_params().is_empty());
+  }
+
   #[allow(clippy::unwrap_used)]
   #[allow(clippy::panic)]
   #[test]
@@ -574,7 +879,7 @@ mod tests {
   #[test]
   fn test_display_impls() {
     assert_eq!(format!("{}", HttpMethod::Get), "get");
-    assert_eq!(format!("{}", SpecName("test".to_string())), "test");
+    assert_eq!(format!("{}", spec_name("test")), "test");
     assert_eq!(
       format!("{}", Url("http://example.com".to_string())),
       "http://example.com"
@@ -0,0 +1,120 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+
+//! Timestamps shared by sessions and interviews
+//!
+//! `session::Timestamp` and `interview::Timestamp` both re-export the type
+//! defined here, so values move freely between the two.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use thiserror::Error;
+
+/// The system clock reads earlier than the Unix epoch
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+#[error("system time is invalid, cannot create timestamp")]
+pub struct InvalidSystemTime;
+
+/// Timestamp for session and interview events
+///
+/// Represented as Unix timestamp (seconds since epoch), and serialized as
+/// that number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Timestamp(i64);
+
+impl Timestamp {
+  /// Create a new Timestamp from seconds since epoch
+  #[must_use]
+  pub const fn from_secs(secs: i64) -> Self {
+    Self(secs)
+  }
+
+  /// Get the current time as a Timestamp
+  ///
+  /// # Errors
+  ///
+  /// Returns `InvalidSystemTime` if the system time is invalid
+  /// (e.g., due to clock skew or being set before `UNIX_EPOCH`)
+  pub fn now() -> Result<Self, InvalidSystemTime> {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .map(|d| Self(d.as_secs().cast_signed()))
+      .map_err(|_| InvalidSystemTime)
+  }
+
+  /// Get the underlying seconds value
+  #[must_use]
+  pub const fn as_secs(&self) -> i64 {
+    self.0
+  }
+
+  /// Format as an RFC 3339 UTC date-time, e.g. `2009-02-13T23:31:30Z`
+  ///
+  /// Timestamps beyond the range `chrono` can represent are clamped to it.
+  #[must_use]
+  pub fn to_rfc3339(&self) -> String {
+    let datetime = DateTime::from_timestamp(self.0, 0).unwrap_or(if self.0 < 0 {
+      DateTime::<Utc>::MIN_UTC
+    } else {
+      DateTime::<Utc>::MAX_UTC
+    });
+    datetime.to_rfc3339_opts(SecondsFormat::Secs, true)
+  }
+
+  /// Parse an RFC 3339 date-time in any offset
+  ///
+  /// Fractional seconds are truncated.
+  ///
+  /// # Errors
+  ///
+  /// Returns a `chrono::ParseError` if the input is not valid RFC 3339
+  pub fn from_rfc3339(input: &str) -> Result<Self, chrono::ParseError> {
+    DateTime::parse_from_rfc3339(input).map(|datetime| Self(datetime.timestamp()))
+  }
+}
+
+impl Display for Timestamp {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_rfc3339_round_trip() {
+    let ts = Timestamp::from_secs(1_234_567_890);
+    assert_eq!(ts.to_rfc3339(), "2009-02-13T23:31:30Z");
+    assert_eq!(Timestamp::from_rfc3339(&ts.to_rfc3339()), Ok(ts));
+  }
+
+  #[test]
+  fn test_from_rfc3339_with_offset_and_fraction() {
+    assert_eq!(
+      Timestamp::from_rfc3339("2009-02-14T00:31:30.75+01:00"),
+      Ok(Timestamp::from_secs(1_234_567_890))
+    );
+    assert!(Timestamp::from_rfc3339("2009-02-13 23:31:30").is_err());
+  }
+
+  #[test]
+  fn test_to_rfc3339_clamps_out_of_range() {
+    assert!(Timestamp::from_secs(i64::MAX)
+      .to_rfc3339()
+      .starts_with("+262"));
+  }
+
+  #[test]
+  fn test_session_and_interview_timestamps_are_interchangeable() {
+    let ts: crate::session::Timestamp = crate::interview::Timestamp::from_secs(42);
+    assert_eq!(ts.as_secs(), 42);
+  }
+}
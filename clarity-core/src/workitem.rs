@@ -0,0 +1,190 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Unified work item view for Clarity
+//!
+//! Sessions, interviews and beads are separate domain types, but the
+//! dashboard shows them as a single feed. `WorkItem` is the common,
+//! read-only projection of all three, tagged with a `kind` discriminator.
+
+use crate::db::models::{Bead, BeadStatus};
+use crate::interview::Interview;
+use crate::session::Session;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+/// The kind of entity a work item was projected from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkItemKind {
+  /// A session
+  Session,
+  /// An interview
+  Interview,
+  /// A bead
+  Bead,
+}
+
+impl Display for WorkItemKind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Session => write!(f, "session"),
+      Self::Interview => write!(f, "interview"),
+      Self::Bead => write!(f, "bead"),
+    }
+  }
+}
+
+/// A session, interview or bead viewed as an entry in the work feed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkItem {
+  /// Which kind of entity this is
+  pub kind: WorkItemKind,
+
+  /// Id of the underlying entity
+  pub id: String,
+
+  /// Display title, if the entity has one
+  pub title: Option<String>,
+
+  /// Lifecycle state of the entity, as its lowercase name
+  pub state: String,
+
+  /// Whether the entity still needs work
+  pub active: bool,
+
+  /// When the entity was last updated, in seconds since the Unix epoch
+  pub updated_at: i64,
+}
+
+impl From<&Session> for WorkItem {
+  fn from(session: &Session) -> Self {
+    Self {
+      kind: WorkItemKind::Session,
+      id: session.id.to_string(),
+      title: session.title.clone(),
+      state: session.state.to_string(),
+      active: session.is_active(),
+      updated_at: session.updated_at.as_secs(),
+    }
+  }
+}
+
+impl From<&Interview> for WorkItem {
+  fn from(interview: &Interview) -> Self {
+    Self {
+      kind: WorkItemKind::Interview,
+      id: interview.id.to_string(),
+      title: interview
+        .title
+        .clone()
+        .or_else(|| Some(interview.spec_name.clone())),
+      state: interview.state.to_string(),
+      active: interview.is_active(),
+      updated_at: interview.updated_at.as_secs(),
+    }
+  }
+}
+
+impl From<&Bead> for WorkItem {
+  fn from(bead: &Bead) -> Self {
+    Self {
+      kind: WorkItemKind::Bead,
+      id: bead.id.to_string(),
+      title: Some(bead.title.clone()),
+      state: bead.status.to_string(),
+      active: bead.status != BeadStatus::Closed,
+      updated_at: bead.updated_at.timestamp(),
+    }
+  }
+}
+
+/// Order work items most recently updated first
+///
+/// Ties are broken by kind and then id so the order is stable across calls.
+/// With `active_only`, items that no longer need work are dropped.
+#[must_use]
+pub fn merge_work_items(items: Vec<WorkItem>, active_only: bool) -> Vec<WorkItem> {
+  let mut items: Vec<WorkItem> = items
+    .into_iter()
+    .filter(|item| !active_only || item.active)
+    .collect();
+
+  items.sort_by(|a, b| {
+    b.updated_at
+      .cmp(&a.updated_at)
+      .then_with(|| a.kind.to_string().cmp(&b.kind.to_string()))
+      .then_with(|| a.id.cmp(&b.id))
+  });
+  items
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn item(kind: WorkItemKind, id: &str, active: bool, updated_at: i64) -> WorkItem {
+    WorkItem {
+      kind,
+      id: id.to_string(),
+      title: None,
+      state: "open".to_string(),
+      active,
+      updated_at,
+    }
+  }
+
+  #[test]
+  fn test_merge_sorts_by_recency() {
+    let merged = merge_work_items(
+      vec![
+        item(WorkItemKind::Bead, "b", true, 10),
+        item(WorkItemKind::Session, "s", true, 30),
+        item(WorkItemKind::Interview, "i", true, 20),
+      ],
+      false,
+    );
+
+    let ids: Vec<&str> = merged.iter().map(|i| i.id.as_str()).collect();
+    assert_eq!(ids, vec!["s", "i", "b"]);
+  }
+
+  #[test]
+  fn test_merge_active_only() {
+    let merged = merge_work_items(
+      vec![
+        item(WorkItemKind::Bead, "open", true, 10),
+        item(WorkItemKind::Bead, "closed", false, 20),
+      ],
+      true,
+    );
+
+    assert_eq!(merged.len(), 1);
+    assert_eq!(merged[0].id, "open");
+  }
+
+  #[test]
+  fn test_merge_breaks_ties_deterministically() {
+    let merged = merge_work_items(
+      vec![
+        item(WorkItemKind::Session, "b", true, 10),
+        item(WorkItemKind::Bead, "z", true, 10),
+        item(WorkItemKind::Session, "a", true, 10),
+      ],
+      false,
+    );
+
+    let ids: Vec<&str> = merged.iter().map(|i| i.id.as_str()).collect();
+    assert_eq!(ids, vec!["z", "a", "b"]);
+  }
+
+  #[test]
+  fn test_work_item_kind_serializes_lowercase() {
+    let json = serde_json::to_string(&WorkItemKind::Interview);
+    assert_eq!(json.ok().as_deref(), Some("\"interview\""));
+  }
+}
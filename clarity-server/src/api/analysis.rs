@@ -0,0 +1,31 @@
+//! Analysis result endpoints
+
+use super::ApiError;
+use crate::state::AppState;
+use axum::{
+  extract::{Path, State},
+  Json,
+};
+use clarity_core::analysis::{AnalysisError, AnalysisId, AnalysisResult};
+
+/// Fetch an analysis result by id
+///
+/// # Errors
+///
+/// Returns a 400 `invalid_id` error if the id is not a UUID
+/// Returns a 404 `not_found` error if no analysis result has this id
+pub async fn get_analysis(
+  State(state): State<AppState>,
+  Path(id): Path<String>,
+) -> Result<Json<AnalysisResult>, ApiError> {
+  let id = AnalysisId::new(id)?;
+  let result = state
+    .analyses
+    .read()
+    .await
+    .get(&id)
+    .cloned()
+    .ok_or_else(|| AnalysisError::NotFound(id.to_string()))?;
+
+  Ok(Json(result))
+}
@@ -0,0 +1,409 @@
+//! Bead endpoints
+
+use super::error::ErrorEnvelope;
+use super::ApiError;
+use crate::state::{AppState, BeadEvent};
+use axum::{
+  extract::{
+    rejection::{JsonRejection, QueryRejection},
+    ws::{Message, WebSocket, WebSocketUpgrade},
+    Path, Query, State,
+  },
+  http::StatusCode,
+  response::Response,
+  Json,
+};
+use clarity_core::db::{
+  blocked_beads as fetch_blocked_beads, get_bead as fetch_bead, list_beads_sorted, Bead,
+  BeadFilter, BeadId, BeadPriority, BeadStatus, DbError, NewBead, SortField, SortOrder, SortSpec,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+use utoipa::{IntoParams, ToSchema};
+
+/// Page size used when the request does not give one
+pub const DEFAULT_LIMIT: u32 = 50;
+
+/// Most beads a single `POST /beads/batch` request may create
+pub const MAX_BATCH_SIZE: usize = 500;
+
+/// Query parameters for `GET /beads`
+///
+/// Values are kept as text so an invalid one can be reported against its
+/// parameter name; [`BeadQuery::parse`] turns them into typed values.
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct BeadQuery {
+  /// Only beads with this status, e.g. `in_progress`
+  #[param(example = "in_progress")]
+  pub status: Option<String>,
+  /// Only beads with this priority (1-3)
+  #[param(value_type = Option<i16>, minimum = 1, maximum = 3)]
+  pub priority: Option<String>,
+  /// Maximum number of beads to return, capped by the repository
+  #[param(value_type = Option<u32>, minimum = 1, maximum = 100, default = 50)]
+  pub limit: Option<String>,
+  /// `next_cursor` from the previous page
+  #[param(format = Uuid)]
+  pub cursor: Option<String>,
+  /// Field to sort by: `priority`, `created_at` or `status`
+  #[param(example = "priority", default = "created_at")]
+  pub sort: Option<String>,
+  /// Sort direction: `asc` or `desc`
+  #[param(example = "asc", default = "desc")]
+  pub order: Option<String>,
+}
+
+/// A `BeadQuery` with every parameter validated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParsedBeadQuery {
+  /// Status and priority conditions
+  pub filter: BeadFilter,
+  /// Order of the beads
+  pub sort: SortSpec,
+  /// Page size
+  pub limit: u32,
+  /// Id of the last bead of the previous page
+  pub cursor: Option<BeadId>,
+}
+
+/// A query parameter that could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidParam {
+  /// Name of the parameter
+  pub field: &'static str,
+  /// Why it was rejected
+  pub message: String,
+}
+
+impl BeadQuery {
+  /// Validate every parameter
+  ///
+  /// # Errors
+  ///
+  /// Returns `InvalidParam` naming the first parameter that is not a valid
+  /// status, priority, limit, bead id, sort field or sort order
+  pub fn parse(&self) -> Result<ParsedBeadQuery, InvalidParam> {
+    let status = self
+      .status
+      .as_deref()
+      .map(str::parse::<BeadStatus>)
+      .transpose()
+      .map_err(|e| invalid("status", &e))?;
+    let priority = self
+      .priority
+      .as_deref()
+      .map(|raw| {
+        raw
+          .parse::<i16>()
+          .map_err(|_| DbError::validation(format!("Invalid bead priority: {raw}")))
+          .and_then(BeadPriority::new)
+      })
+      .transpose()
+      .map_err(|e| invalid("priority", &e))?;
+    let limit = self
+      .limit
+      .as_deref()
+      .map(|raw| {
+        raw.parse::<u32>().map_err(|_| InvalidParam {
+          field: "limit",
+          message: format!("Invalid limit: {raw}"),
+        })
+      })
+      .transpose()?
+      .unwrap_or(DEFAULT_LIMIT);
+    let cursor = self
+      .cursor
+      .as_deref()
+      .map(BeadId::from_str)
+      .transpose()
+      .map_err(|e| invalid("cursor", &e))?;
+    let default_sort = SortSpec::default();
+    let field = self
+      .sort
+      .as_deref()
+      .map(str::parse::<SortField>)
+      .transpose()
+      .map_err(|e| invalid("sort", &e))?
+      .unwrap_or(default_sort.field);
+    let order = self
+      .order
+      .as_deref()
+      .map(str::parse::<SortOrder>)
+      .transpose()
+      .map_err(|e| invalid("order", &e))?
+      .unwrap_or(default_sort.order);
+
+    Ok(ParsedBeadQuery {
+      filter: BeadFilter { status, priority },
+      sort: SortSpec::new(field, order),
+      limit,
+      cursor,
+    })
+  }
+}
+
+fn invalid(field: &'static str, error: &DbError) -> InvalidParam {
+  InvalidParam {
+    field,
+    message: error.to_string(),
+  }
+}
+
+/// Response body for `GET /beads`
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct BeadPage {
+  /// Beads on this page, in the requested order
+  pub items: Vec<Bead>,
+  /// Pass as `cursor` to get the next page; `null` on the last page
+  pub next_cursor: Option<String>,
+}
+
+/// List beads, optionally filtered by status and priority, one page at a time
+///
+/// Beads are sorted by `sort` and `order`, newest first by default.
+///
+/// # Errors
+///
+/// Returns a 400 `invalid_parameter` error if a query parameter is invalid or
+/// the cursor does not name an existing bead
+/// Returns a 503 `db_unavailable` error if no database is configured
+/// Returns a 500 `internal_error` error if the database query fails
+#[utoipa::path(
+  get,
+  path = "/beads",
+  tag = "beads",
+  params(BeadQuery),
+  responses(
+    (status = 200, description = "One page of beads", body = BeadPage),
+    (status = 400, description = "A query parameter is invalid", body = ErrorEnvelope),
+    (status = 429, description = "Rate limit exceeded", body = ErrorEnvelope,
+      headers(("Retry-After" = u64, description = "Seconds to wait before retrying"))),
+    (status = 500, description = "The database query failed", body = ErrorEnvelope),
+    (status = 503, description = "No database is configured", body = ErrorEnvelope),
+  )
+)]
+pub async fn list_beads(
+  State(state): State<AppState>,
+  query: Result<Query<BeadQuery>, QueryRejection>,
+) -> Result<Json<BeadPage>, ApiError> {
+  let Query(query) = query.map_err(|e| ApiError::invalid_parameter("query", e.body_text()))?;
+  let parsed = query
+    .parse()
+    .map_err(|e| ApiError::invalid_parameter(e.field, e.message))?;
+  let pool = database(&state)?;
+
+  let page = list_beads_sorted(
+    pool,
+    parsed.filter,
+    parsed.sort,
+    parsed.cursor,
+    parsed.limit,
+  )
+  .await
+  .map_err(|e| match e {
+    DbError::NotFound { .. } => ApiError::invalid_parameter("cursor", e),
+    _ => ApiError::from(e),
+  })?;
+
+  Ok(Json(BeadPage {
+    items: page.items,
+    next_cursor: page.next_cursor.map(|id| id.to_string()),
+  }))
+}
+
+/// List beads waiting on at least one dependency that is not closed
+///
+/// Beads are returned oldest first.
+///
+/// # Errors
+///
+/// Returns a 503 `db_unavailable` error if no database is configured
+/// Returns a 500 `internal_error` error if the database query fails
+#[utoipa::path(
+  get,
+  path = "/beads/blocked",
+  tag = "beads",
+  responses(
+    (status = 200, description = "Every blocked bead", body = Vec<Bead>),
+    (status = 429, description = "Rate limit exceeded", body = ErrorEnvelope,
+      headers(("Retry-After" = u64, description = "Seconds to wait before retrying"))),
+    (status = 500, description = "The database query failed", body = ErrorEnvelope),
+    (status = 503, description = "No database is configured", body = ErrorEnvelope),
+  )
+)]
+pub async fn blocked_beads(State(state): State<AppState>) -> Result<Json<Vec<Bead>>, ApiError> {
+  let pool = database(&state)?;
+
+  Ok(Json(fetch_blocked_beads(pool).await?))
+}
+
+/// Fetch a bead by id
+///
+/// # Errors
+///
+/// Returns a 400 `validation_error` error if the id is not a UUID
+/// Returns a 404 `not_found` error if no bead has this id
+/// Returns a 503 `db_unavailable` error if no database is configured
+#[utoipa::path(
+  get,
+  path = "/beads/{id}",
+  tag = "beads",
+  params(("id" = String, Path, format = Uuid, description = "Bead id")),
+  responses(
+    (status = 200, description = "The bead", body = Bead),
+    (status = 400, description = "The id is not a UUID", body = ErrorEnvelope),
+    (status = 404, description = "No bead has this id", body = ErrorEnvelope),
+    (status = 429, description = "Rate limit exceeded", body = ErrorEnvelope,
+      headers(("Retry-After" = u64, description = "Seconds to wait before retrying"))),
+    (status = 503, description = "No database is configured", body = ErrorEnvelope),
+  )
+)]
+pub async fn get_bead(
+  State(state): State<AppState>,
+  Path(id): Path<String>,
+) -> Result<Json<Bead>, ApiError> {
+  let id = BeadId::from_str(&id)?;
+  let pool = database(&state)?;
+
+  Ok(Json(fetch_bead(pool, &id).await?))
+}
+
+/// Create several beads at once
+///
+/// The beads are inserted in one transaction: either all are created and
+/// returned in request order, or none is.
+///
+/// # Errors
+///
+/// Returns a 400 `invalid_parameter` error if the body is not a list of beads
+/// Returns a 400 `validation_error` error if any bead is invalid
+/// Returns a 413 `batch_too_large` error for more than [`MAX_BATCH_SIZE`] beads
+/// Returns a 503 `db_unavailable` error if no database is configured
+#[utoipa::path(
+  post,
+  path = "/beads/batch",
+  tag = "beads",
+  request_body = Vec<NewBead>,
+  security(("bearer" = [])),
+  responses(
+    (status = 201, description = "Every bead was created", body = Vec<Bead>),
+    (status = 400, description = "The body or one of the beads is invalid", body = ErrorEnvelope),
+    (status = 401, description = "The bearer token is missing or invalid", body = ErrorEnvelope),
+    (status = 413, description = "The batch holds too many beads", body = ErrorEnvelope),
+    (status = 429, description = "Rate limit exceeded", body = ErrorEnvelope,
+      headers(("Retry-After" = u64, description = "Seconds to wait before retrying"))),
+    (status = 503, description = "No database is configured", body = ErrorEnvelope),
+  )
+)]
+pub async fn create_beads_batch(
+  State(state): State<AppState>,
+  body: Result<Json<Vec<NewBead>>, JsonRejection>,
+) -> Result<(StatusCode, Json<Vec<Bead>>), ApiError> {
+  let Json(beads) = body.map_err(|e| ApiError::invalid_parameter("body", e.body_text()))?;
+  if beads.len() > MAX_BATCH_SIZE {
+    return Err(ApiError::new(
+      StatusCode::PAYLOAD_TOO_LARGE,
+      "batch_too_large",
+      format!(
+        "A batch may create at most {MAX_BATCH_SIZE} beads, got {}",
+        beads.len()
+      ),
+    ));
+  }
+  let pool = database(&state)?;
+
+  let created = state.create_beads(pool, &beads).await?;
+  Ok((StatusCode::CREATED, Json(created)))
+}
+
+/// A message a `/beads/ws` client sends to scope its stream
+///
+/// `{"status": "open"}` limits the stream to beads with that status;
+/// `{}` lifts the limit again.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BeadStreamFilter {
+  /// Only beads with this status, e.g. `in_progress`
+  pub status: Option<String>,
+}
+
+impl BeadStreamFilter {
+  /// Parse a filter message into the status it selects, if any
+  ///
+  /// # Errors
+  ///
+  /// Returns a 400 `invalid_parameter` error if the message is not a filter
+  /// object or names an unknown status
+  pub fn parse(text: &str) -> Result<Option<BeadStatus>, ApiError> {
+    let filter: Self =
+      serde_json::from_str(text).map_err(|e| ApiError::invalid_parameter("filter", e))?;
+    filter
+      .status
+      .as_deref()
+      .map(str::parse::<BeadStatus>)
+      .transpose()
+      .map_err(|e| ApiError::invalid_parameter("status", e))
+  }
+}
+
+/// Stream bead changes to a `/beads/ws` socket
+///
+/// Each created, updated or deleted bead is pushed as a JSON text message,
+/// e.g. `{"event": "created", "bead": {...}}`. A client that falls too far
+/// behind skips the changes it missed rather than holding up writers.
+pub async fn bead_updates(State(state): State<AppState>, ws: WebSocketUpgrade) -> Response {
+  let updates = state.bead_events.subscribe();
+  ws.on_upgrade(move |socket| stream_bead_updates(socket, updates))
+}
+
+async fn stream_bead_updates(mut socket: WebSocket, mut updates: Receiver<BeadEvent>) {
+  let mut status = None;
+  loop {
+    let outgoing = tokio::select! {
+      incoming = socket.recv() => match incoming {
+        Some(Ok(Message::Text(text))) => match BeadStreamFilter::parse(&text) {
+          Ok(selected) => {
+            status = selected;
+            continue;
+          }
+          Err(e) => serde_json::to_string(&e.envelope()),
+        },
+        Some(Ok(Message::Close(_)) | Err(_)) | None => return,
+        Some(Ok(_)) => continue,
+      },
+      update = updates.recv() => match update {
+        Ok(event) if status.is_none_or(|s| event.bead().status == s) => {
+          serde_json::to_string(&event)
+        }
+        Ok(_) => continue,
+        Err(RecvError::Lagged(skipped)) => {
+          tracing::warn!("bead stream subscriber lagged; dropped {skipped} update(s)");
+          continue;
+        }
+        Err(RecvError::Closed) => return,
+      },
+    };
+
+    let sent = match outgoing {
+      Ok(json) => socket.send(Message::Text(json.into())).await,
+      Err(e) => {
+        tracing::error!("failed to encode bead update: {e}");
+        continue;
+      }
+    };
+    if sent.is_err() {
+      return;
+    }
+  }
+}
+
+fn database(state: &AppState) -> Result<&SqlitePool, ApiError> {
+  state.db.as_ref().ok_or_else(|| {
+    ApiError::new(
+      StatusCode::SERVICE_UNAVAILABLE,
+      "db_unavailable",
+      "Database is not configured",
+    )
+  })
+}
@@ -0,0 +1,264 @@
+//! Uniform JSON error envelope for API handlers
+//!
+//! Every [`ApiError`] renders as `{ "error": { "code": "...", "message": "..." } }`
+//! with a matching HTTP status, so clients can parse failures the same way
+//! for every endpoint. Errors about unanswered interview questions also carry
+//! their indices as `"missing": [..]`.
+
+use axum::{
+  http::StatusCode,
+  response::{IntoResponse, Response},
+  Json,
+};
+use clarity_core::analysis::AnalysisError;
+use clarity_core::db::DbError;
+use clarity_core::interview::InterviewError;
+use clarity_core::session::SessionError;
+use clarity_core::validation::ValidationError;
+use serde::Serialize;
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+/// An error returned by an API handler
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+  status: StatusCode,
+  code: &'static str,
+  message: String,
+  missing: Vec<usize>,
+}
+
+impl ApiError {
+  /// Create an error with an explicit status and machine-readable code
+  pub fn new(status: StatusCode, code: &'static str, message: impl Into<String>) -> Self {
+    Self {
+      status,
+      code,
+      message: message.into(),
+      missing: Vec::new(),
+    }
+  }
+
+  /// Attach the indices of the unanswered questions the error is about
+  #[must_use]
+  pub fn with_missing(mut self, missing: Vec<usize>) -> Self {
+    self.missing = missing;
+    self
+  }
+
+  /// 400 for a query or path parameter that could not be parsed
+  pub fn invalid_parameter(field: &str, message: impl Display) -> Self {
+    Self::new(
+      StatusCode::BAD_REQUEST,
+      "invalid_parameter",
+      format!("{field}: {message}"),
+    )
+  }
+
+  /// 404 for an entity that does not exist
+  pub fn not_found(entity: &str, id: impl Display) -> Self {
+    Self::new(
+      StatusCode::NOT_FOUND,
+      "not_found",
+      format!("{entity} '{id}' not found"),
+    )
+  }
+
+  /// 500 for a failure the client cannot fix; details are logged, not returned
+  pub fn internal(error: impl Display) -> Self {
+    tracing::error!("internal error: {error}");
+    Self::new(
+      StatusCode::INTERNAL_SERVER_ERROR,
+      "internal_error",
+      "Internal server error",
+    )
+  }
+
+  /// HTTP status of the response
+  #[must_use]
+  pub const fn status(&self) -> StatusCode {
+    self.status
+  }
+
+  /// Machine-readable error code
+  #[must_use]
+  pub const fn code(&self) -> &'static str {
+    self.code
+  }
+
+  /// Human-readable message
+  #[must_use]
+  pub fn message(&self) -> &str {
+    &self.message
+  }
+
+  /// Indices of the unanswered questions, empty for unrelated errors
+  #[must_use]
+  pub fn missing(&self) -> &[usize] {
+    &self.missing
+  }
+
+  /// The JSON body sent for this error
+  pub(super) fn envelope(&self) -> ErrorEnvelope<'_> {
+    ErrorEnvelope {
+      error: ErrorBody {
+        code: self.code,
+        message: &self.message,
+        missing: &self.missing,
+      },
+    }
+  }
+}
+
+/// JSON body of every [`ApiError`]
+#[derive(Serialize, ToSchema)]
+pub(super) struct ErrorEnvelope<'a> {
+  error: ErrorBody<'a>,
+}
+
+/// Details of an [`ApiError`]
+#[derive(Serialize, ToSchema)]
+pub(super) struct ErrorBody<'a> {
+  /// Machine-readable error code, e.g. `not_found`
+  code: &'a str,
+  /// Human-readable message
+  message: &'a str,
+  /// Indices of unanswered required questions, for `missing_answers` errors
+  #[serde(skip_serializing_if = "<[usize]>::is_empty")]
+  missing: &'a [usize],
+}
+
+impl IntoResponse for ApiError {
+  fn into_response(self) -> Response {
+    (self.status, Json(self.envelope())).into_response()
+  }
+}
+
+impl From<DbError> for ApiError {
+  fn from(error: DbError) -> Self {
+    if error.is_not_found() {
+      Self::new(StatusCode::NOT_FOUND, "not_found", error.to_string())
+    } else if error.is_conflict() {
+      Self::new(StatusCode::CONFLICT, "conflict", error.to_string())
+    } else if error.is_transient() {
+      tracing::warn!("database unavailable: {error}");
+      Self::new(
+        StatusCode::SERVICE_UNAVAILABLE,
+        "db_unavailable",
+        "Database is temporarily unavailable",
+      )
+    } else if matches!(
+      error,
+      DbError::Validation(_) | DbError::InvalidUuid(_) | DbError::InvalidEmail(_)
+    ) {
+      Self::new(
+        StatusCode::BAD_REQUEST,
+        "validation_error",
+        error.to_string(),
+      )
+    } else {
+      Self::internal(error)
+    }
+  }
+}
+
+impl From<ValidationError> for ApiError {
+  fn from(error: ValidationError) -> Self {
+    Self::new(
+      StatusCode::BAD_REQUEST,
+      "validation_error",
+      error.to_string(),
+    )
+  }
+}
+
+impl From<AnalysisError> for ApiError {
+  fn from(error: AnalysisError) -> Self {
+    match error {
+      AnalysisError::InvalidIdFormat(_) => {
+        Self::new(StatusCode::BAD_REQUEST, "invalid_id", error.to_string())
+      }
+      AnalysisError::NotFound(_) => {
+        Self::new(StatusCode::NOT_FOUND, "not_found", error.to_string())
+      }
+    }
+  }
+}
+
+impl From<InterviewError> for ApiError {
+  fn from(error: InterviewError) -> Self {
+    match error {
+      InterviewError::InvalidIdFormat(_) => {
+        Self::new(StatusCode::BAD_REQUEST, "invalid_id", error.to_string())
+      }
+      InterviewError::NotFound(_) => {
+        Self::new(StatusCode::NOT_FOUND, "not_found", error.to_string())
+      }
+      InterviewError::AlreadyExists(_) => {
+        Self::new(StatusCode::CONFLICT, "conflict", error.to_string())
+      }
+      InterviewError::InvalidStateTransition { .. } => Self::new(
+        StatusCode::CONFLICT,
+        "invalid_transition",
+        error.to_string(),
+      ),
+      InterviewError::NotAcceptingAnswers(_) => {
+        Self::new(StatusCode::CONFLICT, "invalid_state", error.to_string())
+      }
+      InterviewError::InvalidState(_)
+      | InterviewError::InvalidQuestionIndex(_)
+      | InterviewError::AnswerTypeMismatch { .. } => Self::new(
+        StatusCode::BAD_REQUEST,
+        "validation_error",
+        error.to_string(),
+      ),
+      InterviewError::MissingRequiredAnswers(ref missing) => {
+        let missing = missing.clone();
+        Self::new(
+          StatusCode::UNPROCESSABLE_ENTITY,
+          "missing_answers",
+          error.to_string(),
+        )
+        .with_missing(missing)
+      }
+      InterviewError::MissingField(_)
+      | InterviewError::EmptySpecName
+      | InterviewError::InvalidQuestionId(_)
+      | InterviewError::DuplicateQuestionId(_) => Self::new(
+        StatusCode::UNPROCESSABLE_ENTITY,
+        "validation_error",
+        error.to_string(),
+      ),
+      InterviewError::SystemTimeInvalid => Self::internal(error),
+    }
+  }
+}
+
+impl From<SessionError> for ApiError {
+  fn from(error: SessionError) -> Self {
+    match error {
+      SessionError::InvalidIdFormat(_) => {
+        Self::new(StatusCode::BAD_REQUEST, "invalid_id", error.to_string())
+      }
+      SessionError::NotFound(_) => Self::new(StatusCode::NOT_FOUND, "not_found", error.to_string()),
+      SessionError::InvalidStateTransition { .. } => Self::new(
+        StatusCode::CONFLICT,
+        "invalid_transition",
+        error.to_string(),
+      ),
+      SessionError::AlreadyExists(_) | SessionError::StaleUpdate(_) => {
+        Self::new(StatusCode::CONFLICT, "conflict", error.to_string())
+      }
+      SessionError::InvalidState(_)
+      | SessionError::InvalidKind(_)
+      | SessionError::MissingField(_)
+      | SessionError::UnexpectedInterviewId { .. }
+      | SessionError::ResultKindMismatch { .. } => Self::new(
+        StatusCode::BAD_REQUEST,
+        "validation_error",
+        error.to_string(),
+      ),
+      SessionError::SystemTimeInvalid => Self::internal(error),
+    }
+  }
+}
@@ -0,0 +1,63 @@
+//! Service health
+
+use crate::state::AppState;
+use axum::{extract::State, http::StatusCode, Json};
+use clarity_core::db::health_check;
+use serde::Serialize;
+
+/// State of one dependency in a health report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DependencyStatus {
+  /// Reachable and answering
+  Ok,
+  /// Configured but not answering
+  Down,
+  /// Not configured for this deployment
+  Disabled,
+}
+
+/// Response body for `GET /health`
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthResponse {
+  /// `"ok"` when every configured dependency is up, `"degraded"` otherwise
+  pub status: &'static str,
+  /// Embedded database status
+  pub db: DependencyStatus,
+  /// Version of the running server
+  pub version: &'static str,
+  /// Whole seconds since the server booted
+  pub uptime_secs: u64,
+}
+
+/// Report service health, including database connectivity
+///
+/// Responds with 503 Service Unavailable when a configured dependency is down.
+pub async fn health(State(state): State<AppState>) -> (StatusCode, Json<HealthResponse>) {
+  let db = match &state.db {
+    Some(pool) => match health_check(pool).await {
+      Ok(()) => DependencyStatus::Ok,
+      Err(e) => {
+        tracing::warn!("database health check failed: {e}");
+        DependencyStatus::Down
+      }
+    },
+    None => DependencyStatus::Disabled,
+  };
+
+  let (code, status) = if db == DependencyStatus::Down {
+    (StatusCode::SERVICE_UNAVAILABLE, "degraded")
+  } else {
+    (StatusCode::OK, "ok")
+  };
+
+  (
+    code,
+    Json(HealthResponse {
+      status,
+      db,
+      version: env!("CARGO_PKG_VERSION"),
+      uptime_secs: state.uptime_secs(),
+    }),
+  )
+}
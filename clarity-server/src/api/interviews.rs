@@ -0,0 +1,82 @@
+//! Interview endpoints
+
+use super::ApiError;
+use crate::state::AppState;
+use axum::{
+  extract::{Path, State},
+  Json,
+};
+use clarity_core::interview::{AnswerValue, InterviewError, InterviewId, Timestamp};
+use clarity_core::progress::ProgressMetrics;
+use serde::{Deserialize, Serialize};
+
+/// Request body for `POST /interviews/{id}/answers`
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubmitAnswerRequest {
+  /// Index of the question being answered
+  pub question_index: usize,
+  /// The answer, tagged with its question type
+  pub value: AnswerValue,
+}
+
+/// Submit an answer to an interview question
+///
+/// Validates the answer against the question, stores the updated interview and
+/// responds with the interview's progress.
+///
+/// # Errors
+///
+/// Returns a 400 response if the id is malformed, the question index is out of
+/// range, or the answer type does not match
+/// Returns a 409 response if the interview is completed, failed or cancelled
+/// Returns a 404 response if no interview exists with the given id
+pub async fn submit_answer(
+  State(state): State<AppState>,
+  Path(id): Path<String>,
+  Json(request): Json<SubmitAnswerRequest>,
+) -> Result<Json<ProgressMetrics>, ApiError> {
+  let (id, now) = parse_request(id)?;
+  let updated = state
+    .interviews
+    .submit_answer(&id, request.question_index, request.value, now)
+    .await?;
+
+  Ok(Json(updated.progress()))
+}
+
+/// Response body for `POST /interviews/{id}/complete`
+#[derive(Debug, Clone, Serialize)]
+pub struct CompleteInterviewResponse {
+  /// Id of the completed interview
+  pub id: String,
+  /// State of the interview after completion
+  pub state: String,
+}
+
+/// Mark an interview as completed
+///
+/// Only succeeds once every required question has an answer.
+///
+/// # Errors
+///
+/// Returns a 422 response whose `missing` field lists the unanswered required
+/// question indices
+/// Returns a 409 response if the interview cannot complete from its current state
+/// Returns a 404 response if no interview exists with the given id
+pub async fn complete_interview(
+  State(state): State<AppState>,
+  Path(id): Path<String>,
+) -> Result<Json<CompleteInterviewResponse>, ApiError> {
+  let (id, now) = parse_request(id)?;
+  let completed = state.interviews.complete(&id, now).await?;
+
+  Ok(Json(CompleteInterviewResponse {
+    id: completed.id.to_string(),
+    state: completed.state.to_string(),
+  }))
+}
+
+/// Validate the path id and take the timestamp for the update
+fn parse_request(id: String) -> Result<(InterviewId, Timestamp), InterviewError> {
+  Ok((InterviewId::new(id)?, Timestamp::now()?))
+}
@@ -0,0 +1,44 @@
+//! Prometheus scrape endpoint
+
+use super::ApiError;
+use crate::session_store::SessionStore;
+use crate::state::AppState;
+use crate::telemetry::SESSIONS;
+use axum::{
+  extract::State,
+  http::{header, StatusCode},
+  response::IntoResponse,
+};
+use clarity_core::session::SessionState;
+
+/// Content type of the Prometheus text exposition format
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Render every recorded metric in the Prometheus text format
+///
+/// The per-state session gauges are refreshed from the store on each scrape.
+///
+/// # Errors
+///
+/// Returns a 404 `not_found` error if metrics are not enabled
+pub async fn metrics(State(state): State<AppState>) -> Result<impl IntoResponse, ApiError> {
+  let handle = state.metrics.as_ref().ok_or_else(|| {
+    ApiError::new(
+      StatusCode::NOT_FOUND,
+      "not_found",
+      "Metrics are not enabled",
+    )
+  })?;
+
+  let sessions = state.sessions.list().await;
+  for session_state in SessionState::all() {
+    let count = sessions.iter().filter(|s| s.state == session_state).count();
+    #[allow(clippy::cast_precision_loss)]
+    metrics::gauge!(SESSIONS, "state" => session_state.to_string()).set(count as f64);
+  }
+
+  Ok((
+    [(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)],
+    handle.render(),
+  ))
+}
@@ -0,0 +1,111 @@
+//! HTTP API routes
+//!
+//! Each submodule holds the handlers for one resource; [`router`] mounts them all.
+
+pub mod analysis;
+pub mod beads;
+mod error;
+pub mod health;
+pub mod interviews;
+pub mod metrics;
+pub mod openapi;
+pub mod sessions;
+pub mod workitems;
+
+pub use error::ApiError;
+
+use crate::state::AppState;
+use crate::{auth, idempotency, rate_limit, telemetry};
+use axum::{
+  middleware,
+  routing::{delete, get, post},
+  Router,
+};
+
+/// Build the API router over the given application state
+///
+/// When the state has a rate limiter it applies to the analysis, bead and
+/// session routes only, so health checks are never throttled. When it has an
+/// auth configuration the mutating routes require a bearer token; reads stay
+/// public. Mutating routes apply a write sent with an `Idempotency-Key` once
+/// and replay its response for repeats. When it has a metrics handle every
+/// request is recorded and served at `/metrics`.
+pub fn router(state: AppState) -> Router {
+  let writes = require_auth(
+    idempotent(
+      Router::new()
+        .route("/beads/batch", post(beads::create_beads_batch))
+        .route("/sessions/{id}", delete(sessions::delete_session)),
+      &state,
+    ),
+    &state,
+  );
+  let limited = rate_limited(
+    Router::new()
+      .route("/analysis/{id}", get(analysis::get_analysis))
+      .route("/beads", get(beads::list_beads))
+      .route("/beads/blocked", get(beads::blocked_beads))
+      .route("/beads/ws", get(beads::bead_updates))
+      .route("/beads/{id}", get(beads::get_bead))
+      .route("/sessions/{id}", get(sessions::get_session))
+      .route("/sessions/{id}/events", get(sessions::session_events))
+      .merge(writes),
+    &state,
+  );
+  let interview_writes = require_auth(
+    idempotent(
+      Router::new()
+        .route("/interviews/{id}/answers", post(interviews::submit_answer))
+        .route(
+          "/interviews/{id}/complete",
+          post(interviews::complete_interview),
+        ),
+      &state,
+    ),
+    &state,
+  );
+
+  let routes = Router::new()
+    .merge(limited)
+    .merge(interview_writes)
+    .route("/health", get(health::health))
+    .route("/openapi.json", get(openapi::openapi_json))
+    .route("/workitems", get(workitems::list_work_items));
+
+  with_metrics(routes, &state).with_state(state)
+}
+
+/// Require a bearer token on every route of `router` if auth is configured
+fn require_auth(router: Router<AppState>, state: &AppState) -> Router<AppState> {
+  match state.auth.clone() {
+    Some(config) => router.route_layer(middleware::from_fn_with_state(config, auth::require_token)),
+    None => router,
+  }
+}
+
+/// Deduplicate writes on every route of `router` by their `Idempotency-Key`
+fn idempotent(router: Router<AppState>, state: &AppState) -> Router<AppState> {
+  router.route_layer(middleware::from_fn_with_state(
+    state.idempotency.clone(),
+    idempotency::dedupe,
+  ))
+}
+
+/// Rate-limit every route of `router` if a limiter is configured
+fn rate_limited(router: Router<AppState>, state: &AppState) -> Router<AppState> {
+  match state.rate_limiter.clone() {
+    Some(limiter) => router.route_layer(middleware::from_fn_with_state(limiter, rate_limit::limit)),
+    None => router,
+  }
+}
+
+/// Serve `/metrics` and record every route of `router` if metrics are enabled
+fn with_metrics(router: Router<AppState>, state: &AppState) -> Router<AppState> {
+  if state.metrics.is_some() {
+    router
+      .route(telemetry::METRICS_ROUTE, get(metrics::metrics))
+      .layer(middleware::from_fn(telemetry::track_requests))
+  } else {
+    router
+  }
+}
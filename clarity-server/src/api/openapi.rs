@@ -0,0 +1,50 @@
+//! Machine-readable API description
+//!
+//! An `OpenAPI` spec is generated from the `#[utoipa::path]` annotations on
+//! the bead and session handlers and served at `GET /openapi.json`.
+
+use super::{beads, sessions};
+use axum::Json;
+use utoipa::openapi::security::{Http, HttpAuthScheme, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+/// The generated spec for the bead and session endpoints
+#[derive(OpenApi)]
+#[openapi(
+  info(title = "Clarity API"),
+  paths(
+    beads::list_beads,
+    beads::blocked_beads,
+    beads::get_bead,
+    beads::create_beads_batch,
+    sessions::get_session,
+    sessions::delete_session,
+    sessions::session_events,
+  ),
+  modifiers(&BearerAuth),
+  tags(
+    (name = "beads", description = "Units of work"),
+    (name = "sessions", description = "Interview, analysis and planning sessions"),
+  )
+)]
+pub struct ApiDoc;
+
+/// Declares the `bearer` scheme referenced by routes that require a token
+struct BearerAuth;
+
+impl Modify for BearerAuth {
+  fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+    openapi
+      .components
+      .get_or_insert_with(Default::default)
+      .add_security_scheme(
+        "bearer",
+        SecurityScheme::Http(Http::new(HttpAuthScheme::Bearer)),
+      );
+  }
+}
+
+/// Serve the generated spec as JSON
+pub async fn openapi_json() -> Json<utoipa::openapi::OpenApi> {
+  Json(ApiDoc::openapi())
+}
@@ -0,0 +1,217 @@
+//! Session endpoints
+
+use super::error::ErrorEnvelope;
+use super::ApiError;
+use crate::session_store::SessionStore;
+use crate::state::AppState;
+use axum::{
+  extract::{Path, Query, State},
+  http::StatusCode,
+  response::sse::{Event, KeepAlive, Sse},
+  Json,
+};
+use clarity_core::session::{Session, SessionError, SessionId, Timestamp};
+use futures::stream::{self, Stream};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+use utoipa::{IntoParams, ToSchema};
+
+/// Response body describing one session
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SessionResponse {
+  /// Session id
+  pub id: String,
+  /// Session kind, e.g. `"interview"`
+  pub kind: String,
+  /// Lifecycle state, e.g. `"in_progress"`
+  pub state: String,
+  /// Interview run by this session, for interview sessions
+  pub interview_id: Option<String>,
+  /// Optional title
+  pub title: Option<String>,
+  /// Optional description
+  pub description: Option<String>,
+  /// Creation time, in seconds since the Unix epoch
+  pub created_at: i64,
+  /// Last update time, in seconds since the Unix epoch
+  pub updated_at: i64,
+  /// Soft-deletion time, in seconds since the Unix epoch, if deleted
+  pub deleted_at: Option<i64>,
+}
+
+impl From<&Session> for SessionResponse {
+  fn from(session: &Session) -> Self {
+    Self {
+      id: session.id.to_string(),
+      kind: session.kind.to_string(),
+      state: session.state.to_string(),
+      interview_id: session.interview_id.as_ref().map(ToString::to_string),
+      title: session.title.clone(),
+      description: session.description.clone(),
+      created_at: session.created_at.as_secs(),
+      updated_at: session.updated_at.as_secs(),
+      deleted_at: session.deleted_at.as_ref().map(Timestamp::as_secs),
+    }
+  }
+}
+
+/// Fetch a session by id
+///
+/// # Errors
+///
+/// Returns a 400 `invalid_id` error if the id is not a UUID
+/// Returns a 404 `not_found` error if no session has this id
+#[utoipa::path(
+  get,
+  path = "/sessions/{id}",
+  tag = "sessions",
+  params(("id" = String, Path, format = Uuid, description = "Session id")),
+  responses(
+    (status = 200, description = "The session", body = SessionResponse),
+    (status = 400, description = "The id is not a UUID", body = ErrorEnvelope),
+    (status = 404, description = "No session has this id", body = ErrorEnvelope),
+    (status = 429, description = "Rate limit exceeded", body = ErrorEnvelope,
+      headers(("Retry-After" = u64, description = "Seconds to wait before retrying"))),
+  )
+)]
+pub async fn get_session(
+  State(state): State<AppState>,
+  Path(id): Path<String>,
+) -> Result<Json<SessionResponse>, ApiError> {
+  let id = SessionId::new(id)?;
+  let session = state.sessions.get(&id).await.map_err(|error| match error {
+    SessionError::NotFound(_) => ApiError::not_found("session", &id),
+    error => error.into(),
+  })?;
+
+  Ok(Json(SessionResponse::from(&session)))
+}
+
+/// Query parameters for `DELETE /sessions/{id}`
+#[derive(Debug, Clone, Copy, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DeleteSessionQuery {
+  /// Remove the session entirely instead of soft-deleting it
+  #[serde(default)]
+  pub purge: bool,
+}
+
+/// Delete a session
+///
+/// By default the session is soft-deleted: it is cancelled and marked with a
+/// `deleted_at` time but kept for its history. `?purge=true` removes it.
+///
+/// # Errors
+///
+/// Returns a 400 `invalid_id` error if the id is not a UUID
+/// Returns a 404 `not_found` error if no session has this id
+/// Returns a 409 `invalid_transition` error if the session cannot be cancelled
+#[utoipa::path(
+  delete,
+  path = "/sessions/{id}",
+  tag = "sessions",
+  params(("id" = String, Path, format = Uuid, description = "Session id"), DeleteSessionQuery),
+  security(("bearer" = [])),
+  responses(
+    (status = 204, description = "The session was deleted"),
+    (status = 400, description = "The id is not a UUID", body = ErrorEnvelope),
+    (status = 401, description = "The bearer token is missing or invalid", body = ErrorEnvelope),
+    (status = 404, description = "No session has this id", body = ErrorEnvelope),
+    (status = 409, description = "The session cannot be cancelled", body = ErrorEnvelope),
+    (status = 429, description = "Rate limit exceeded", body = ErrorEnvelope,
+      headers(("Retry-After" = u64, description = "Seconds to wait before retrying"))),
+  )
+)]
+pub async fn delete_session(
+  State(state): State<AppState>,
+  Path(id): Path<String>,
+  Query(query): Query<DeleteSessionQuery>,
+) -> Result<StatusCode, ApiError> {
+  let id = SessionId::new(id)?;
+  if query.purge {
+    state.purge_session(&id).await?;
+  } else {
+    let now = Timestamp::now().map_err(SessionError::from)?;
+    state.soft_delete_session(&id, now).await?;
+  }
+
+  Ok(StatusCode::NO_CONTENT)
+}
+
+/// Stream a session's state as server-sent events
+///
+/// The current state is sent as soon as the client connects, followed by one
+/// `state` event per transition. The stream ends after the session reaches a
+/// terminal state; a client disconnecting simply drops its subscription.
+///
+/// # Errors
+///
+/// Returns a 400 `invalid_id` error if the id is not a UUID
+/// Returns a 404 `not_found` error if no session has this id
+#[utoipa::path(
+  get,
+  path = "/sessions/{id}/events",
+  tag = "sessions",
+  params(("id" = String, Path, format = Uuid, description = "Session id")),
+  responses(
+    (status = 200, description = "`state` events, each carrying a SessionResponse",
+      content_type = "text/event-stream", body = String),
+    (status = 400, description = "The id is not a UUID", body = ErrorEnvelope),
+    (status = 404, description = "No session has this id", body = ErrorEnvelope),
+    (status = 429, description = "Rate limit exceeded", body = ErrorEnvelope,
+      headers(("Retry-After" = u64, description = "Seconds to wait before retrying"))),
+  )
+)]
+pub async fn session_events(
+  State(state): State<AppState>,
+  Path(id): Path<String>,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, ApiError> {
+  let id = SessionId::new(id)?;
+  // Subscribe before reading the current state so no transition is missed
+  let updates = state.session_events.subscribe();
+  let current = state.sessions.get(&id).await?;
+
+  let sessions = stream::unfold(
+    (Some(current), updates, false),
+    move |(pending, mut updates, finished)| {
+      let (state, id) = (state.clone(), id.clone());
+      async move {
+        if finished {
+          return None;
+        }
+        let session = match pending {
+          Some(session) => session,
+          None => next_update(&state, &mut updates, &id).await?,
+        };
+        let finished = session.is_terminal();
+        Some((session, (None, updates, finished)))
+      }
+    },
+  );
+  let events = stream::StreamExt::map(sessions, |session| {
+    Event::default()
+      .event("state")
+      .json_data(SessionResponse::from(&session))
+  });
+
+  Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+/// Wait for the next update to session `id`, or `None` once the channel closes
+///
+/// If updates were dropped because this subscriber fell behind, the stored
+/// session is sent instead so the client still ends on the latest state.
+async fn next_update(
+  state: &AppState,
+  updates: &mut Receiver<Session>,
+  id: &SessionId,
+) -> Option<Session> {
+  loop {
+    match updates.recv().await {
+      Ok(session) if &session.id == id => return Some(session),
+      Ok(_) => {}
+      Err(RecvError::Lagged(_)) => return state.sessions.get(id).await.ok(),
+      Err(RecvError::Closed) => return None,
+    }
+  }
+}
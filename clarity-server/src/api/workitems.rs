@@ -0,0 +1,78 @@
+//! Unified work item feed
+
+use super::ApiError;
+use crate::session_store::SessionStore;
+use crate::state::AppState;
+use axum::{
+  extract::{Query, State},
+  Json,
+};
+use clarity_core::db::list_beads;
+use clarity_core::workitem::{merge_work_items, WorkItem};
+use serde::{Deserialize, Serialize};
+
+/// Page size used when the request does not give one
+pub const DEFAULT_LIMIT: usize = 50;
+
+/// Largest page size a request may ask for
+pub const MAX_LIMIT: usize = 100;
+
+/// Query parameters for `GET /workitems`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct WorkItemQuery {
+  /// Only include items that still need work
+  #[serde(default)]
+  pub active: bool,
+  /// Maximum number of items to return, capped at [`MAX_LIMIT`]
+  pub limit: Option<usize>,
+  /// Number of items to skip
+  #[serde(default)]
+  pub offset: usize,
+}
+
+/// One page of the work item feed
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkItemPage {
+  /// Items on this page, most recently updated first
+  pub items: Vec<WorkItem>,
+  /// Number of items across all pages
+  pub total: usize,
+  /// Offset of the next page, if there is one
+  pub next_offset: Option<usize>,
+}
+
+/// List sessions, interviews and beads as one feed sorted by `updated_at`
+///
+/// Beads come from the database; without one the feed has no beads.
+///
+/// # Errors
+///
+/// Returns an `ApiError` if the beads cannot be read from the database
+pub async fn list_work_items(
+  State(state): State<AppState>,
+  Query(query): Query<WorkItemQuery>,
+) -> Result<Json<WorkItemPage>, ApiError> {
+  let mut items: Vec<WorkItem> = state
+    .sessions
+    .list()
+    .await
+    .iter()
+    .map(WorkItem::from)
+    .collect();
+  items.extend(state.interviews.list().await.iter().map(WorkItem::from));
+  if let Some(pool) = &state.db {
+    items.extend(list_beads(pool).await?.iter().map(WorkItem::from));
+  }
+
+  let merged = merge_work_items(items, query.active);
+  let total = merged.len();
+  let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+  let items: Vec<WorkItem> = merged.into_iter().skip(query.offset).take(limit).collect();
+  let end = query.offset.saturating_add(items.len());
+
+  Ok(Json(WorkItemPage {
+    items,
+    total,
+    next_offset: (end < total).then_some(end),
+  }))
+}
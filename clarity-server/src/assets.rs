@@ -0,0 +1,76 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Static pages and assets
+//!
+//! Assets are embedded at compile time with `include_str!()` so the server
+//! does not depend on the working directory it is launched from.
+
+use axum::{
+  http::{header, HeaderMap, StatusCode},
+  response::{Html, IntoResponse, Response},
+  routing::get,
+  Router,
+};
+use clarity_core::hash::fnv1a;
+use std::sync::OnceLock;
+
+/// The client stylesheet, embedded at compile time
+pub const CSS: &str = include_str!("../../clarity-client/assets/responsive.css");
+
+/// FNV-1a hash of [`CSS`], computed at compile time since the content is static
+const CSS_HASH: u64 = fnv1a(CSS.as_bytes());
+
+/// `Cache-Control` for assets: clients may cache but must revalidate with the `ETag`
+const CACHE_CONTROL: &str = "public, no-cache";
+
+/// Routes for the landing page and embedded assets
+pub fn router() -> Router {
+  Router::new()
+    .route("/", get(root))
+    .route("/assets/responsive.css", get(serve_css))
+}
+
+async fn root() -> Html<&'static str> {
+  Html("<!DOCTYPE html><html lang=\"en\"><head><meta charset=\"UTF-8\"><meta name=\"viewport\" content=\"width=device-width, initial-scale=1.0\"><title>Clarity Application</title><link rel=\"stylesheet\" href=\"/assets/responsive.css\"></head><body><div class=\"container\"><h1>Clarity Application</h1><p>Welcome to Clarity with responsive design!</p></div></body></html>")
+}
+
+/// Serve the responsive CSS file with proper content type
+///
+/// Responds with 304 Not Modified and no body when the request's
+/// `If-None-Match` lists the stylesheet's `ETag` (compared weakly, so a
+/// `W/` prefix is ignored) or is `*`.
+async fn serve_css(headers: HeaderMap) -> Response {
+  let etag = css_etag();
+  let cache_headers = [(header::ETAG, etag), (header::CACHE_CONTROL, CACHE_CONTROL)];
+
+  let not_modified = headers
+    .get(header::IF_NONE_MATCH)
+    .and_then(|value| value.to_str().ok())
+    .is_some_and(|value| {
+      value
+        .split(',')
+        .map(str::trim)
+        .any(|tag| tag == "*" || tag.strip_prefix("W/").unwrap_or(tag) == etag)
+    });
+  if not_modified {
+    return (StatusCode::NOT_MODIFIED, cache_headers).into_response();
+  }
+
+  (
+    cache_headers,
+    [(header::CONTENT_TYPE, "text/css; charset=utf-8")],
+    CSS,
+  )
+    .into_response()
+}
+
+/// Strong `ETag` for the embedded stylesheet, derived from its contents
+pub fn css_etag() -> &'static str {
+  static ETAG: OnceLock<String> = OnceLock::new();
+  ETAG.get_or_init(|| format!("\"{CSS_HASH:016x}\""))
+}
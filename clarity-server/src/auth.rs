@@ -0,0 +1,210 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Bearer-token authentication
+//!
+//! Requests must carry `Authorization: Bearer <token>` with one of the
+//! configured tokens. The caller's [`Principal`] is stored in the request
+//! extensions, so handlers can take it with `Extension<Principal>`.
+
+use crate::api::ApiError;
+use axum::{
+  extract::{Request, State},
+  http::{header, HeaderValue, StatusCode},
+  middleware::Next,
+  response::{IntoResponse, Response},
+};
+use std::sync::Arc;
+
+/// Environment variable holding a comma-separated list of `name:token` pairs
+///
+/// A token without a name authenticates as [`DEFAULT_PRINCIPAL`].
+pub const API_TOKENS_ENV: &str = "CLARITY_API_TOKENS";
+
+/// Principal name used for tokens configured without one
+pub const DEFAULT_PRINCIPAL: &str = "api";
+
+/// The authenticated caller of a request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Principal(pub String);
+
+impl Principal {
+  /// Name of the principal
+  #[must_use]
+  pub fn name(&self) -> &str {
+    &self.0
+  }
+}
+
+impl std::fmt::Display for Principal {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+/// Tokens accepted by the API and who each one belongs to
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuthConfig {
+  tokens: Vec<(Principal, String)>,
+}
+
+impl AuthConfig {
+  /// Create a configuration with no tokens, which rejects every request
+  #[must_use]
+  pub const fn new() -> Self {
+    Self { tokens: Vec::new() }
+  }
+
+  /// Accept `token` on behalf of `principal`; empty tokens are ignored
+  #[must_use]
+  pub fn with_token(mut self, principal: impl Into<String>, token: impl Into<String>) -> Self {
+    let token = token.into();
+    if !token.is_empty() {
+      self.tokens.push((Principal(principal.into()), token));
+    }
+    self
+  }
+
+  /// Parse `name:token` pairs separated by commas
+  ///
+  /// Whitespace around entries is ignored, as are empty entries.
+  #[must_use]
+  pub fn parse(list: &str) -> Self {
+    list
+      .split(',')
+      .map(str::trim)
+      .filter(|entry| !entry.is_empty())
+      .fold(Self::new(), |config, entry| match entry.split_once(':') {
+        Some((name, token)) => config.with_token(name.trim(), token.trim()),
+        None => config.with_token(DEFAULT_PRINCIPAL, entry),
+      })
+  }
+
+  /// Read tokens from `CLARITY_API_TOKENS`
+  ///
+  /// Returns `None` when the variable is unset or lists no tokens, leaving
+  /// authentication disabled.
+  #[must_use]
+  pub fn from_env() -> Option<Self> {
+    std::env::var(API_TOKENS_ENV)
+      .ok()
+      .map(|list| Self::parse(&list))
+      .filter(|config| !config.is_empty())
+  }
+
+  /// Whether no tokens are configured
+  #[must_use]
+  pub const fn is_empty(&self) -> bool {
+    self.tokens.is_empty()
+  }
+
+  /// The principal a token belongs to, if it is accepted
+  ///
+  /// Every configured token is compared in full, so the time taken does not
+  /// reveal how much of a guess was right.
+  #[must_use]
+  pub fn authenticate(&self, token: &str) -> Option<Principal> {
+    self
+      .tokens
+      .iter()
+      .fold(None, |found, (principal, expected)| {
+        let matches = constant_time_eq(token.as_bytes(), expected.as_bytes());
+        found.or_else(|| matches.then(|| principal.clone()))
+      })
+  }
+
+  /// Share the configuration between requests
+  #[must_use]
+  pub fn into_shared(self) -> Arc<Self> {
+    Arc::new(self)
+  }
+}
+
+/// Middleware requiring a valid bearer token
+///
+/// Responds `401 Unauthorized` with a `WWW-Authenticate: Bearer` header when
+/// the token is missing or not accepted.
+pub async fn require_token(
+  State(config): State<Arc<AuthConfig>>,
+  mut request: Request,
+  next: Next,
+) -> Response {
+  let principal = request
+    .headers()
+    .get(header::AUTHORIZATION)
+    .and_then(|value| value.to_str().ok())
+    .and_then(bearer_token)
+    .and_then(|token| config.authenticate(token));
+
+  match principal {
+    Some(principal) => {
+      request.extensions_mut().insert(principal);
+      next.run(request).await
+    }
+    None => unauthorized(),
+  }
+}
+
+/// The token from an `Authorization: Bearer <token>` header value
+fn bearer_token(value: &str) -> Option<&str> {
+  let (scheme, token) = value.trim().split_once(' ')?;
+  let token = token.trim();
+  (scheme.eq_ignore_ascii_case("bearer") && !token.is_empty()).then_some(token)
+}
+
+/// Compare two byte strings without stopping at the first difference
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+  a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+fn unauthorized() -> Response {
+  let mut response = ApiError::new(
+    StatusCode::UNAUTHORIZED,
+    "unauthorized",
+    "A valid bearer token is required",
+  )
+  .into_response();
+  response
+    .headers_mut()
+    .insert(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+  response
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_named_and_bare_tokens() {
+    let config = AuthConfig::parse(" alice:secret-a , , secret-b ");
+
+    assert_eq!(
+      config.authenticate("secret-a"),
+      Some(Principal("alice".to_string()))
+    );
+    assert_eq!(
+      config.authenticate("secret-b"),
+      Some(Principal(DEFAULT_PRINCIPAL.to_string()))
+    );
+    assert_eq!(config.authenticate("secret"), None);
+  }
+
+  #[test]
+  fn test_empty_config_rejects_everything() {
+    assert!(AuthConfig::parse(" , ").is_empty());
+    assert_eq!(AuthConfig::new().authenticate(""), None);
+  }
+
+  #[test]
+  fn test_bearer_token() {
+    assert_eq!(bearer_token("Bearer abc"), Some("abc"));
+    assert_eq!(bearer_token("bearer  abc "), Some("abc"));
+    assert_eq!(bearer_token("Basic abc"), None);
+    assert_eq!(bearer_token("Bearer "), None);
+    assert_eq!(bearer_token("abc"), None);
+  }
+}
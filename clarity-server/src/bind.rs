@@ -0,0 +1,126 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Listening address for the server
+//!
+//! The host and port come from the environment so the server can bind all
+//! interfaces in a container while defaulting to localhost for development.
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use thiserror::Error;
+
+/// Environment variable holding the IP address to bind
+pub const HOST_ENV: &str = "CLARITY_HOST";
+
+/// Environment variable holding the port to bind
+pub const PORT_ENV: &str = "CLARITY_PORT";
+
+/// Address bound when `CLARITY_HOST` is unset
+pub const DEFAULT_HOST: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+/// Port bound when `CLARITY_PORT` is unset
+pub const DEFAULT_PORT: u16 = 4123;
+
+/// Errors that can occur when resolving the listening address
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum BindError {
+  /// The host is not an IP address
+  #[error("invalid CLARITY_HOST '{0}': expected an IP address such as 0.0.0.0")]
+  InvalidHost(String),
+
+  /// The port is not a number in `0..=65535`
+  #[error("invalid CLARITY_PORT '{0}': expected a port number from 0 to 65535")]
+  InvalidPort(String),
+}
+
+/// Resolve the listening address from variables looked up with `env`
+///
+/// Unset or blank variables fall back to [`DEFAULT_HOST`] and
+/// [`DEFAULT_PORT`].
+///
+/// # Errors
+///
+/// Returns `BindError::InvalidHost` if the host is not an IP address
+/// Returns `BindError::InvalidPort` if the port is not a valid port number
+pub fn resolve_bind_addr(env: impl Fn(&str) -> Option<String>) -> Result<SocketAddr, BindError> {
+  let lookup = |name| env(name).filter(|value| !value.trim().is_empty());
+
+  let host = lookup(HOST_ENV).map_or(Ok(DEFAULT_HOST), |raw| {
+    raw.trim().parse().map_err(|_| BindError::InvalidHost(raw))
+  })?;
+  let port = lookup(PORT_ENV).map_or(Ok(DEFAULT_PORT), |raw| {
+    raw.trim().parse().map_err(|_| BindError::InvalidPort(raw))
+  })?;
+
+  Ok(SocketAddr::new(host, port))
+}
+
+/// Resolve the listening address from `CLARITY_HOST` and `CLARITY_PORT`
+///
+/// # Errors
+///
+/// Returns a `BindError` if either variable is malformed
+pub fn bind_addr_from_env() -> Result<SocketAddr, BindError> {
+  resolve_bind_addr(|name| std::env::var(name).ok())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+    move |name| {
+      vars
+        .iter()
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| (*value).to_string())
+    }
+  }
+
+  #[test]
+  fn test_defaults_when_unset() {
+    assert_eq!(
+      resolve_bind_addr(env(&[])),
+      Ok(SocketAddr::new(DEFAULT_HOST, DEFAULT_PORT))
+    );
+    assert_eq!(
+      resolve_bind_addr(env(&[(HOST_ENV, " "), (PORT_ENV, "")])),
+      Ok(SocketAddr::new(DEFAULT_HOST, DEFAULT_PORT))
+    );
+  }
+
+  #[test]
+  fn test_valid_host_and_port() {
+    assert_eq!(
+      resolve_bind_addr(env(&[(HOST_ENV, "0.0.0.0"), (PORT_ENV, "8080")])),
+      Ok(SocketAddr::from(([0, 0, 0, 0], 8080)))
+    );
+    assert_eq!(
+      resolve_bind_addr(env(&[(HOST_ENV, "::")])),
+      Ok(SocketAddr::new(
+        IpAddr::V6(std::net::Ipv6Addr::UNSPECIFIED),
+        DEFAULT_PORT
+      ))
+    );
+  }
+
+  #[test]
+  fn test_malformed_values_are_rejected() {
+    assert_eq!(
+      resolve_bind_addr(env(&[(PORT_ENV, "80a")])),
+      Err(BindError::InvalidPort("80a".to_string()))
+    );
+    assert_eq!(
+      resolve_bind_addr(env(&[(PORT_ENV, "70000")])),
+      Err(BindError::InvalidPort("70000".to_string()))
+    );
+    assert_eq!(
+      resolve_bind_addr(env(&[(HOST_ENV, "example.test")])),
+      Err(BindError::InvalidHost("example.test".to_string()))
+    );
+  }
+}
@@ -0,0 +1,32 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Response compression
+//!
+//! Responses are gzip or brotli encoded when the client's `Accept-Encoding`
+//! allows it. Small bodies are sent as-is, since compressing them saves
+//! little, and server-sent events are never compressed so each event is
+//! flushed as soon as it is written.
+
+use axum::Router;
+use tower_http::compression::{
+  predicate::{NotForContentType, Predicate, SizeAbove},
+  CompressionLayer,
+};
+
+/// Bodies smaller than this many bytes are not compressed
+pub const MIN_COMPRESSED_SIZE: u16 = 1024;
+
+/// Wrap `router` so large responses are compressed for clients that accept it
+pub fn with_compression(router: Router) -> Router {
+  let predicate = SizeAbove::new(MIN_COMPRESSED_SIZE)
+    .and(NotForContentType::SSE)
+    .and(NotForContentType::GRPC)
+    .and(NotForContentType::IMAGES);
+
+  router.layer(CompressionLayer::new().compress_when(predicate))
+}
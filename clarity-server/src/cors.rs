@@ -0,0 +1,132 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Cross-origin request policy for the API
+//!
+//! Only origins listed in the configuration receive CORS headers; requests
+//! from any other origin get none, so browsers block them.
+
+use crate::idempotency::IDEMPOTENCY_KEY_HEADER;
+use crate::request_id::REQUEST_ID_HEADER;
+use axum::http::{header, HeaderName, HeaderValue, Method};
+use thiserror::Error;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+/// Environment variable holding a comma-separated list of allowed origins
+pub const ALLOWED_ORIGINS_ENV: &str = "CLARITY_ALLOWED_ORIGINS";
+
+/// Origin of the client's local dev server, allowed when nothing is configured
+pub const DEFAULT_ALLOWED_ORIGIN: &str = "http://localhost:8080";
+
+/// Errors that can occur when building the CORS policy
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum CorsError {
+  /// An origin is not a valid header value
+  #[error("invalid allowed origin: {0}")]
+  InvalidOrigin(String),
+}
+
+/// Which origins may call the API from a browser
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorsConfig {
+  /// Exact origins, e.g. `http://localhost:8080`
+  pub allowed_origins: Vec<String>,
+}
+
+impl Default for CorsConfig {
+  fn default() -> Self {
+    Self {
+      allowed_origins: vec![DEFAULT_ALLOWED_ORIGIN.to_string()],
+    }
+  }
+}
+
+impl CorsConfig {
+  /// Create a configuration allowing exactly the given origins
+  #[must_use]
+  pub const fn new(allowed_origins: Vec<String>) -> Self {
+    Self { allowed_origins }
+  }
+
+  /// Read allowed origins from `CLARITY_ALLOWED_ORIGINS`
+  ///
+  /// Falls back to [`DEFAULT_ALLOWED_ORIGIN`] when the variable is unset or
+  /// lists no origins.
+  #[must_use]
+  pub fn from_env() -> Self {
+    std::env::var(ALLOWED_ORIGINS_ENV)
+      .ok()
+      .map(|raw| parse_origins(&raw))
+      .filter(|origins| !origins.is_empty())
+      .map_or_else(Self::default, Self::new)
+  }
+
+  /// Build the CORS layer for this configuration
+  ///
+  /// # Errors
+  ///
+  /// Returns `CorsError::InvalidOrigin` if an origin is not a valid header value
+  pub fn layer(&self) -> Result<CorsLayer, CorsError> {
+    let origins = self
+      .allowed_origins
+      .iter()
+      .map(|origin| {
+        HeaderValue::from_str(origin).map_err(|_| CorsError::InvalidOrigin(origin.clone()))
+      })
+      .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(
+      CorsLayer::new()
+        .allow_origin(AllowOrigin::list(origins))
+        .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
+        .allow_headers([
+          header::AUTHORIZATION,
+          header::CONTENT_TYPE,
+          header::IF_NONE_MATCH,
+          HeaderName::from_static(IDEMPOTENCY_KEY_HEADER),
+          HeaderName::from_static(REQUEST_ID_HEADER),
+        ])
+        // Readable by the client: the id to quote in bug reports and how
+        // long a rate-limited client should wait
+        .expose_headers([
+          HeaderName::from_static(REQUEST_ID_HEADER),
+          header::RETRY_AFTER,
+        ]),
+    )
+  }
+}
+
+/// Split a comma-separated origin list, dropping blanks and trailing slashes
+fn parse_origins(raw: &str) -> Vec<String> {
+  raw
+    .split(',')
+    .map(|origin| origin.trim().trim_end_matches('/'))
+    .filter(|origin| !origin.is_empty())
+    .map(str::to_string)
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_origins() {
+    assert_eq!(
+      parse_origins(" http://a.test/ ,,https://b.test"),
+      vec!["http://a.test".to_string(), "https://b.test".to_string()]
+    );
+    assert!(parse_origins(" , ").is_empty());
+  }
+
+  #[test]
+  fn test_invalid_origin_is_rejected() {
+    let config = CorsConfig::new(vec!["http://bad\norigin".to_string()]);
+
+    assert!(matches!(config.layer(), Err(CorsError::InvalidOrigin(_))));
+  }
+}
@@ -0,0 +1,218 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Deduplication of retried writes by `Idempotency-Key`
+//!
+//! A mutating request that carries an `Idempotency-Key` header is applied
+//! once; repeating it with the same key, method and path within
+//! [`IDEMPOTENCY_TTL`] replays the stored response instead of applying it
+//! again. This lets the client's offline queue resend a write whose response
+//! was lost. Server errors are not stored, so a failed write can be retried.
+//! Requests without the header are passed through untouched.
+
+use crate::api::ApiError;
+use axum::{
+  body::{to_bytes, Body, Bytes},
+  extract::{Request, State},
+  http::{header, HeaderValue, StatusCode},
+  middleware::Next,
+  response::{IntoResponse, Response},
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Request header naming the write a request belongs to
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Response header set to `true` on a replayed response
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "idempotent-replayed";
+
+/// How long a stored response is replayed for
+pub const IDEMPOTENCY_TTL: Duration = Duration::from_hours(24);
+
+/// Keys kept before expired ones are dropped
+const MAX_TRACKED_KEYS: usize = 10_000;
+
+/// A response kept for replay
+#[derive(Debug, Clone)]
+struct StoredResponse {
+  status: StatusCode,
+  content_type: Option<HeaderValue>,
+  body: Bytes,
+}
+
+#[derive(Debug, Clone)]
+enum Entry {
+  /// The first request with this key is still being handled
+  InFlight,
+  /// The first request finished with this response
+  Done(StoredResponse, Instant),
+}
+
+/// What to do with a request carrying a key
+enum Claim {
+  /// First time this key is seen: handle the request
+  New,
+  /// An earlier request with this key is still running
+  InFlight,
+  /// Replay the earlier response
+  Replay(StoredResponse),
+}
+
+/// Responses to keyed writes, shared by all requests
+#[derive(Debug, Clone, Default)]
+pub struct IdempotencyCache {
+  entries: Arc<Mutex<HashMap<String, Entry>>>,
+}
+
+impl IdempotencyCache {
+  /// Create an empty cache
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Mark `key` as in flight unless it is already known
+  fn claim(&self, key: &str, now: Instant) -> Claim {
+    let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+    if entries.len() >= MAX_TRACKED_KEYS {
+      entries.retain(|_, entry| !is_expired(entry, now));
+    }
+    let claim = match entries.get(key) {
+      Some(Entry::InFlight) => Claim::InFlight,
+      Some(entry @ Entry::Done(stored, _)) if !is_expired(entry, now) => {
+        Claim::Replay(stored.clone())
+      }
+      _ => {
+        entries.insert(key.to_string(), Entry::InFlight);
+        Claim::New
+      }
+    };
+    drop(entries);
+    claim
+  }
+
+  /// Store the response for `key`, or forget the key if there is none
+  fn finish(&self, key: &str, response: Option<StoredResponse>, now: Instant) {
+    let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+    match response {
+      Some(stored) => entries.insert(key.to_string(), Entry::Done(stored, now)),
+      None => entries.remove(key),
+    };
+  }
+}
+
+/// Whether a finished entry is older than [`IDEMPOTENCY_TTL`]
+fn is_expired(entry: &Entry, now: Instant) -> bool {
+  match entry {
+    Entry::InFlight => false,
+    Entry::Done(_, at) => now.saturating_duration_since(*at) >= IDEMPOTENCY_TTL,
+  }
+}
+
+/// Middleware applying each keyed write once and replaying its response
+///
+/// Keys are scoped to the request's method and path. A request that reuses a
+/// key while the first one is still running is rejected with 409.
+pub async fn dedupe(
+  State(cache): State<IdempotencyCache>,
+  request: Request,
+  next: Next,
+) -> Response {
+  let Some(key) = request
+    .headers()
+    .get(IDEMPOTENCY_KEY_HEADER)
+    .and_then(|value| value.to_str().ok())
+    .map(|key| format!("{} {} {key}", request.method(), request.uri().path()))
+  else {
+    return next.run(request).await;
+  };
+
+  match cache.claim(&key, Instant::now()) {
+    Claim::Replay(stored) => replay(stored),
+    Claim::InFlight => ApiError::new(
+      StatusCode::CONFLICT,
+      "idempotency_in_flight",
+      "A request with this Idempotency-Key is still being processed",
+    )
+    .into_response(),
+    Claim::New => {
+      let (parts, body) = next.run(request).await.into_parts();
+      let body = match to_bytes(body, usize::MAX).await {
+        Ok(body) => body,
+        Err(e) => {
+          cache.finish(&key, None, Instant::now());
+          return ApiError::internal(e).into_response();
+        }
+      };
+      let stored = (!parts.status.is_server_error()).then(|| StoredResponse {
+        status: parts.status,
+        content_type: parts.headers.get(header::CONTENT_TYPE).cloned(),
+        body: body.clone(),
+      });
+      cache.finish(&key, stored, Instant::now());
+      Response::from_parts(parts, Body::from(body))
+    }
+  }
+}
+
+/// Rebuild a stored response, marked as a replay
+fn replay(stored: StoredResponse) -> Response {
+  let mut response = (stored.status, stored.body).into_response();
+  let headers = response.headers_mut();
+  if let Some(content_type) = stored.content_type {
+    headers.insert(header::CONTENT_TYPE, content_type);
+  }
+  headers.insert(IDEMPOTENT_REPLAYED_HEADER, HeaderValue::from_static("true"));
+  response
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn stored(status: StatusCode) -> StoredResponse {
+    StoredResponse {
+      status,
+      content_type: None,
+      body: Bytes::from_static(b"{}"),
+    }
+  }
+
+  #[allow(clippy::panic)]
+  #[test]
+  fn test_claim_then_replay() {
+    let cache = IdempotencyCache::new();
+    let now = Instant::now();
+
+    assert!(matches!(cache.claim("k", now), Claim::New));
+    assert!(matches!(cache.claim("k", now), Claim::InFlight));
+
+    cache.finish("k", Some(stored(StatusCode::OK)), now);
+    match cache.claim("k", now) {
+      Claim::Replay(response) => assert_eq!(response.status, StatusCode::OK),
+      _ => panic!("Expected the stored response to be replayed"),
+    }
+  }
+
+  #[test]
+  fn test_forgotten_and_expired_keys_are_claimed_again() {
+    let cache = IdempotencyCache::new();
+    let now = Instant::now();
+
+    assert!(matches!(cache.claim("failed", now), Claim::New));
+    cache.finish("failed", None, now);
+    assert!(matches!(cache.claim("failed", now), Claim::New));
+
+    cache.finish("old", Some(stored(StatusCode::OK)), now);
+    assert!(matches!(
+      cache.claim("old", now + IDEMPOTENCY_TTL),
+      Claim::New
+    ));
+  }
+}
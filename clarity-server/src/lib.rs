@@ -0,0 +1,29 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Clarity server library
+//!
+//! Exposes the HTTP API routes, shared application state and the startup
+//! self-check so they can be used by the binary and exercised directly in tests.
+
+pub mod api;
+pub mod assets;
+pub mod auth;
+pub mod bind;
+pub mod compression;
+pub mod cors;
+pub mod idempotency;
+pub mod rate_limit;
+pub mod request_id;
+pub mod self_check;
+pub mod session_store;
+pub mod shutdown;
+pub mod state;
+pub mod telemetry;
+
+pub use self_check::{self_check, SelfCheckReport};
+pub use state::AppState;
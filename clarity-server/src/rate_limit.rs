@@ -0,0 +1,218 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Per-client rate limiting
+//!
+//! Each client IP gets a token bucket that refills at a steady rate up to a
+//! burst size. A request spends one token; when none is left the request is
+//! rejected with `429 Too Many Requests` and a `Retry-After` header.
+
+use crate::api::ApiError;
+use axum::{
+  extract::{ConnectInfo, Request, State},
+  http::{header, HeaderValue, StatusCode},
+  middleware::Next,
+  response::{IntoResponse, Response},
+};
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Environment variable holding the sustained requests per second per client
+pub const RATE_LIMIT_RPS_ENV: &str = "CLARITY_RATE_LIMIT_RPS";
+
+/// Environment variable holding the burst size per client
+pub const RATE_LIMIT_BURST_ENV: &str = "CLARITY_RATE_LIMIT_BURST";
+
+/// Buckets kept before idle, fully refilled ones are dropped
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+/// How fast each client may send requests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitConfig {
+  /// Sustained requests per second
+  pub requests_per_second: u32,
+  /// Requests allowed back to back before the rate applies
+  pub burst: u32,
+}
+
+impl Default for RateLimitConfig {
+  fn default() -> Self {
+    Self::new(10, 20)
+  }
+}
+
+impl RateLimitConfig {
+  /// Create a configuration; zero values are raised to one
+  #[must_use]
+  pub fn new(requests_per_second: u32, burst: u32) -> Self {
+    Self {
+      requests_per_second: requests_per_second.max(1),
+      burst: burst.max(1),
+    }
+  }
+
+  /// Read the limits from `CLARITY_RATE_LIMIT_RPS` and `CLARITY_RATE_LIMIT_BURST`
+  ///
+  /// Unset or unparsable values fall back to the defaults.
+  #[must_use]
+  pub fn from_env() -> Self {
+    let defaults = Self::default();
+    let read = |name: &str, default: u32| {
+      std::env::var(name)
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(default)
+    };
+    Self::new(
+      read(RATE_LIMIT_RPS_ENV, defaults.requests_per_second),
+      read(RATE_LIMIT_BURST_ENV, defaults.burst),
+    )
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+  tokens: f64,
+  updated: Instant,
+}
+
+impl Bucket {
+  /// Refill for the time elapsed since the last update, then spend one token
+  fn take(&mut self, now: Instant, rate: f64, burst: f64) -> Result<(), Duration> {
+    let elapsed = now.duration_since(self.updated).as_secs_f64();
+    self.tokens = elapsed.mul_add(rate, self.tokens).min(burst);
+    self.updated = now;
+
+    if self.tokens >= 1.0 {
+      self.tokens -= 1.0;
+      Ok(())
+    } else {
+      Err(Duration::from_secs_f64((1.0 - self.tokens) / rate))
+    }
+  }
+}
+
+/// Token buckets for every client seen, shared by all requests
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+  config: RateLimitConfig,
+  buckets: Arc<Mutex<HashMap<IpAddr, Bucket>>>,
+}
+
+impl RateLimiter {
+  /// Create a limiter with no clients tracked yet
+  #[must_use]
+  pub fn new(config: RateLimitConfig) -> Self {
+    Self {
+      config,
+      buckets: Arc::default(),
+    }
+  }
+
+  /// The limits this limiter enforces
+  #[must_use]
+  pub const fn config(&self) -> RateLimitConfig {
+    self.config
+  }
+
+  /// Spend a token for `client` at time `now`
+  ///
+  /// # Errors
+  ///
+  /// Returns how long the client must wait for the next token if it has none
+  pub fn check(&self, client: IpAddr, now: Instant) -> Result<(), Duration> {
+    let rate = f64::from(self.config.requests_per_second);
+    let burst = f64::from(self.config.burst);
+
+    let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+    if buckets.len() >= MAX_TRACKED_CLIENTS {
+      let full_after = Duration::from_secs_f64(burst / rate);
+      buckets.retain(|_, bucket| now.duration_since(bucket.updated) < full_after);
+    }
+    let bucket = buckets.entry(client).or_insert(Bucket {
+      tokens: burst,
+      updated: now,
+    });
+    let result = bucket.take(now, rate, burst);
+    drop(buckets);
+    result
+  }
+}
+
+/// Middleware rejecting clients that exceed the limiter's rate
+///
+/// Clients are identified by the peer address from `ConnectInfo`; requests
+/// without one share a single bucket.
+pub async fn limit(State(limiter): State<RateLimiter>, request: Request, next: Next) -> Response {
+  let client = request
+    .extensions()
+    .get::<ConnectInfo<SocketAddr>>()
+    .map_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED), |info| info.0.ip());
+
+  match limiter.check(client, Instant::now()) {
+    Ok(()) => next.run(request).await,
+    Err(wait) => too_many_requests(wait),
+  }
+}
+
+/// 429 response telling the client how many whole seconds to wait
+fn too_many_requests(wait: Duration) -> Response {
+  let seconds = wait.as_secs() + u64::from(wait.subsec_nanos() > 0);
+  let mut response = ApiError::new(
+    StatusCode::TOO_MANY_REQUESTS,
+    "rate_limited",
+    "Too many requests, slow down",
+  )
+  .into_response();
+  response
+    .headers_mut()
+    .insert(header::RETRY_AFTER, HeaderValue::from(seconds.max(1)));
+  response
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const CLIENT: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+  #[test]
+  fn test_burst_then_refill() {
+    let limiter = RateLimiter::new(RateLimitConfig::new(2, 3));
+    let start = Instant::now();
+
+    assert!((0..3).all(|_| limiter.check(CLIENT, start).is_ok()));
+    assert_eq!(
+      limiter.check(CLIENT, start),
+      Err(Duration::from_millis(500))
+    );
+    assert_eq!(
+      limiter.check(CLIENT, start + Duration::from_millis(500)),
+      Ok(())
+    );
+  }
+
+  #[test]
+  fn test_clients_have_separate_buckets() {
+    let limiter = RateLimiter::new(RateLimitConfig::new(1, 1));
+    let now = Instant::now();
+
+    assert_eq!(limiter.check(CLIENT, now), Ok(()));
+    assert!(limiter.check(CLIENT, now).is_err());
+    assert_eq!(
+      limiter.check(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)), now),
+      Ok(())
+    );
+  }
+
+  #[test]
+  fn test_config_rejects_zero() {
+    assert_eq!(RateLimitConfig::new(0, 0), RateLimitConfig::new(1, 1));
+  }
+}
@@ -0,0 +1,44 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Request correlation
+//!
+//! Every request gets an `x-request-id`: the client's own if it sent one,
+//! otherwise a fresh UUID. The id is recorded on the request's tracing span
+//! and echoed back in the response so logs can be correlated across services.
+
+use axum::{body::Body, http::Request, Router};
+use tower::ServiceBuilder;
+use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
+use tower_http::trace::TraceLayer;
+
+/// Header carrying the request id
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Wrap `router` so each request has an id, a tracing span and an echoed header
+pub fn with_request_id(router: Router) -> Router {
+  router.layer(
+    ServiceBuilder::new()
+      .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
+      .layer(
+        TraceLayer::new_for_http().make_span_with(|request: &Request<Body>| {
+          let request_id = request
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+          tracing::info_span!(
+            "request",
+            method = %request.method(),
+            uri = %request.uri(),
+            request_id,
+          )
+        }),
+      )
+      .layer(PropagateRequestIdLayer::x_request_id()),
+  )
+}
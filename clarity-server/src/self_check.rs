@@ -0,0 +1,175 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Startup self-check
+//!
+//! Runs the checks an operator needs before trusting a deployment: the
+//! embedded `SQLite` database configuration is valid, the database is
+//! reachable and every embedded migration has been applied. Checks run in order and a failure
+//! skips the checks that depend on it.
+
+use clarity_core::db::{
+  create_sqlite_pool, test_sqlite_connection, verify_sqlite_migrations, DbError, SqliteDbConfig,
+};
+use clarity_core::{map_db_error, ExitCode};
+use std::fmt::{self, Display};
+
+/// Outcome of a single self-check step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+  /// The check succeeded
+  Passed,
+  /// The check failed and maps to the given exit code
+  Failed(ExitCode),
+  /// The check was not run because an earlier check failed
+  Skipped,
+}
+
+/// A named self-check step and its outcome
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckResult {
+  /// Short name of the check, e.g. `"migrations"`
+  pub name: &'static str,
+  /// Whether the check passed, failed or was skipped
+  pub status: CheckStatus,
+  /// Human-readable detail
+  pub detail: String,
+}
+
+impl CheckResult {
+  /// A check that succeeded
+  pub fn passed(name: &'static str, detail: impl Into<String>) -> Self {
+    Self {
+      name,
+      status: CheckStatus::Passed,
+      detail: detail.into(),
+    }
+  }
+
+  /// A check that failed with a database error
+  #[must_use]
+  pub fn failed(name: &'static str, error: &DbError) -> Self {
+    Self {
+      name,
+      status: CheckStatus::Failed(map_db_error(error).unwrap_or(ExitCode::ERROR)),
+      detail: error.to_string(),
+    }
+  }
+
+  /// A check that was not run
+  pub fn skipped(name: &'static str, reason: impl Into<String>) -> Self {
+    Self {
+      name,
+      status: CheckStatus::Skipped,
+      detail: reason.into(),
+    }
+  }
+}
+
+impl Display for CheckResult {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let label = match self.status {
+      CheckStatus::Passed => "ok",
+      CheckStatus::Failed(_) => "FAIL",
+      CheckStatus::Skipped => "skip",
+    };
+    write!(f, "[{label}] {}: {}", self.name, self.detail)
+  }
+}
+
+/// Results of every self-check step, in the order they ran
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelfCheckReport {
+  checks: Vec<CheckResult>,
+}
+
+impl SelfCheckReport {
+  /// Create a report from its check results
+  #[must_use]
+  pub const fn from_checks(checks: Vec<CheckResult>) -> Self {
+    Self { checks }
+  }
+
+  /// All check results
+  #[must_use]
+  pub fn checks(&self) -> &[CheckResult] {
+    &self.checks
+  }
+
+  /// Whether no check failed
+  #[must_use]
+  pub fn is_healthy(&self) -> bool {
+    self.exit_code().is_success()
+  }
+
+  /// Exit code for the process: the code of the first failed check, or success
+  #[must_use]
+  pub fn exit_code(&self) -> ExitCode {
+    self
+      .checks
+      .iter()
+      .find_map(|check| match check.status {
+        CheckStatus::Failed(code) => Some(code),
+        CheckStatus::Passed | CheckStatus::Skipped => None,
+      })
+      .unwrap_or(ExitCode::SUCCESS)
+  }
+}
+
+impl Display for SelfCheckReport {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for check in &self.checks {
+      writeln!(f, "{check}")?;
+    }
+    let status = if self.is_healthy() {
+      "healthy"
+    } else {
+      "unhealthy"
+    };
+    write!(f, "self-check {status}")
+  }
+}
+
+/// Run the startup self-check against the database the server serves from
+///
+/// Failures are recorded in the report rather than returned, so every step
+/// that could run is reported.
+pub async fn self_check(config: &SqliteDbConfig) -> SelfCheckReport {
+  let mut checks = Vec::new();
+
+  if let Err(e) = config.validate() {
+    checks.push(CheckResult::failed("config", &e));
+    checks.push(CheckResult::skipped("database", "invalid configuration"));
+    checks.push(CheckResult::skipped("migrations", "invalid configuration"));
+    return SelfCheckReport::from_checks(checks);
+  }
+  checks.push(CheckResult::passed(
+    "config",
+    "database configuration is valid",
+  ));
+
+  let connected = match create_sqlite_pool(config).await {
+    Ok(pool) => test_sqlite_connection(&pool).await.map(|()| pool),
+    Err(e) => Err(e),
+  };
+  let pool = match connected {
+    Ok(pool) => pool,
+    Err(e) => {
+      checks.push(CheckResult::failed("database", &e));
+      checks.push(CheckResult::skipped("migrations", "database unavailable"));
+      return SelfCheckReport::from_checks(checks);
+    }
+  };
+  checks.push(CheckResult::passed("database", "connected"));
+
+  checks.push(match verify_sqlite_migrations(&pool).await {
+    Ok(()) => CheckResult::passed("migrations", "all migrations applied"),
+    Err(e) => CheckResult::failed("migrations", &e),
+  });
+
+  SelfCheckReport::from_checks(checks)
+}
@@ -0,0 +1,302 @@
+//! Storage for sessions
+//!
+//! Handlers reach sessions only through the [`SessionStore`] trait, so the
+//! in-memory [`InMemorySessionStore`] can later be replaced by a database
+//! backed one without touching them.
+//!
+//! Updates are optimistic: the caller passes the version it read, and the
+//! write is refused with `SessionError::StaleUpdate` if the stored session has
+//! changed since. Versions come from a counter rather than `updated_at`,
+//! whose one-second resolution cannot tell apart two writes in the same
+//! second.
+
+use clarity_core::session::{Session, SessionError, SessionId};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// A stored session and the version it was read at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionedSession {
+  /// The session as stored
+  pub session: Session,
+  /// Changes on every write; pass it back to [`SessionStore::update`]
+  pub version: u64,
+}
+
+/// Where sessions are kept
+pub trait SessionStore {
+  /// Add a new session
+  ///
+  /// # Errors
+  ///
+  /// Returns `SessionError::AlreadyExists` if a session with the same id is stored
+  fn create(&self, session: Session) -> impl Future<Output = Result<Session, SessionError>> + Send;
+
+  /// Get a snapshot of a session
+  ///
+  /// # Errors
+  ///
+  /// Returns `SessionError::NotFound` if no session has the given id
+  fn get(&self, id: &SessionId) -> impl Future<Output = Result<Session, SessionError>> + Send;
+
+  /// Get a snapshot of a session together with its current version
+  ///
+  /// # Errors
+  ///
+  /// Returns `SessionError::NotFound` if no session has the given id
+  fn get_versioned(
+    &self,
+    id: &SessionId,
+  ) -> impl Future<Output = Result<VersionedSession, SessionError>> + Send;
+
+  /// Replace a stored session, provided it is still at `expected_version`
+  ///
+  /// # Errors
+  ///
+  /// Returns `SessionError::NotFound` if no session has the session's id
+  /// Returns `SessionError::StaleUpdate` if the stored session was updated
+  /// since the caller read it
+  fn update(
+    &self,
+    session: Session,
+    expected_version: u64,
+  ) -> impl Future<Output = Result<Session, SessionError>> + Send;
+
+  /// Remove a session, returning it
+  ///
+  /// # Errors
+  ///
+  /// Returns `SessionError::NotFound` if no session has the given id
+  fn remove(&self, id: &SessionId) -> impl Future<Output = Result<Session, SessionError>> + Send;
+
+  /// Get a snapshot of every stored session, in no particular order
+  fn list(&self) -> impl Future<Output = Vec<Session>> + Send;
+}
+
+/// Thread-safe in-memory session store
+///
+/// Cloning the store is cheap and yields a handle to the same sessions.
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySessionStore {
+  inner: Arc<RwLock<Sessions>>,
+}
+
+/// Sessions keyed by id, and the version the next write is given
+///
+/// Versions are unique across the store, so a session that is removed and
+/// created again does not reuse a version an old reader may still hold.
+#[derive(Debug, Default)]
+struct Sessions {
+  by_id: HashMap<SessionId, VersionedSession>,
+  next_version: u64,
+}
+
+impl Sessions {
+  const fn next_version(&mut self) -> u64 {
+    let version = self.next_version;
+    self.next_version += 1;
+    version
+  }
+}
+
+impl InMemorySessionStore {
+  /// Create an empty store
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+}
+
+impl SessionStore for InMemorySessionStore {
+  async fn create(&self, session: Session) -> Result<Session, SessionError> {
+    let mut sessions = self.inner.write().await;
+    if sessions.by_id.contains_key(&session.id) {
+      return Err(SessionError::AlreadyExists(session.id.to_string()));
+    }
+
+    let version = sessions.next_version();
+    sessions.by_id.insert(
+      session.id.clone(),
+      VersionedSession {
+        session: session.clone(),
+        version,
+      },
+    );
+    drop(sessions);
+    Ok(session)
+  }
+
+  async fn get(&self, id: &SessionId) -> Result<Session, SessionError> {
+    self.get_versioned(id).await.map(|stored| stored.session)
+  }
+
+  async fn get_versioned(&self, id: &SessionId) -> Result<VersionedSession, SessionError> {
+    self
+      .inner
+      .read()
+      .await
+      .by_id
+      .get(id)
+      .cloned()
+      .ok_or_else(|| SessionError::NotFound(id.to_string()))
+  }
+
+  async fn update(&self, session: Session, expected_version: u64) -> Result<Session, SessionError> {
+    let mut sessions = self.inner.write().await;
+    let version = sessions.next_version();
+    let stored = sessions
+      .by_id
+      .get_mut(&session.id)
+      .ok_or_else(|| SessionError::NotFound(session.id.to_string()))?;
+    if stored.version != expected_version {
+      return Err(SessionError::StaleUpdate(session.id.to_string()));
+    }
+
+    *stored = VersionedSession {
+      session: session.clone(),
+      version,
+    };
+    drop(sessions);
+    Ok(session)
+  }
+
+  async fn remove(&self, id: &SessionId) -> Result<Session, SessionError> {
+    self
+      .inner
+      .write()
+      .await
+      .by_id
+      .remove(id)
+      .map(|stored| stored.session)
+      .ok_or_else(|| SessionError::NotFound(id.to_string()))
+  }
+
+  async fn list(&self) -> Vec<Session> {
+    self
+      .inner
+      .read()
+      .await
+      .by_id
+      .values()
+      .map(|stored| stored.session.clone())
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use clarity_core::session::{SessionKind, SessionState, Timestamp};
+
+  const ID: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+  #[allow(clippy::unwrap_used)]
+  fn session() -> Session {
+    Session::new(
+      SessionId::new(ID.to_string()).unwrap(),
+      SessionKind::Analysis,
+      None,
+      Timestamp::from_secs(100),
+    )
+    .unwrap()
+  }
+
+  #[tokio::test]
+  async fn test_create_get_and_remove() {
+    let store = InMemorySessionStore::new();
+    let id = session().id;
+    assert_eq!(
+      store.get(&id).await,
+      Err(SessionError::NotFound(ID.to_string()))
+    );
+
+    assert_eq!(store.create(session()).await, Ok(session()));
+    assert_eq!(
+      store.create(session()).await,
+      Err(SessionError::AlreadyExists(ID.to_string()))
+    );
+    assert_eq!(store.get(&id).await, Ok(session()));
+    assert_eq!(store.list().await, vec![session()]);
+
+    assert_eq!(store.remove(&id).await, Ok(session()));
+    assert!(store.list().await.is_empty());
+  }
+
+  #[allow(clippy::unwrap_used)]
+  #[tokio::test]
+  async fn test_concurrent_update_is_stale() {
+    let store = InMemorySessionStore::new();
+    store.create(session()).await.unwrap();
+
+    // Two writers read the same snapshot, then both try to write it back
+    let read = store.get_versioned(&session().id).await.unwrap();
+    let writers = [200, 300].map(|secs| {
+      let (store, read) = (store.clone(), read.clone());
+      tokio::spawn(async move {
+        let updated = read
+          .session
+          .transition_to(SessionState::InProgress, Timestamp::from_secs(secs))
+          .unwrap();
+        store.update(updated, read.version).await
+      })
+    });
+
+    let mut results = Vec::new();
+    for writer in writers {
+      results.push(writer.await.unwrap());
+    }
+    let stored = store.get(&session().id).await.unwrap();
+    assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+    assert!(results.contains(&Ok(stored)));
+    assert!(results.contains(&Err(SessionError::StaleUpdate(ID.to_string()))));
+  }
+
+  #[allow(clippy::unwrap_used)]
+  #[tokio::test]
+  async fn test_same_second_update_is_stale() {
+    let store = InMemorySessionStore::new();
+    store.create(session()).await.unwrap();
+
+    // Both writers read the session and write within the same second
+    let first = store.get_versioned(&session().id).await.unwrap();
+    let second = store.get_versioned(&session().id).await.unwrap();
+    let at = Timestamp::from_secs(200);
+
+    let started = first
+      .session
+      .transition_to(SessionState::InProgress, at)
+      .unwrap();
+    assert_eq!(
+      store.update(started.clone(), first.version).await,
+      Ok(started)
+    );
+
+    let deleted = second.session.soft_delete(at).unwrap();
+    assert_eq!(
+      store.update(deleted, second.version).await,
+      Err(SessionError::StaleUpdate(ID.to_string()))
+    );
+    assert_eq!(
+      store.get(&session().id).await.unwrap().state,
+      SessionState::InProgress
+    );
+  }
+
+  #[allow(clippy::unwrap_used)]
+  #[tokio::test]
+  async fn test_recreated_session_rejects_old_version() {
+    let store = InMemorySessionStore::new();
+    store.create(session()).await.unwrap();
+    let read = store.get_versioned(&session().id).await.unwrap();
+
+    store.remove(&session().id).await.unwrap();
+    store.create(session()).await.unwrap();
+
+    assert_eq!(
+      store.update(session(), read.version).await,
+      Err(SessionError::StaleUpdate(ID.to_string()))
+    );
+  }
+}
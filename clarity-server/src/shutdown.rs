@@ -0,0 +1,53 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Graceful shutdown
+//!
+//! [`graceful_shutdown`] wraps any signal future, so tests can trigger
+//! shutdown with a channel instead of a real OS signal.
+
+use std::future::Future;
+
+/// Resolve when the process receives Ctrl-C, or SIGTERM on Unix
+pub async fn os_signal() {
+  let ctrl_c = async {
+    if let Err(e) = tokio::signal::ctrl_c().await {
+      tracing::error!("failed to listen for ctrl-c: {e}");
+      std::future::pending::<()>().await;
+    }
+  };
+
+  #[cfg(unix)]
+  let terminate = async {
+    match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+      Ok(mut sigterm) => {
+        sigterm.recv().await;
+      }
+      Err(e) => {
+        tracing::error!("failed to listen for SIGTERM: {e}");
+        std::future::pending::<()>().await;
+      }
+    }
+  };
+
+  #[cfg(not(unix))]
+  let terminate = std::future::pending::<()>();
+
+  tokio::select! {
+    () = ctrl_c => {},
+    () = terminate => {},
+  }
+}
+
+/// Wait for `signal`, then log that the server is shutting down
+///
+/// Pass the result to `axum::serve(..).with_graceful_shutdown`; in-flight
+/// requests are allowed to finish once it resolves.
+pub async fn graceful_shutdown(signal: impl Future<Output = ()>) {
+  signal.await;
+  tracing::info!("shutting down");
+}
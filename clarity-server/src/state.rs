@@ -0,0 +1,282 @@
+//! Shared application state for request handlers
+
+use crate::auth::AuthConfig;
+use crate::idempotency::IdempotencyCache;
+use crate::rate_limit::{RateLimitConfig, RateLimiter};
+use crate::session_store::{InMemorySessionStore, SessionStore};
+use crate::telemetry::BEADS_CREATED;
+use clarity_core::analysis::{AnalysisId, AnalysisResult};
+use clarity_core::db::models::{Bead, BeadId, NewBead};
+use clarity_core::db::{repository, DbResult};
+use clarity_core::interview::InterviewStore;
+use clarity_core::session::{Session, SessionError, SessionId, SessionState, Timestamp};
+use metrics_exporter_prometheus::PrometheusHandle;
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::{broadcast, RwLock};
+
+/// How many session updates are buffered for slow subscribers
+pub const SESSION_EVENT_CAPACITY: usize = 64;
+
+/// How many bead changes are buffered for slow subscribers
+pub const BEAD_EVENT_CAPACITY: usize = 256;
+
+/// A change to a stored bead, published by the `AppState` bead methods
+///
+/// Serializes as `{ "event": "created", "bead": { ... } }`; a deleted bead
+/// carries its last stored state.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", content = "bead", rename_all = "lowercase")]
+pub enum BeadEvent {
+  /// A bead was inserted
+  Created(Bead),
+  /// A bead's fields were replaced
+  Updated(Bead),
+  /// A bead was removed
+  Deleted(Bead),
+}
+
+impl BeadEvent {
+  /// The bead the event is about
+  #[must_use]
+  pub const fn bead(&self) -> &Bead {
+    match self {
+      Self::Created(bead) | Self::Updated(bead) | Self::Deleted(bead) => bead,
+    }
+  }
+}
+
+/// State shared by all request handlers
+///
+/// Beads are stored in the embedded database; everything else is held in memory.
+#[derive(Debug, Clone)]
+pub struct AppState {
+  /// Interviews available to the API
+  pub interviews: InterviewStore,
+
+  /// Sessions available to the API
+  pub sessions: InMemorySessionStore,
+
+  /// Analysis results keyed by id
+  pub analyses: Arc<RwLock<HashMap<AnalysisId, AnalysisResult>>>,
+
+  /// Embedded database, if one has been configured
+  pub db: Option<SqlitePool>,
+
+  /// Every session after a state change, published by `transition_session`
+  pub session_events: broadcast::Sender<Session>,
+
+  /// Every bead change made through the `AppState` bead methods
+  pub bead_events: broadcast::Sender<BeadEvent>,
+
+  /// Per-client limit on the analysis, bead and session routes, if enabled
+  pub rate_limiter: Option<RateLimiter>,
+
+  /// Tokens required by the mutating routes, if authentication is enabled
+  pub auth: Option<Arc<AuthConfig>>,
+
+  /// Responses to writes sent with an `Idempotency-Key`, replayed on retry
+  pub idempotency: IdempotencyCache,
+
+  /// Renders the Prometheus metrics served at `GET /metrics`, if enabled
+  pub metrics: Option<PrometheusHandle>,
+
+  /// When the state was created, i.e. when the server booted
+  pub started_at: Instant,
+}
+
+impl Default for AppState {
+  fn default() -> Self {
+    let (session_events, _) = broadcast::channel(SESSION_EVENT_CAPACITY);
+    let (bead_events, _) = broadcast::channel(BEAD_EVENT_CAPACITY);
+    Self {
+      interviews: InterviewStore::default(),
+      sessions: InMemorySessionStore::default(),
+      analyses: Arc::default(),
+      db: None,
+      session_events,
+      bead_events,
+      rate_limiter: None,
+      auth: None,
+      idempotency: IdempotencyCache::default(),
+      metrics: None,
+      started_at: Instant::now(),
+    }
+  }
+}
+
+impl AppState {
+  /// Create empty application state
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Attach an embedded database pool
+  #[must_use]
+  pub fn with_db(mut self, pool: SqlitePool) -> Self {
+    self.db = Some(pool);
+    self
+  }
+
+  /// Rate-limit the bead and session routes per client
+  #[must_use]
+  pub fn with_rate_limit(mut self, config: RateLimitConfig) -> Self {
+    self.rate_limiter = Some(RateLimiter::new(config));
+    self
+  }
+
+  /// Require a bearer token from `config` on the mutating routes
+  #[must_use]
+  pub fn with_auth(mut self, config: AuthConfig) -> Self {
+    self.auth = Some(config.into_shared());
+    self
+  }
+
+  /// Serve `GET /metrics` from `handle` and record per-request metrics
+  #[must_use]
+  pub fn with_metrics(mut self, handle: PrometheusHandle) -> Self {
+    self.metrics = Some(handle);
+    self
+  }
+
+  /// Whole seconds since the state was created
+  #[must_use]
+  pub fn uptime_secs(&self) -> u64 {
+    self.started_at.elapsed().as_secs()
+  }
+
+  /// Move a stored session to a new state and notify subscribers
+  ///
+  /// # Errors
+  ///
+  /// Returns `SessionError::NotFound` if no session has this id
+  /// Returns `SessionError::InvalidStateTransition` if the transition is not allowed
+  /// Returns `SessionError::StaleUpdate` if the session changed concurrently
+  pub async fn transition_session(
+    &self,
+    id: &SessionId,
+    to: SessionState,
+    at: Timestamp,
+  ) -> Result<Session, SessionError> {
+    self
+      .update_session(id, |session| session.transition_to(to, at))
+      .await
+  }
+
+  /// Soft-delete a stored session and notify subscribers
+  ///
+  /// The session is cancelled and marked deleted but stays in the store.
+  ///
+  /// # Errors
+  ///
+  /// Returns `SessionError::NotFound` if no session has this id
+  /// Returns `SessionError::InvalidStateTransition` if the session cannot be cancelled
+  /// Returns `SessionError::StaleUpdate` if the session changed concurrently
+  pub async fn soft_delete_session(
+    &self,
+    id: &SessionId,
+    at: Timestamp,
+  ) -> Result<Session, SessionError> {
+    self
+      .update_session(id, |session| session.soft_delete(at))
+      .await
+  }
+
+  /// Remove a stored session entirely
+  ///
+  /// # Errors
+  ///
+  /// Returns `SessionError::NotFound` if no session has this id
+  pub async fn purge_session(&self, id: &SessionId) -> Result<Session, SessionError> {
+    self.sessions.remove(id).await
+  }
+
+  /// Insert a bead into `pool` and notify subscribers
+  ///
+  /// # Errors
+  ///
+  /// Returns the repository's `DbError` if the insert fails
+  pub async fn create_bead(&self, pool: &SqlitePool, bead: &NewBead) -> DbResult<Bead> {
+    let created = repository::create_bead(pool, bead).await?;
+    metrics::counter!(BEADS_CREATED).increment(1);
+    self.publish_bead(BeadEvent::Created(created.clone()));
+    Ok(created)
+  }
+
+  /// Insert beads into `pool` in one transaction and notify subscribers
+  ///
+  /// Subscribers hear about the beads only once all of them are stored.
+  ///
+  /// # Errors
+  ///
+  /// Returns the repository's `DbError` if any insert fails; nothing is stored then
+  pub async fn create_beads(&self, pool: &SqlitePool, beads: &[NewBead]) -> DbResult<Vec<Bead>> {
+    let created = repository::create_beads(pool, beads).await?;
+    metrics::counter!(BEADS_CREATED).increment(created.len() as u64);
+    for bead in &created {
+      self.publish_bead(BeadEvent::Created(bead.clone()));
+    }
+    Ok(created)
+  }
+
+  /// Replace a bead's fields in `pool` and notify subscribers
+  ///
+  /// # Errors
+  ///
+  /// Returns `DbError::NotFound` if no bead has this id
+  /// Returns a `DbError::Connection` if the update fails
+  pub async fn update_bead(
+    &self,
+    pool: &SqlitePool,
+    id: &BeadId,
+    bead: &NewBead,
+  ) -> DbResult<Bead> {
+    let updated = repository::update_bead(pool, id, bead).await?;
+    self.publish_bead(BeadEvent::Updated(updated.clone()));
+    Ok(updated)
+  }
+
+  /// Delete a bead from `pool` and notify subscribers
+  ///
+  /// Returns the bead as it was before deletion.
+  ///
+  /// # Errors
+  ///
+  /// Returns `DbError::NotFound` if no bead has this id
+  /// Returns a `DbError::Connection` if the delete fails
+  pub async fn delete_bead(&self, pool: &SqlitePool, id: &BeadId) -> DbResult<Bead> {
+    let deleted = repository::get_bead(pool, id).await?;
+    repository::delete_bead(pool, id).await?;
+    self.publish_bead(BeadEvent::Deleted(deleted.clone()));
+    Ok(deleted)
+  }
+
+  fn publish_bead(&self, event: BeadEvent) {
+    // Sending only fails when nobody is subscribed, which is fine
+    let _ = self.bead_events.send(event);
+  }
+
+  /// Replace a stored session with `update` applied to it and publish the result
+  ///
+  /// Fails with `SessionError::StaleUpdate` if another request changed the
+  /// session between the read and the write.
+  async fn update_session(
+    &self,
+    id: &SessionId,
+    update: impl FnOnce(&Session) -> Result<Session, SessionError>,
+  ) -> Result<Session, SessionError> {
+    let current = self.sessions.get_versioned(id).await?;
+    let updated = self
+      .sessions
+      .update(update(&current.session)?, current.version)
+      .await?;
+
+    // Sending only fails when nobody is subscribed, which is fine
+    let _ = self.session_events.send(updated.clone());
+    Ok(updated)
+  }
+}
@@ -0,0 +1,88 @@
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![forbid(unsafe_code)]
+
+//! Prometheus metrics
+//!
+//! [`install`] sets up the process-wide recorder. Once its handle is attached
+//! with `AppState::with_metrics`, [`track_requests`] counts and times every API
+//! request and `GET /metrics` renders all metrics in the Prometheus text format.
+
+use axum::{
+  extract::{MatchedPath, Request},
+  middleware::Next,
+  response::Response,
+};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+use std::sync::OnceLock;
+use std::time::Instant;
+
+/// Counter of handled requests, labelled by method, route and status
+pub const REQUESTS_TOTAL: &str = "clarity_http_requests_total";
+
+/// Histogram of request latency in seconds, labelled by method and route
+pub const REQUEST_DURATION: &str = "clarity_http_request_duration_seconds";
+
+/// Counter of beads stored through `AppState`
+pub const BEADS_CREATED: &str = "clarity_beads_created_total";
+
+/// Gauge of stored sessions, labelled by state
+pub const SESSIONS: &str = "clarity_sessions";
+
+/// Route serving the metrics, which is not itself counted
+pub const METRICS_ROUTE: &str = "/metrics";
+
+/// Upper bounds of the latency histogram buckets, in seconds
+const LATENCY_BUCKETS: &[f64] = &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0];
+
+/// Install the global Prometheus recorder, returning a handle that renders it
+///
+/// Only the first call installs anything; later calls return the same handle.
+/// Returns `None`, after logging why, if the recorder could not be installed.
+pub fn install() -> Option<PrometheusHandle> {
+  static HANDLE: OnceLock<Option<PrometheusHandle>> = OnceLock::new();
+
+  HANDLE
+    .get_or_init(|| {
+      PrometheusBuilder::new()
+        .set_buckets_for_metric(Matcher::Full(REQUEST_DURATION.to_string()), LATENCY_BUCKETS)
+        .and_then(PrometheusBuilder::install_recorder)
+        .map_err(|e| tracing::warn!("metrics are disabled: {e}"))
+        .ok()
+    })
+    .clone()
+}
+
+/// Record the route, status and latency of a request
+///
+/// Requests are labelled by their route template, e.g. `/beads/{id}`, so the
+/// number of series stays bounded; requests that matched no route share the
+/// `unmatched` label.
+pub async fn track_requests(request: Request, next: Next) -> Response {
+  let route = request
+    .extensions()
+    .get::<MatchedPath>()
+    .map_or("unmatched", MatchedPath::as_str)
+    .to_string();
+  if route == METRICS_ROUTE {
+    return next.run(request).await;
+  }
+
+  let method = request.method().to_string();
+  let started = Instant::now();
+  let response = next.run(request).await;
+
+  metrics::histogram!(REQUEST_DURATION, "method" => method.clone(), "route" => route.clone())
+    .record(started.elapsed().as_secs_f64());
+  metrics::counter!(
+    REQUESTS_TOTAL,
+    "method" => method,
+    "route" => route,
+    "status" => response.status().as_u16().to_string(),
+  )
+  .increment(1);
+  response
+}
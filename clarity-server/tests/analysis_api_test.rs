@@ -0,0 +1,84 @@
+#![allow(clippy::disallowed_methods)]
+#![allow(clippy::panic)]
+
+//! Integration tests for the analysis result endpoint
+
+use axum::{
+  body::{to_bytes, Body},
+  http::{Request, StatusCode},
+};
+use clarity_core::analysis::{AnalysisId, AnalysisResult, Finding, Timestamp};
+use clarity_core::quality::{QualityScore, Severity};
+use clarity_server::{api, AppState};
+use serde_json::Value;
+use tower::ServiceExt;
+
+const ANALYSIS_ID: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+async fn state_with_analysis() -> AppState {
+  let id = match AnalysisId::new(ANALYSIS_ID.to_string()) {
+    Ok(id) => id,
+    Err(e) => panic!("Invalid analysis id: {e}"),
+  };
+  let score = match QualityScore::new(80) {
+    Ok(score) => score,
+    Err(e) => panic!("Invalid score: {e}"),
+  };
+  let result = AnalysisResult::new(id, "petstore", score, Timestamp::from_secs(1_000))
+    .with_finding(Finding::new(Severity::Warning, "no examples given").at("paths./pets"));
+
+  let state = AppState::new();
+  state
+    .analyses
+    .write()
+    .await
+    .insert(result.id.clone(), result);
+  state
+}
+
+async fn get_json(state: AppState, uri: &str) -> (StatusCode, Value) {
+  let request = match Request::builder().uri(uri).body(Body::empty()) {
+    Ok(request) => request,
+    Err(e) => panic!("Failed to build request: {e}"),
+  };
+  let response = match api::router(state).oneshot(request).await {
+    Ok(response) => response,
+    Err(e) => panic!("Request failed: {e}"),
+  };
+  let status = response.status();
+  let bytes = match to_bytes(response.into_body(), usize::MAX).await {
+    Ok(bytes) => bytes,
+    Err(e) => panic!("Failed to read body: {e}"),
+  };
+  match serde_json::from_slice(&bytes) {
+    Ok(json) => (status, json),
+    Err(e) => panic!("Response body is not JSON: {e}"),
+  }
+}
+
+#[tokio::test]
+async fn test_get_analysis_returns_result() {
+  let (status, body) = get_json(
+    state_with_analysis().await,
+    &format!("/analysis/{ANALYSIS_ID}"),
+  )
+  .await;
+
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(body["id"], ANALYSIS_ID);
+  assert_eq!(body["spec_name"], "petstore");
+  assert_eq!(body["score"], 80);
+  assert_eq!(body["findings"][0]["severity"], "warning");
+  assert_eq!(body["findings"][0]["location"], "paths./pets");
+}
+
+#[tokio::test]
+async fn test_get_analysis_errors() {
+  let (status, body) = get_json(AppState::new(), "/analysis/not-a-uuid").await;
+  assert_eq!(status, StatusCode::BAD_REQUEST);
+  assert_eq!(body["error"]["code"], "invalid_id");
+
+  let (status, body) = get_json(AppState::new(), &format!("/analysis/{ANALYSIS_ID}")).await;
+  assert_eq!(status, StatusCode::NOT_FOUND);
+  assert_eq!(body["error"]["code"], "not_found");
+}
@@ -0,0 +1,83 @@
+#![allow(clippy::disallowed_methods)]
+#![allow(clippy::panic)]
+
+//! Tests for the embedded static assets
+
+use axum::{
+  body::{to_bytes, Body},
+  http::{header, Request, StatusCode},
+};
+use clarity_server::assets;
+use tower::ServiceExt;
+
+async fn get_css(if_none_match: Option<&str>) -> (StatusCode, header::HeaderMap, Vec<u8>) {
+  let mut request = Request::builder().uri("/assets/responsive.css");
+  if let Some(etag) = if_none_match {
+    request = request.header(header::IF_NONE_MATCH, etag);
+  }
+  let request = match request.body(Body::empty()) {
+    Ok(request) => request,
+    Err(e) => panic!("Failed to build request: {e}"),
+  };
+
+  let response = match assets::router().oneshot(request).await {
+    Ok(response) => response,
+    Err(e) => panic!("Request failed: {e}"),
+  };
+  let status = response.status();
+  let headers = response.headers().clone();
+  match to_bytes(response.into_body(), usize::MAX).await {
+    Ok(bytes) => (status, headers, bytes.to_vec()),
+    Err(e) => panic!("Failed to read body: {e}"),
+  }
+}
+
+#[tokio::test]
+async fn test_css_is_served_regardless_of_cwd() {
+  if let Err(e) = std::env::set_current_dir(std::env::temp_dir()) {
+    panic!("Failed to change directory: {e}");
+  }
+
+  let (status, headers, body) = get_css(None).await;
+
+  assert_eq!(status, StatusCode::OK);
+  assert!(!body.is_empty());
+  assert_eq!(
+    headers.get(header::CONTENT_TYPE).map(|v| v.as_bytes()),
+    Some(&b"text/css; charset=utf-8"[..])
+  );
+  assert_eq!(
+    headers.get(header::ETAG).map(|v| v.as_bytes()),
+    Some(assets::css_etag().as_bytes())
+  );
+  assert!(headers.contains_key(header::CACHE_CONTROL));
+}
+
+#[tokio::test]
+async fn test_css_matching_etag_is_not_modified() {
+  let (status, _, body) = get_css(Some(assets::css_etag())).await;
+
+  assert_eq!(status, StatusCode::NOT_MODIFIED);
+  assert!(body.is_empty());
+}
+
+#[tokio::test]
+async fn test_css_weak_or_listed_etag_is_not_modified() {
+  let listed = format!("\"stale\", W/{}", assets::css_etag());
+  let (status, _, body) = get_css(Some(&listed)).await;
+
+  assert_eq!(status, StatusCode::NOT_MODIFIED);
+  assert!(body.is_empty());
+}
+
+#[tokio::test]
+async fn test_css_stale_etag_gets_full_response() {
+  let (status, headers, body) = get_css(Some("\"0000000000000000\"")).await;
+
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(body, assets::CSS.as_bytes());
+  assert_eq!(
+    headers.get(header::ETAG).map(|v| v.as_bytes()),
+    Some(assets::css_etag().as_bytes())
+  );
+}
@@ -0,0 +1,101 @@
+#![allow(clippy::disallowed_methods)]
+#![allow(clippy::panic)]
+
+//! Tests for bearer-token authentication of mutating endpoints
+
+use axum::{
+  body::{to_bytes, Body},
+  http::{header, Request, StatusCode},
+  middleware,
+  routing::get,
+  Extension, Router,
+};
+use clarity_server::auth::{self, AuthConfig, Principal};
+use clarity_server::{api, AppState};
+use serde_json::Value;
+use tower::ServiceExt;
+
+const TOKEN: &str = "s3cret";
+const ANSWERS_URI: &str = "/interviews/550e8400-e29b-41d4-a716-446655440000/answers";
+
+fn app() -> Router {
+  api::router(AppState::new().with_auth(AuthConfig::new().with_token("ci", TOKEN)))
+}
+
+async fn send(app: Router, method: &str, uri: &str, token: Option<&str>) -> (StatusCode, Value) {
+  let mut builder = Request::builder()
+    .method(method)
+    .uri(uri)
+    .header(header::CONTENT_TYPE, "application/json");
+  if let Some(token) = token {
+    builder = builder.header(header::AUTHORIZATION, format!("Bearer {token}"));
+  }
+  let request = match builder.body(Body::from(
+    r#"{"question_index": 0, "value": {"type": "text", "value": "Ada"}}"#,
+  )) {
+    Ok(request) => request,
+    Err(e) => panic!("Failed to build request: {e}"),
+  };
+
+  let response = match app.oneshot(request).await {
+    Ok(response) => response,
+    Err(e) => panic!("Request failed: {e}"),
+  };
+  let status = response.status();
+  let bytes = match to_bytes(response.into_body(), usize::MAX).await {
+    Ok(bytes) => bytes,
+    Err(e) => panic!("Failed to read body: {e}"),
+  };
+  (
+    status,
+    serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+  )
+}
+
+#[tokio::test]
+async fn test_missing_header_is_unauthorized() {
+  let (status, body) = send(app(), "POST", ANSWERS_URI, None).await;
+
+  assert_eq!(status, StatusCode::UNAUTHORIZED);
+  assert_eq!(body["error"]["code"], "unauthorized");
+}
+
+#[tokio::test]
+async fn test_wrong_token_is_unauthorized() {
+  let (status, _) = send(app(), "POST", ANSWERS_URI, Some("guess")).await;
+
+  assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_valid_token_reaches_handler() {
+  // The interview does not exist, so getting past auth means a 404
+  let (status, _) = send(app(), "POST", ANSWERS_URI, Some(TOKEN)).await;
+
+  assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_health_is_public() {
+  let (status, _) = send(app(), "GET", "/health", None).await;
+
+  assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_principal_is_available_to_handlers() {
+  let config = AuthConfig::new().with_token("ci", TOKEN).into_shared();
+  let app = Router::new()
+    .route(
+      "/whoami",
+      get(|Extension(principal): Extension<Principal>| async move {
+        axum::Json(serde_json::json!({ "principal": principal.name() }))
+      }),
+    )
+    .layer(middleware::from_fn_with_state(config, auth::require_token));
+
+  let (status, body) = send(app, "GET", "/whoami", Some(TOKEN)).await;
+
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(body["principal"], "ci");
+}
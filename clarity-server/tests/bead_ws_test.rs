@@ -0,0 +1,119 @@
+#![allow(clippy::disallowed_methods)]
+#![allow(clippy::panic)]
+
+//! Integration tests for the live bead update WebSocket
+
+use clarity_core::db::{
+  create_sqlite_pool, run_sqlite_migrations, BeadPriority, BeadStatus, BeadType, NewBead,
+  SqliteDbConfig,
+};
+use clarity_server::{api, AppState};
+use futures::{SinkExt, StreamExt};
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+async fn serve() -> (AppState, SqlitePool, Socket) {
+  // A single connection keeps every query on the same in-memory database
+  let config = SqliteDbConfig::in_memory().with_max_connections(1);
+  let pool = match create_sqlite_pool(&config).await {
+    Ok(pool) => pool,
+    Err(e) => panic!("Failed to create pool: {e}"),
+  };
+  if let Err(e) = run_sqlite_migrations(&pool).await {
+    panic!("Failed to run migrations: {e}");
+  }
+  let state = AppState::new().with_db(pool.clone());
+
+  let listener = match TcpListener::bind("127.0.0.1:0").await {
+    Ok(listener) => listener,
+    Err(e) => panic!("Failed to bind: {e}"),
+  };
+  let addr = match listener.local_addr() {
+    Ok(addr) => addr,
+    Err(e) => panic!("Failed to read address: {e}"),
+  };
+  let app = api::router(state.clone());
+  tokio::spawn(async move { axum::serve(listener, app).await });
+
+  match connect_async(format!("ws://{addr}/beads/ws")).await {
+    Ok((socket, _)) => (state, pool, socket),
+    Err(e) => panic!("Failed to connect: {e}"),
+  }
+}
+
+fn new_bead(title: &str, status: BeadStatus) -> NewBead {
+  NewBead {
+    title: title.to_string(),
+    description: None,
+    status,
+    priority: BeadPriority::MEDIUM,
+    bead_type: BeadType::Feature,
+    created_by: None,
+  }
+}
+
+async fn next_json(socket: &mut Socket) -> Value {
+  let message = match tokio::time::timeout(Duration::from_secs(5), socket.next()).await {
+    Ok(Some(Ok(message))) => message,
+    Ok(other) => panic!("Socket ended unexpectedly: {other:?}"),
+    Err(_) => panic!("Timed out waiting for a message"),
+  };
+  match message {
+    Message::Text(text) => match serde_json::from_str(&text) {
+      Ok(json) => json,
+      Err(e) => panic!("Message is not JSON: {e}"),
+    },
+    other => panic!("Expected a text message, got {other:?}"),
+  }
+}
+
+async fn send_text(socket: &mut Socket, text: &str) {
+  if let Err(e) = socket.send(Message::text(text)).await {
+    panic!("Failed to send message: {e}");
+  }
+}
+
+#[tokio::test]
+async fn test_creating_a_bead_emits_a_message() {
+  let (state, pool, mut socket) = serve().await;
+
+  let created = match state
+    .create_bead(&pool, &new_bead("Live", BeadStatus::Open))
+    .await
+  {
+    Ok(bead) => bead,
+    Err(e) => panic!("Failed to create bead: {e}"),
+  };
+
+  let message = next_json(&mut socket).await;
+  assert_eq!(message["event"], "created");
+  assert_eq!(message["bead"]["id"], created.id.to_string());
+  assert_eq!(message["bead"]["title"], "Live");
+}
+
+#[tokio::test]
+async fn test_status_filter_scopes_the_stream() {
+  let (state, pool, mut socket) = serve().await;
+
+  send_text(&mut socket, r#"{"status":"closed"}"#).await;
+  // Messages are handled in order, so once the bad filter is rejected the
+  // good one is in effect
+  send_text(&mut socket, r#"{"status":"nope"}"#).await;
+  let rejected = next_json(&mut socket).await;
+  assert_eq!(rejected["error"]["code"], "invalid_parameter");
+
+  for (title, status) in [("Open", BeadStatus::Open), ("Closed", BeadStatus::Closed)] {
+    if let Err(e) = state.create_bead(&pool, &new_bead(title, status)).await {
+      panic!("Failed to create bead: {e}");
+    }
+  }
+
+  let message = next_json(&mut socket).await;
+  assert_eq!(message["event"], "created");
+  assert_eq!(message["bead"]["title"], "Closed");
+}
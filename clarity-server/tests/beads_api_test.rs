@@ -0,0 +1,333 @@
+#![allow(clippy::disallowed_methods)]
+#![allow(clippy::panic)]
+
+//! Integration tests for the beads API endpoint
+
+use axum::{
+  body::{to_bytes, Body},
+  http::{header, Request, StatusCode},
+  Router,
+};
+use clarity_core::db::{
+  add_bead_dependency, create_bead, create_sqlite_pool, run_sqlite_migrations, Bead,
+  BeadDependency, BeadPriority, BeadStatus, BeadType, NewBead, SqliteDbConfig,
+};
+use clarity_server::{api, AppState};
+use serde_json::Value;
+use sqlx::SqlitePool;
+use tower::ServiceExt;
+
+async fn app_with_beads(statuses: &[BeadStatus]) -> Router {
+  let beads = statuses
+    .iter()
+    .enumerate()
+    .map(|(i, status)| NewBead {
+      title: format!("Bead {i}"),
+      description: None,
+      status: *status,
+      priority: BeadPriority::MEDIUM,
+      bead_type: BeadType::Feature,
+      created_by: None,
+    })
+    .collect();
+  app_with(beads).await
+}
+
+async fn migrated_pool() -> SqlitePool {
+  // A single connection keeps every query on the same in-memory database
+  let config = SqliteDbConfig::in_memory().with_max_connections(1);
+  let pool = match create_sqlite_pool(&config).await {
+    Ok(pool) => pool,
+    Err(e) => panic!("Failed to create pool: {e}"),
+  };
+  if let Err(e) = run_sqlite_migrations(&pool).await {
+    panic!("Failed to run migrations: {e}");
+  }
+  pool
+}
+
+async fn seed(pool: &SqlitePool, bead: &NewBead) -> Bead {
+  match create_bead(pool, bead).await {
+    Ok(bead) => bead,
+    Err(e) => panic!("Failed to seed bead: {e}"),
+  }
+}
+
+async fn app_with(beads: Vec<NewBead>) -> Router {
+  let pool = migrated_pool().await;
+  for bead in beads {
+    seed(&pool, &bead).await;
+  }
+
+  api::router(AppState::new().with_db(pool))
+}
+
+async fn get_json(app: Router, uri: &str) -> (StatusCode, Value) {
+  let request = match Request::builder().uri(uri).body(Body::empty()) {
+    Ok(request) => request,
+    Err(e) => panic!("Failed to build request: {e}"),
+  };
+
+  send(app, request).await
+}
+
+async fn post_json(app: Router, uri: &str, body: &Value) -> (StatusCode, Value) {
+  let request = match Request::builder()
+    .method("POST")
+    .uri(uri)
+    .header(header::CONTENT_TYPE, "application/json")
+    .body(Body::from(body.to_string()))
+  {
+    Ok(request) => request,
+    Err(e) => panic!("Failed to build request: {e}"),
+  };
+
+  send(app, request).await
+}
+
+async fn send(app: Router, request: Request<Body>) -> (StatusCode, Value) {
+  let response = match app.oneshot(request).await {
+    Ok(response) => response,
+    Err(e) => panic!("Request failed: {e}"),
+  };
+  let status = response.status();
+  let bytes = match to_bytes(response.into_body(), usize::MAX).await {
+    Ok(bytes) => bytes,
+    Err(e) => panic!("Failed to read body: {e}"),
+  };
+  match serde_json::from_slice(&bytes) {
+    Ok(json) => (status, json),
+    Err(e) => panic!("Response body is not JSON: {e}"),
+  }
+}
+
+#[tokio::test]
+async fn test_list_beads_filters_and_pages() {
+  let app = app_with_beads(&[BeadStatus::Open, BeadStatus::Closed, BeadStatus::Open]).await;
+
+  let (status, first) = get_json(app.clone(), "/beads?status=open&limit=1").await;
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(first["items"].as_array().map(Vec::len), Some(1));
+  assert_eq!(first["items"][0]["title"], "Bead 2");
+  let cursor = match first["next_cursor"].as_str() {
+    Some(cursor) => cursor.to_string(),
+    None => panic!("Expected a next_cursor"),
+  };
+
+  let (status, second) =
+    get_json(app, &format!("/beads?status=open&limit=1&cursor={cursor}")).await;
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(second["items"][0]["title"], "Bead 0");
+  assert!(second["next_cursor"].is_null());
+}
+
+async fn app_with_priorities(priorities: &[BeadPriority]) -> Router {
+  let beads = priorities
+    .iter()
+    .enumerate()
+    .map(|(i, priority)| NewBead {
+      title: format!("Bead {i}"),
+      description: None,
+      status: BeadStatus::Open,
+      priority: *priority,
+      bead_type: BeadType::Feature,
+      created_by: None,
+    })
+    .collect();
+  app_with(beads).await
+}
+
+fn titles(page: &Value) -> Vec<&str> {
+  page["items"]
+    .as_array()
+    .map(|items| {
+      items
+        .iter()
+        .filter_map(|bead| bead["title"].as_str())
+        .collect()
+    })
+    .unwrap_or_default()
+}
+
+#[tokio::test]
+async fn test_sort_by_priority_pages_in_order() {
+  let app = app_with_priorities(&[
+    BeadPriority::LOW,
+    BeadPriority::HIGH,
+    BeadPriority::MEDIUM,
+    BeadPriority::HIGH,
+  ])
+  .await;
+
+  let (status, first) = get_json(app.clone(), "/beads?sort=priority&order=asc&limit=2").await;
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(titles(&first), vec!["Bead 1", "Bead 3"]);
+  let cursor = match first["next_cursor"].as_str() {
+    Some(cursor) => cursor.to_string(),
+    None => panic!("Expected a next_cursor"),
+  };
+
+  let (_, second) = get_json(
+    app,
+    &format!("/beads?sort=priority&order=asc&limit=2&cursor={cursor}"),
+  )
+  .await;
+  assert_eq!(titles(&second), vec!["Bead 2", "Bead 0"]);
+}
+
+#[tokio::test]
+async fn test_sort_descending() {
+  let app =
+    app_with_priorities(&[BeadPriority::HIGH, BeadPriority::LOW, BeadPriority::MEDIUM]).await;
+
+  let (status, page) = get_json(app.clone(), "/beads?sort=priority&order=desc").await;
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(titles(&page), vec!["Bead 1", "Bead 2", "Bead 0"]);
+
+  let (_, page) = get_json(app, "/beads?order=asc").await;
+  assert_eq!(titles(&page), vec!["Bead 0", "Bead 1", "Bead 2"]);
+}
+
+#[tokio::test]
+async fn test_unknown_sort_field_returns_bad_request() {
+  let app = app_with_beads(&[BeadStatus::Open]).await;
+
+  let (status, body) = get_json(app, "/beads?sort=title;DROP%20TABLE%20beads").await;
+
+  assert_eq!(status, StatusCode::BAD_REQUEST);
+  assert_eq!(body["error"]["code"], "invalid_parameter");
+  assert_eq!(
+    body["error"]["message"],
+    "sort: Validation error: Invalid sort field: title;DROP TABLE beads"
+  );
+}
+
+#[tokio::test]
+async fn test_invalid_status_returns_bad_request() {
+  let app = app_with_beads(&[BeadStatus::Open]).await;
+
+  let (status, body) = get_json(app, "/beads?status=bogus").await;
+
+  assert_eq!(status, StatusCode::BAD_REQUEST);
+  assert_eq!(body["error"]["code"], "invalid_parameter");
+  assert_eq!(
+    body["error"]["message"],
+    "status: Validation error: Invalid bead status: bogus"
+  );
+}
+
+#[tokio::test]
+async fn test_invalid_priority_returns_bad_request() {
+  let app = app_with_beads(&[BeadStatus::Open]).await;
+
+  let (status, body) = get_json(app, "/beads?priority=7").await;
+
+  assert_eq!(status, StatusCode::BAD_REQUEST);
+  assert_eq!(body["error"]["code"], "invalid_parameter");
+}
+
+#[tokio::test]
+async fn test_unknown_bead_returns_not_found_envelope() {
+  let app = app_with_beads(&[BeadStatus::Open]).await;
+
+  let (status, body) = get_json(app, "/beads/550e8400-e29b-41d4-a716-446655440000").await;
+
+  assert_eq!(status, StatusCode::NOT_FOUND);
+  assert_eq!(body["error"]["code"], "not_found");
+  assert!(body["error"]["message"].is_string());
+}
+
+#[tokio::test]
+async fn test_get_bead_with_malformed_id_returns_bad_request() {
+  let app = app_with_beads(&[]).await;
+
+  let (status, body) = get_json(app, "/beads/not-a-uuid").await;
+
+  assert_eq!(status, StatusCode::BAD_REQUEST);
+  assert_eq!(body["error"]["code"], "validation_error");
+}
+
+fn new_bead(title: &str, priority: i16) -> Value {
+  serde_json::json!({
+    "title": title,
+    "description": null,
+    "status": "Open",
+    "priority": priority,
+    "bead_type": "Feature",
+    "created_by": null,
+  })
+}
+
+#[tokio::test]
+async fn test_batch_creates_every_bead() {
+  let app = app_with_beads(&[]).await;
+  let batch = serde_json::json!([new_bead("First", 1), new_bead("Second", 3)]);
+
+  let (status, body) = post_json(app.clone(), "/beads/batch", &batch).await;
+
+  assert_eq!(status, StatusCode::CREATED);
+  assert_eq!(body[0]["title"], "First");
+  assert_eq!(body[1]["title"], "Second");
+  let (_, page) = get_json(app, "/beads").await;
+  assert_eq!(page["items"].as_array().map(Vec::len), Some(2));
+}
+
+#[tokio::test]
+async fn test_batch_with_invalid_bead_is_rolled_back() {
+  let app = app_with_beads(&[]).await;
+  let batch = serde_json::json!([new_bead("Valid", 1), new_bead("Bad priority", 7)]);
+
+  let (status, body) = post_json(app.clone(), "/beads/batch", &batch).await;
+
+  assert_eq!(status, StatusCode::BAD_REQUEST);
+  assert_eq!(body["error"]["code"], "validation_error");
+  let (_, page) = get_json(app, "/beads").await;
+  assert_eq!(page["items"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn test_oversized_batch_is_rejected() {
+  let app = app_with_beads(&[]).await;
+  let batch = Value::Array(vec![new_bead("Bead", 2); 501]);
+
+  let (status, body) = post_json(app, "/beads/batch", &batch).await;
+
+  assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+  assert_eq!(body["error"]["code"], "batch_too_large");
+}
+
+#[tokio::test]
+async fn test_blocked_beads_lists_beads_with_open_dependencies() {
+  let pool = migrated_pool().await;
+  let bead = |title: &str, status| NewBead {
+    title: title.to_string(),
+    description: None,
+    status,
+    priority: BeadPriority::MEDIUM,
+    bead_type: BeadType::Feature,
+    created_by: None,
+  };
+  let open = seed(&pool, &bead("open dependency", BeadStatus::Open)).await;
+  let closed = seed(&pool, &bead("closed dependency", BeadStatus::Closed)).await;
+  let blocked = seed(&pool, &bead("blocked", BeadStatus::Open)).await;
+  let unblocked = seed(&pool, &bead("unblocked", BeadStatus::Open)).await;
+  for (bead_id, depends_on) in [(blocked.id, open.id), (unblocked.id, closed.id)] {
+    let dependency = BeadDependency {
+      bead_id,
+      depends_on,
+    };
+    if let Err(e) = add_bead_dependency(&pool, &dependency).await {
+      panic!("Failed to seed dependency: {e}");
+    }
+  }
+  let app = api::router(AppState::new().with_db(pool));
+
+  let (status, body) = get_json(app, "/beads/blocked").await;
+
+  assert_eq!(status, StatusCode::OK);
+  let titles: Vec<&str> = body
+    .as_array()
+    .map(|beads| beads.iter().filter_map(|b| b["title"].as_str()).collect())
+    .unwrap_or_default();
+  assert_eq!(titles, vec!["blocked"]);
+}
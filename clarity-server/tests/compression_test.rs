@@ -0,0 +1,90 @@
+#![allow(clippy::disallowed_methods)]
+#![allow(clippy::panic)]
+
+//! Tests for response compression
+
+use axum::{
+  body::Body,
+  http::{header, HeaderMap, Request},
+  Router,
+};
+use clarity_core::session::{Session, SessionKind, Timestamp};
+use clarity_server::compression::with_compression;
+use clarity_server::session_store::SessionStore;
+use clarity_server::{api, assets, AppState};
+use tower::ServiceExt;
+
+const SESSION_ID: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+async fn response_headers(app: Router, uri: &str, accept_encoding: &str) -> HeaderMap {
+  let request = match Request::builder()
+    .uri(uri)
+    .header(header::ACCEPT_ENCODING, accept_encoding)
+    .body(Body::empty())
+  {
+    Ok(request) => request,
+    Err(e) => panic!("Failed to build request: {e}"),
+  };
+
+  match with_compression(app).oneshot(request).await {
+    Ok(response) => response.headers().clone(),
+    Err(e) => panic!("Request failed: {e}"),
+  }
+}
+
+#[tokio::test]
+async fn test_css_is_gzipped() {
+  let headers = response_headers(assets::router(), "/assets/responsive.css", "gzip").await;
+
+  assert_eq!(
+    headers
+      .get(header::CONTENT_ENCODING)
+      .and_then(|v| v.to_str().ok()),
+    Some("gzip")
+  );
+}
+
+#[tokio::test]
+async fn test_css_is_brotli_encoded() {
+  let headers = response_headers(assets::router(), "/assets/responsive.css", "br").await;
+
+  assert_eq!(
+    headers
+      .get(header::CONTENT_ENCODING)
+      .and_then(|v| v.to_str().ok()),
+    Some("br")
+  );
+}
+
+#[tokio::test]
+async fn test_small_responses_are_not_compressed() {
+  let headers = response_headers(api::router(AppState::new()), "/health", "gzip").await;
+
+  assert!(headers.get(header::CONTENT_ENCODING).is_none());
+}
+
+#[tokio::test]
+async fn test_event_streams_are_not_compressed() {
+  let session = match Session::builder()
+    .id(SESSION_ID.to_string())
+    .kind(SessionKind::Analysis)
+    .created_at(Timestamp::from_secs(1_000))
+    .build()
+  {
+    Ok(session) => session,
+    Err(e) => panic!("Failed to build session: {e}"),
+  };
+  let state = AppState::new();
+  if let Err(e) = state.sessions.create(session).await {
+    panic!("Expected session to be stored, got {e}");
+  }
+
+  let headers = response_headers(
+    api::router(state),
+    &format!("/sessions/{SESSION_ID}/events"),
+    "gzip",
+  )
+  .await;
+
+  assert!(headers.get(header::CONTENT_ENCODING).is_none());
+}
@@ -0,0 +1,103 @@
+#![allow(clippy::disallowed_methods)]
+#![allow(clippy::panic)]
+
+//! Tests for the API's CORS policy
+
+use axum::{
+  body::Body,
+  http::{header, HeaderMap, Method, Request},
+};
+use clarity_server::cors::CorsConfig;
+use clarity_server::{api, AppState};
+use tower::ServiceExt;
+
+const ALLOWED: &str = "https://app.clarity.test";
+
+async fn send(request: Request<Body>) -> HeaderMap {
+  let cors = match CorsConfig::new(vec![ALLOWED.to_string()]).layer() {
+    Ok(cors) => cors,
+    Err(e) => panic!("Failed to build CORS layer: {e}"),
+  };
+  let app = api::router(AppState::new()).layer(cors);
+
+  match app.oneshot(request).await {
+    Ok(response) => response.headers().clone(),
+    Err(e) => panic!("Request failed: {e}"),
+  }
+}
+
+async fn preflight(origin: &str) -> HeaderMap {
+  preflight_with_headers(origin, "GET", "").await
+}
+
+async fn preflight_with_headers(origin: &str, method: &str, headers: &str) -> HeaderMap {
+  let mut request = Request::builder()
+    .method(Method::OPTIONS)
+    .uri("/health")
+    .header(header::ORIGIN, origin)
+    .header(header::ACCESS_CONTROL_REQUEST_METHOD, method);
+  if !headers.is_empty() {
+    request = request.header(header::ACCESS_CONTROL_REQUEST_HEADERS, headers);
+  }
+
+  match request.body(Body::empty()) {
+    Ok(request) => send(request).await,
+    Err(e) => panic!("Failed to build request: {e}"),
+  }
+}
+
+fn header_list(headers: &HeaderMap, name: header::HeaderName) -> Vec<String> {
+  headers
+    .get_all(name)
+    .iter()
+    .filter_map(|value| value.to_str().ok())
+    .flat_map(|value| value.split(','))
+    .map(|value| value.trim().to_ascii_lowercase())
+    .collect()
+}
+
+#[tokio::test]
+async fn test_preflight_allows_configured_origin() {
+  let headers = preflight(ALLOWED).await;
+
+  assert_eq!(
+    headers
+      .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+      .map(|v| v.as_bytes()),
+    Some(ALLOWED.as_bytes())
+  );
+}
+
+#[tokio::test]
+async fn test_preflight_rejects_other_origin() {
+  let headers = preflight("https://evil.test").await;
+
+  assert!(headers.get(header::ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+}
+
+#[tokio::test]
+async fn test_preflight_allows_bearer_auth() {
+  let headers = preflight_with_headers(ALLOWED, "POST", "authorization,content-type").await;
+
+  let allowed = header_list(&headers, header::ACCESS_CONTROL_ALLOW_HEADERS);
+  assert!(allowed.contains(&"authorization".to_string()));
+  assert!(allowed.contains(&"content-type".to_string()));
+}
+
+#[tokio::test]
+async fn test_response_exposes_request_id_and_retry_after() {
+  let request = match Request::builder()
+    .uri("/health")
+    .header(header::ORIGIN, ALLOWED)
+    .body(Body::empty())
+  {
+    Ok(request) => request,
+    Err(e) => panic!("Failed to build request: {e}"),
+  };
+
+  let headers = send(request).await;
+
+  let exposed = header_list(&headers, header::ACCESS_CONTROL_EXPOSE_HEADERS);
+  assert!(exposed.contains(&"x-request-id".to_string()));
+  assert!(exposed.contains(&"retry-after".to_string()));
+}
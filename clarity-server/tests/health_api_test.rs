@@ -0,0 +1,81 @@
+#![allow(clippy::disallowed_methods)]
+#![allow(clippy::panic)]
+
+//! Integration tests for the health endpoint
+
+use axum::{
+  body::{to_bytes, Body},
+  http::{Request, StatusCode},
+  Router,
+};
+use clarity_core::db::{create_sqlite_pool, SqliteDbConfig};
+use clarity_server::{api, AppState};
+use serde_json::Value;
+use tower::ServiceExt;
+
+async fn get_health(app: Router) -> (StatusCode, Value) {
+  let request = match Request::builder().uri("/health").body(Body::empty()) {
+    Ok(request) => request,
+    Err(e) => panic!("Failed to build request: {e}"),
+  };
+
+  let response = match app.oneshot(request).await {
+    Ok(response) => response,
+    Err(e) => panic!("Request failed: {e}"),
+  };
+  let status = response.status();
+  let bytes = match to_bytes(response.into_body(), usize::MAX).await {
+    Ok(bytes) => bytes,
+    Err(e) => panic!("Failed to read body: {e}"),
+  };
+  match serde_json::from_slice(&bytes) {
+    Ok(json) => (status, json),
+    Err(e) => panic!("Response body is not JSON: {e}"),
+  }
+}
+
+#[tokio::test]
+async fn test_health_reports_db_ok() {
+  let pool = match create_sqlite_pool(&SqliteDbConfig::in_memory()).await {
+    Ok(pool) => pool,
+    Err(e) => panic!("Failed to create pool: {e}"),
+  };
+
+  let (status, body) = get_health(api::router(AppState::new().with_db(pool))).await;
+
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(body["status"], "ok");
+  assert_eq!(body["db"], "ok");
+  assert_eq!(body["version"], env!("CARGO_PKG_VERSION"));
+  assert!(body["uptime_secs"].is_u64());
+  assert_eq!(
+    body.as_object().map(|fields| fields.len()),
+    Some(4),
+    "unexpected fields in {body}"
+  );
+}
+
+#[tokio::test]
+async fn test_health_reports_db_down() {
+  let pool = match create_sqlite_pool(&SqliteDbConfig::in_memory()).await {
+    Ok(pool) => pool,
+    Err(e) => panic!("Failed to create pool: {e}"),
+  };
+  pool.close().await;
+
+  let (status, body) = get_health(api::router(AppState::new().with_db(pool))).await;
+
+  assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+  assert_eq!(body["status"], "degraded");
+  assert_eq!(body["db"], "down");
+  assert_eq!(body["version"], env!("CARGO_PKG_VERSION"));
+  assert!(body["uptime_secs"].is_u64());
+}
+
+#[tokio::test]
+async fn test_health_without_db() {
+  let (status, body) = get_health(api::router(AppState::new())).await;
+
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(body["db"], "disabled");
+}
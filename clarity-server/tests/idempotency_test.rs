@@ -0,0 +1,107 @@
+#![allow(clippy::disallowed_methods)]
+#![allow(clippy::panic)]
+
+//! Tests for `Idempotency-Key` deduplication of writes
+
+use axum::{
+  body::{to_bytes, Body},
+  http::{header, Request, StatusCode},
+  Router,
+};
+use clarity_core::db::{create_sqlite_pool, run_sqlite_migrations, SqliteDbConfig};
+use clarity_server::idempotency::{IDEMPOTENCY_KEY_HEADER, IDEMPOTENT_REPLAYED_HEADER};
+use clarity_server::{api, AppState};
+use serde_json::Value;
+use tower::ServiceExt;
+
+async fn app() -> Router {
+  // A single connection keeps every query on the same in-memory database
+  let config = SqliteDbConfig::in_memory().with_max_connections(1);
+  let pool = match create_sqlite_pool(&config).await {
+    Ok(pool) => pool,
+    Err(e) => panic!("Failed to create pool: {e}"),
+  };
+  if let Err(e) = run_sqlite_migrations(&pool).await {
+    panic!("Failed to run migrations: {e}");
+  }
+  api::router(AppState::new().with_db(pool))
+}
+
+/// Response status, whether it was a replay, and the JSON body
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, bool, Value) {
+  let response = match app.clone().oneshot(request).await {
+    Ok(response) => response,
+    Err(e) => panic!("Request failed: {e}"),
+  };
+  let status = response.status();
+  let replayed = response.headers().contains_key(IDEMPOTENT_REPLAYED_HEADER);
+  let bytes = match to_bytes(response.into_body(), usize::MAX).await {
+    Ok(bytes) => bytes,
+    Err(e) => panic!("Failed to read body: {e}"),
+  };
+  match serde_json::from_slice(&bytes) {
+    Ok(json) => (status, replayed, json),
+    Err(e) => panic!("Response body is not JSON: {e}"),
+  }
+}
+
+async fn post_batch(app: &Router, key: Option<&str>) -> (StatusCode, bool, Value) {
+  let batch = serde_json::json!([{
+    "title": "Ship it",
+    "description": null,
+    "status": "Open",
+    "priority": 1,
+    "bead_type": "Feature",
+    "created_by": null,
+  }]);
+  let mut builder = Request::builder()
+    .method("POST")
+    .uri("/beads/batch")
+    .header(header::CONTENT_TYPE, "application/json");
+  if let Some(key) = key {
+    builder = builder.header(IDEMPOTENCY_KEY_HEADER, key);
+  }
+  match builder.body(Body::from(batch.to_string())) {
+    Ok(request) => send(app, request).await,
+    Err(e) => panic!("Failed to build request: {e}"),
+  }
+}
+
+async fn bead_count(app: &Router) -> Option<usize> {
+  let request = match Request::builder().uri("/beads").body(Body::empty()) {
+    Ok(request) => request,
+    Err(e) => panic!("Failed to build request: {e}"),
+  };
+  let (_, _, page) = send(app, request).await;
+  page["items"].as_array().map(Vec::len)
+}
+
+#[tokio::test]
+async fn test_replayed_key_is_applied_once() {
+  let app = app().await;
+
+  let (status, replayed, first) = post_batch(&app, Some("key-1")).await;
+  assert_eq!(status, StatusCode::CREATED);
+  assert!(!replayed);
+
+  let (status, replayed, second) = post_batch(&app, Some("key-1")).await;
+  assert_eq!(status, StatusCode::CREATED);
+  assert!(replayed);
+  assert_eq!(second, first);
+
+  assert_eq!(bead_count(&app).await, Some(1));
+}
+
+#[tokio::test]
+async fn test_distinct_or_missing_keys_are_applied_each_time() {
+  let app = app().await;
+
+  post_batch(&app, Some("key-1")).await;
+  let (status, replayed, _) = post_batch(&app, Some("key-2")).await;
+  assert_eq!(status, StatusCode::CREATED);
+  assert!(!replayed);
+  post_batch(&app, None).await;
+  post_batch(&app, None).await;
+
+  assert_eq!(bead_count(&app).await, Some(4));
+}
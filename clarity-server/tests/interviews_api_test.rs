@@ -0,0 +1,290 @@
+#![allow(clippy::disallowed_methods)]
+#![allow(clippy::panic)]
+
+//! Integration tests for the interview API endpoints
+//!
+//! These tests drive the router directly with `tower::ServiceExt::oneshot`,
+//! so no socket is bound.
+
+use axum::{
+  body::{to_bytes, Body},
+  http::{header, Request, StatusCode},
+  Router,
+};
+use clarity_core::interview::{
+  AnswerValue, Interview, InterviewId, InterviewState, Question, QuestionType, Timestamp,
+};
+use clarity_server::idempotency::{IDEMPOTENCY_KEY_HEADER, IDEMPOTENT_REPLAYED_HEADER};
+use clarity_server::{api, AppState};
+use serde_json::Value;
+use tower::ServiceExt;
+
+const INTERVIEW_ID: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+fn sample_interview() -> Interview {
+  let result = Interview::builder()
+    .id(INTERVIEW_ID.to_string())
+    .spec_name("my_spec".to_string())
+    .created_at(Timestamp::from_secs(1_000))
+    .add_question(Question {
+      id: None,
+      text: "What is your name?".to_string(),
+      help_text: None,
+      required: true,
+      question_type: QuestionType::Text,
+    })
+    .add_question(Question {
+      id: None,
+      text: "Do you like Rust?".to_string(),
+      help_text: None,
+      required: false,
+      question_type: QuestionType::Boolean,
+    })
+    .build();
+
+  match result {
+    Ok(interview) => interview,
+    Err(e) => panic!("Failed to build interview: {e}"),
+  }
+}
+
+fn in_progress_interview() -> Interview {
+  match sample_interview().transition_to(InterviewState::InProgress, Timestamp::from_secs(2_000)) {
+    Ok(interview) => interview,
+    Err(e) => panic!("Failed to start interview: {e}"),
+  }
+}
+
+async fn state_with(interview: Interview) -> AppState {
+  let state = AppState::new();
+  if let Err(e) = state.interviews.create(interview).await {
+    panic!("Failed to seed interview: {e}");
+  }
+  state
+}
+
+async fn app_with_interview() -> (Router, AppState) {
+  let state = state_with(sample_interview()).await;
+  (api::router(state.clone()), state)
+}
+
+async fn post_answer(app: Router, id: &str, body: &str) -> (StatusCode, Value) {
+  post_json(app, &format!("/interviews/{id}/answers"), body).await
+}
+
+async fn post_json(app: Router, uri: &str, body: &str) -> (StatusCode, Value) {
+  let request = match Request::builder()
+    .method("POST")
+    .uri(uri)
+    .header(header::CONTENT_TYPE, "application/json")
+    .body(Body::from(body.to_string()))
+  {
+    Ok(request) => request,
+    Err(e) => panic!("Failed to build request: {e}"),
+  };
+
+  let response = match app.oneshot(request).await {
+    Ok(response) => response,
+    Err(e) => panic!("Request failed: {e}"),
+  };
+  let status = response.status();
+  let bytes = match to_bytes(response.into_body(), usize::MAX).await {
+    Ok(bytes) => bytes,
+    Err(e) => panic!("Failed to read body: {e}"),
+  };
+  let json = match serde_json::from_slice(&bytes) {
+    Ok(json) => json,
+    Err(e) => panic!("Response body is not JSON: {e}"),
+  };
+
+  (status, json)
+}
+
+#[tokio::test]
+async fn test_submit_valid_answer_returns_progress() {
+  let (app, state) = app_with_interview().await;
+
+  let (status, body) = post_answer(
+    app,
+    INTERVIEW_ID,
+    r#"{"question_index": 0, "value": {"type": "text", "value": "Ada"}}"#,
+  )
+  .await;
+
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(body["total"], 2);
+  assert_eq!(body["completed"], 1);
+
+  let id = match InterviewId::new(INTERVIEW_ID.to_string()) {
+    Ok(id) => id,
+    Err(e) => panic!("Invalid id: {e}"),
+  };
+  match state.interviews.get(&id).await {
+    Ok(interview) => assert_eq!(interview.answers.len(), 1),
+    Err(e) => panic!("Interview should still be stored: {e}"),
+  }
+}
+
+#[tokio::test]
+async fn test_submit_mismatched_answer_returns_bad_request() {
+  let (app, _state) = app_with_interview().await;
+
+  let (status, body) = post_answer(
+    app,
+    INTERVIEW_ID,
+    r#"{"question_index": 1, "value": {"type": "text", "value": "yes"}}"#,
+  )
+  .await;
+
+  assert_eq!(status, StatusCode::BAD_REQUEST);
+  assert_eq!(body["error"]["code"], "validation_error");
+  assert_eq!(
+    body["error"]["message"],
+    "answer to question 1 must be of type boolean"
+  );
+}
+
+#[tokio::test]
+async fn test_submit_answer_out_of_range_returns_bad_request() {
+  let (app, _state) = app_with_interview().await;
+
+  let (status, body) = post_answer(
+    app,
+    INTERVIEW_ID,
+    r#"{"question_index": 5, "value": {"type": "boolean", "value": true}}"#,
+  )
+  .await;
+
+  assert_eq!(status, StatusCode::BAD_REQUEST);
+  assert_eq!(body["error"]["code"], "validation_error");
+  assert_eq!(body["error"]["message"], "invalid question index: 5");
+}
+
+#[tokio::test]
+async fn test_submit_answer_unknown_interview_returns_not_found() {
+  let (app, _state) = app_with_interview().await;
+
+  let (status, body) = post_answer(
+    app,
+    "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+    r#"{"question_index": 0, "value": {"type": "text", "value": "Ada"}}"#,
+  )
+  .await;
+
+  assert_eq!(status, StatusCode::NOT_FOUND);
+  assert_eq!(body["error"]["code"], "not_found");
+  assert!(body["error"].get("missing").is_none());
+}
+
+#[tokio::test]
+async fn test_complete_answered_interview() {
+  let state = state_with(in_progress_interview()).await;
+
+  let (status, _) = post_answer(
+    api::router(state.clone()),
+    INTERVIEW_ID,
+    r#"{"question_index": 0, "value": {"type": "text", "value": "Ada"}}"#,
+  )
+  .await;
+  assert_eq!(status, StatusCode::OK);
+
+  let (status, body) = post_json(
+    api::router(state),
+    &format!("/interviews/{INTERVIEW_ID}/complete"),
+    "",
+  )
+  .await;
+
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(body["id"], INTERVIEW_ID);
+  assert_eq!(body["state"], "completed");
+}
+
+#[tokio::test]
+async fn test_submit_answer_to_completed_interview_returns_conflict() {
+  let state = state_with(in_progress_interview()).await;
+  let answer = r#"{"question_index": 0, "value": {"type": "text", "value": "Ada"}}"#;
+
+  let (status, _) = post_answer(api::router(state.clone()), INTERVIEW_ID, answer).await;
+  assert_eq!(status, StatusCode::OK);
+  let (status, _) = post_json(
+    api::router(state.clone()),
+    &format!("/interviews/{INTERVIEW_ID}/complete"),
+    "",
+  )
+  .await;
+  assert_eq!(status, StatusCode::OK);
+
+  let (status, body) = post_answer(
+    api::router(state),
+    INTERVIEW_ID,
+    r#"{"question_index": 0, "value": {"type": "text", "value": "Grace"}}"#,
+  )
+  .await;
+
+  assert_eq!(status, StatusCode::CONFLICT);
+  assert_eq!(body["error"]["code"], "invalid_state");
+  assert_eq!(
+    body["error"]["message"],
+    "interview is completed and no longer accepts answers"
+  );
+}
+
+#[tokio::test]
+async fn test_complete_with_unanswered_required_returns_unprocessable() {
+  let state = state_with(in_progress_interview()).await;
+
+  let (status, body) = post_json(
+    api::router(state),
+    &format!("/interviews/{INTERVIEW_ID}/complete"),
+    "",
+  )
+  .await;
+
+  assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+  assert_eq!(body["error"]["code"], "missing_answers");
+  assert_eq!(body["error"]["missing"], serde_json::json!([0]));
+}
+
+#[tokio::test]
+async fn test_answer_replayed_with_same_idempotency_key_is_applied_once() {
+  let (app, state) = app_with_interview().await;
+  let answer = |name: &str| {
+    let body =
+      format!(r#"{{"question_index": 0, "value": {{"type": "text", "value": "{name}"}}}}"#);
+    match Request::builder()
+      .method("POST")
+      .uri(format!("/interviews/{INTERVIEW_ID}/answers"))
+      .header(header::CONTENT_TYPE, "application/json")
+      .header(IDEMPOTENCY_KEY_HEADER, "answer-1")
+      .body(Body::from(body))
+    {
+      Ok(request) => request,
+      Err(e) => panic!("Failed to build request: {e}"),
+    }
+  };
+
+  for name in ["Ada", "Grace"] {
+    let response = match app.clone().oneshot(answer(name)).await {
+      Ok(response) => response,
+      Err(e) => panic!("Request failed: {e}"),
+    };
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.headers().contains_key(IDEMPOTENT_REPLAYED_HEADER),
+      name == "Grace"
+    );
+  }
+
+  let id = match InterviewId::new(INTERVIEW_ID.to_string()) {
+    Ok(id) => id,
+    Err(e) => panic!("Invalid id: {e}"),
+  };
+  match state.interviews.get(&id).await {
+    Ok(interview) => assert_eq!(
+      interview.answers[0].value,
+      AnswerValue::Text("Ada".to_string())
+    ),
+    Err(e) => panic!("Interview should still be stored: {e}"),
+  }
+}
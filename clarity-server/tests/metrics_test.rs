@@ -0,0 +1,75 @@
+#![allow(clippy::disallowed_methods)]
+#![allow(clippy::panic)]
+
+//! Integration tests for the Prometheus metrics endpoint
+
+use axum::{
+  body::{to_bytes, Body},
+  http::{header, Request, StatusCode},
+  Router,
+};
+use clarity_server::{api, telemetry, AppState};
+use tower::ServiceExt;
+
+async fn get(app: &Router, uri: &str) -> (StatusCode, Option<String>, String) {
+  let request = match Request::builder().uri(uri).body(Body::empty()) {
+    Ok(request) => request,
+    Err(e) => panic!("Failed to build request: {e}"),
+  };
+
+  let response = match app.clone().oneshot(request).await {
+    Ok(response) => response,
+    Err(e) => panic!("Request failed: {e}"),
+  };
+  let status = response.status();
+  let content_type = response
+    .headers()
+    .get(header::CONTENT_TYPE)
+    .and_then(|value| value.to_str().ok())
+    .map(str::to_string);
+  let bytes = match to_bytes(response.into_body(), usize::MAX).await {
+    Ok(bytes) => bytes,
+    Err(e) => panic!("Failed to read body: {e}"),
+  };
+  match String::from_utf8(bytes.to_vec()) {
+    Ok(body) => (status, content_type, body),
+    Err(e) => panic!("Response body is not UTF-8: {e}"),
+  }
+}
+
+/// The value of the request counter series for `route`, if it was recorded
+fn request_count(metrics: &str, route: &str) -> Option<f64> {
+  let labels = format!("route=\"{route}\"");
+  metrics
+    .lines()
+    .filter(|line| line.starts_with(telemetry::REQUESTS_TOTAL) && line.contains(&labels))
+    .find_map(|line| line.rsplit(' ').next()?.parse().ok())
+}
+
+#[tokio::test]
+async fn test_health_request_is_counted() {
+  let handle = match telemetry::install() {
+    Some(handle) => handle,
+    None => panic!("Failed to install the metrics recorder"),
+  };
+  let app = api::router(AppState::new().with_metrics(handle));
+
+  let (status, _, _) = get(&app, "/health").await;
+  assert_eq!(status, StatusCode::OK);
+
+  let (status, content_type, body) = get(&app, "/metrics").await;
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(content_type.as_deref(), Some("text/plain; version=0.0.4"));
+  assert!(
+    request_count(&body, "/health").is_some_and(|count| count > 0.0),
+    "no /health request counted in:\n{body}"
+  );
+  assert_eq!(request_count(&body, "/metrics"), None);
+  assert!(body.contains(&format!("{}{{state=\"created\"}} 0", telemetry::SESSIONS)));
+}
+
+#[tokio::test]
+async fn test_metrics_route_absent_when_disabled() {
+  let (status, _, _) = get(&api::router(AppState::new()), "/metrics").await;
+  assert_eq!(status, StatusCode::NOT_FOUND);
+}
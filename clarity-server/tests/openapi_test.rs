@@ -0,0 +1,73 @@
+#![allow(clippy::disallowed_methods)]
+#![allow(clippy::panic)]
+
+//! Tests for the served OpenAPI spec
+
+use axum::{
+  body::{to_bytes, Body},
+  http::{Request, StatusCode},
+};
+use clarity_server::{api, AppState};
+use serde_json::Value;
+use tower::ServiceExt;
+
+async fn spec() -> Value {
+  let request = match Request::builder().uri("/openapi.json").body(Body::empty()) {
+    Ok(request) => request,
+    Err(e) => panic!("Failed to build request: {e}"),
+  };
+  let response = match api::router(AppState::new()).oneshot(request).await {
+    Ok(response) => response,
+    Err(e) => panic!("Request failed: {e}"),
+  };
+  assert_eq!(response.status(), StatusCode::OK);
+
+  let bytes = match to_bytes(response.into_body(), usize::MAX).await {
+    Ok(bytes) => bytes,
+    Err(e) => panic!("Failed to read body: {e}"),
+  };
+  match serde_json::from_slice(&bytes) {
+    Ok(spec) => spec,
+    Err(e) => panic!("Spec is not valid JSON: {e}"),
+  }
+}
+
+#[tokio::test]
+async fn test_spec_describes_bead_listing() {
+  let spec = spec().await;
+
+  let list = &spec["paths"]["/beads"]["get"];
+  assert!(list.is_object(), "missing GET /beads in {spec}");
+
+  let params: Vec<&str> = list["parameters"]
+    .as_array()
+    .map(|params| params.iter().filter_map(|p| p["name"].as_str()).collect())
+    .unwrap_or_default();
+  assert_eq!(
+    params,
+    ["status", "priority", "limit", "cursor", "sort", "order"]
+  );
+}
+
+#[tokio::test]
+async fn test_spec_uses_error_envelope() {
+  let spec = spec().await;
+
+  assert_eq!(
+    spec["paths"]["/beads/{id}"]["get"]["responses"]["404"]["content"]["application/json"]
+      ["schema"]["$ref"],
+    "#/components/schemas/ErrorEnvelope"
+  );
+  assert!(spec["components"]["schemas"]["ErrorEnvelope"]["properties"]["error"].is_object());
+}
+
+#[tokio::test]
+async fn test_spec_marks_delete_as_authenticated() {
+  let spec = spec().await;
+
+  assert!(spec["paths"]["/sessions/{id}"]["delete"]["security"].is_array());
+  assert_eq!(
+    spec["components"]["securitySchemes"]["bearer"]["scheme"],
+    "bearer"
+  );
+}
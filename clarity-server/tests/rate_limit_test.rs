@@ -0,0 +1,54 @@
+#![allow(clippy::disallowed_methods)]
+#![allow(clippy::panic)]
+
+//! Tests for per-client rate limiting of the API
+
+use axum::{
+  body::Body,
+  http::{header, Request, StatusCode},
+  Router,
+};
+use clarity_server::rate_limit::RateLimitConfig;
+use clarity_server::{api, AppState};
+use tower::ServiceExt;
+
+async fn status_of(app: &Router, uri: &str) -> (StatusCode, Option<String>) {
+  let request = match Request::builder().uri(uri).body(Body::empty()) {
+    Ok(request) => request,
+    Err(e) => panic!("Failed to build request: {e}"),
+  };
+
+  match app.clone().oneshot(request).await {
+    Ok(response) => (
+      response.status(),
+      response
+        .headers()
+        .get(header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string),
+    ),
+    Err(e) => panic!("Request failed: {e}"),
+  }
+}
+
+#[tokio::test]
+async fn test_second_immediate_request_is_rate_limited() {
+  let app = api::router(AppState::new().with_rate_limit(RateLimitConfig::new(1, 1)));
+
+  let (first, _) = status_of(&app, "/sessions/550e8400-e29b-41d4-a716-446655440000").await;
+  assert_eq!(first, StatusCode::NOT_FOUND);
+
+  let (second, retry_after) = status_of(&app, "/beads").await;
+  assert_eq!(second, StatusCode::TOO_MANY_REQUESTS);
+  assert_eq!(retry_after.as_deref(), Some("1"));
+}
+
+#[tokio::test]
+async fn test_health_is_not_rate_limited() {
+  let app = api::router(AppState::new().with_rate_limit(RateLimitConfig::new(1, 1)));
+
+  for _ in 0..3 {
+    let (status, _) = status_of(&app, "/health").await;
+    assert_eq!(status, StatusCode::OK);
+  }
+}
@@ -0,0 +1,53 @@
+#![allow(clippy::disallowed_methods)]
+#![allow(clippy::panic)]
+
+//! Tests for request id propagation
+
+use axum::{
+  body::Body,
+  http::{HeaderMap, Request},
+};
+use clarity_server::request_id::{with_request_id, REQUEST_ID_HEADER};
+use clarity_server::{api, AppState};
+use tower::ServiceExt;
+
+async fn get_health(request_id: Option<&str>) -> HeaderMap {
+  let mut request = Request::builder().uri("/health");
+  if let Some(id) = request_id {
+    request = request.header(REQUEST_ID_HEADER, id);
+  }
+  let request = match request.body(Body::empty()) {
+    Ok(request) => request,
+    Err(e) => panic!("Failed to build request: {e}"),
+  };
+
+  match with_request_id(api::router(AppState::new()))
+    .oneshot(request)
+    .await
+  {
+    Ok(response) => response.headers().clone(),
+    Err(e) => panic!("Request failed: {e}"),
+  }
+}
+
+#[tokio::test]
+async fn test_client_request_id_is_preserved() {
+  let headers = get_health(Some("client-supplied-id")).await;
+
+  assert_eq!(
+    headers.get(REQUEST_ID_HEADER).map(|v| v.as_bytes()),
+    Some(&b"client-supplied-id"[..])
+  );
+}
+
+#[tokio::test]
+async fn test_request_id_is_generated() {
+  let headers = get_health(None).await;
+
+  let id = match headers.get(REQUEST_ID_HEADER).map(|v| v.to_str()) {
+    Some(Ok(id)) => id.to_string(),
+    Some(Err(e)) => panic!("Request id is not ASCII: {e}"),
+    None => panic!("Missing {REQUEST_ID_HEADER} header"),
+  };
+  assert!(uuid::Uuid::parse_str(&id).is_ok());
+}
@@ -0,0 +1,112 @@
+#![allow(clippy::disallowed_methods)]
+#![allow(clippy::panic)]
+
+//! Tests for the startup self-check report
+
+use clarity_core::db::{create_sqlite_pool, run_sqlite_migrations, DbError, SqliteDbConfig};
+use clarity_core::ExitCode;
+use clarity_server::self_check::{CheckResult, CheckStatus};
+use clarity_server::{self_check, SelfCheckReport};
+
+#[test]
+fn test_all_green_report_exits_successfully() {
+  let report = SelfCheckReport::from_checks(vec![
+    CheckResult::passed("config", "database configuration is valid"),
+    CheckResult::passed("database", "connected"),
+    CheckResult::passed("migrations", "all migrations applied"),
+  ]);
+
+  assert!(report.is_healthy());
+  assert_eq!(report.exit_code(), ExitCode::SUCCESS);
+  assert!(report.to_string().ends_with("self-check healthy"));
+}
+
+#[test]
+fn test_failing_migrations_report_exits_non_zero() {
+  let report = SelfCheckReport::from_checks(vec![
+    CheckResult::passed("config", "database configuration is valid"),
+    CheckResult::passed("database", "connected"),
+    CheckResult::failed(
+      "migrations",
+      &DbError::Migration("Pending migrations: 2".to_string()),
+    ),
+  ]);
+
+  assert!(!report.is_healthy());
+  assert!(report.exit_code().is_failure());
+  assert_eq!(report.exit_code(), ExitCode::CONFIG_ERROR);
+  assert!(report
+    .to_string()
+    .contains("[FAIL] migrations: Migration error: Pending migrations: 2"));
+}
+
+#[tokio::test]
+async fn test_invalid_config_skips_remaining_checks() {
+  let config = SqliteDbConfig::new("mysql://localhost/clarity".to_string());
+
+  let report = self_check(&config).await;
+
+  let statuses: Vec<CheckStatus> = report.checks().iter().map(|c| c.status).collect();
+  assert_eq!(
+    statuses,
+    vec![
+      CheckStatus::Failed(ExitCode::VALIDATION_ERROR),
+      CheckStatus::Skipped,
+      CheckStatus::Skipped,
+    ]
+  );
+  assert_eq!(report.exit_code(), ExitCode::VALIDATION_ERROR);
+}
+
+/// A `SQLite` database file that is removed when the test ends
+struct TempDb(std::path::PathBuf);
+
+impl TempDb {
+  fn new() -> Self {
+    Self(std::env::temp_dir().join(format!("clarity-check-{}.db", uuid::Uuid::new_v4())))
+  }
+
+  fn config(&self) -> SqliteDbConfig {
+    SqliteDbConfig::new(format!("sqlite:{}", self.0.display()))
+  }
+}
+
+impl Drop for TempDb {
+  fn drop(&mut self) {
+    let _ = std::fs::remove_file(&self.0);
+  }
+}
+
+#[tokio::test]
+async fn test_migrated_sqlite_database_is_healthy() {
+  let db = TempDb::new();
+  let pool = match create_sqlite_pool(&db.config()).await {
+    Ok(pool) => pool,
+    Err(e) => panic!("Failed to create pool: {e}"),
+  };
+  if let Err(e) = run_sqlite_migrations(&pool).await {
+    panic!("Failed to run migrations: {e}");
+  }
+  pool.close().await;
+
+  let report = self_check(&db.config()).await;
+
+  assert!(report.is_healthy(), "{report}");
+}
+
+#[tokio::test]
+async fn test_unmigrated_sqlite_database_fails_migrations_check() {
+  let db = TempDb::new();
+
+  let report = self_check(&db.config()).await;
+
+  let statuses: Vec<(&str, CheckStatus)> =
+    report.checks().iter().map(|c| (c.name, c.status)).collect();
+  assert_eq!(statuses[0], ("config", CheckStatus::Passed));
+  assert_eq!(statuses[1], ("database", CheckStatus::Passed));
+  assert!(matches!(
+    statuses[2],
+    ("migrations", CheckStatus::Failed(_))
+  ));
+  assert!(!report.is_healthy());
+}
@@ -0,0 +1,178 @@
+#![allow(clippy::disallowed_methods)]
+#![allow(clippy::panic)]
+
+//! Integration tests for the session endpoints
+
+use axum::{
+  body::Body,
+  http::{Request, StatusCode},
+  response::Response,
+};
+use clarity_core::session::{Session, SessionKind, SessionState, Timestamp};
+use clarity_server::session_store::SessionStore;
+use clarity_server::{api, AppState};
+use futures::StreamExt;
+use tower::ServiceExt;
+
+const SESSION_ID: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+async fn state_with_session(session_state: SessionState) -> AppState {
+  let session = match Session::builder()
+    .id(SESSION_ID.to_string())
+    .kind(SessionKind::Analysis)
+    .created_at(Timestamp::from_secs(1_000))
+    .build()
+  {
+    Ok(session) => session,
+    Err(e) => panic!("Failed to build session: {e}"),
+  };
+  let session = if session_state == SessionState::Created {
+    session
+  } else {
+    let started = session.transition_to(SessionState::InProgress, Timestamp::from_secs(1_500));
+    match started.and_then(|s| s.transition_to(session_state, Timestamp::from_secs(2_000))) {
+      Ok(session) => session,
+      Err(e) => panic!("Failed to move session to {session_state}: {e}"),
+    }
+  };
+
+  let state = AppState::new();
+  if let Err(e) = state.sessions.create(session).await {
+    panic!("Expected session to be stored, got {e}");
+  }
+  state
+}
+
+async fn get(state: AppState, uri: &str) -> Response {
+  let request = match Request::builder().uri(uri).body(Body::empty()) {
+    Ok(request) => request,
+    Err(e) => panic!("Failed to build request: {e}"),
+  };
+  match api::router(state).oneshot(request).await {
+    Ok(response) => response,
+    Err(e) => panic!("Request failed: {e}"),
+  }
+}
+
+async fn next_chunk<S>(body: &mut S) -> String
+where
+  S: futures::Stream<Item = Result<axum::body::Bytes, axum::Error>> + Unpin,
+{
+  match body.next().await {
+    Some(Ok(bytes)) => String::from_utf8_lossy(&bytes).into_owned(),
+    Some(Err(e)) => panic!("Failed to read event: {e}"),
+    None => panic!("Event stream ended early"),
+  }
+}
+
+#[tokio::test]
+async fn test_events_start_with_current_state() {
+  let state = state_with_session(SessionState::InProgress).await;
+
+  let response = get(state.clone(), &format!("/sessions/{SESSION_ID}/events")).await;
+  assert_eq!(response.status(), StatusCode::OK);
+  let mut body = response.into_body().into_data_stream();
+
+  let initial = next_chunk(&mut body).await;
+  assert!(initial.starts_with("event: state\n"));
+  assert!(initial.contains(r#""state":"in_progress""#));
+
+  let id = match clarity_core::session::SessionId::new(SESSION_ID.to_string()) {
+    Ok(id) => id,
+    Err(e) => panic!("Invalid session id: {e}"),
+  };
+  if let Err(e) = state
+    .transition_session(&id, SessionState::Completed, Timestamp::from_secs(3_000))
+    .await
+  {
+    panic!("Failed to complete session: {e}");
+  }
+
+  let completed = next_chunk(&mut body).await;
+  assert!(completed.contains(r#""state":"completed""#));
+  assert!(
+    body.next().await.is_none(),
+    "Stream should close on a terminal state"
+  );
+}
+
+#[tokio::test]
+async fn test_events_for_unknown_session_return_not_found() {
+  let state = AppState::new();
+
+  let response = get(state, &format!("/sessions/{SESSION_ID}/events")).await;
+
+  assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+async fn delete(state: AppState, uri: &str) -> Response {
+  let request = match Request::builder()
+    .method("DELETE")
+    .uri(uri)
+    .body(Body::empty())
+  {
+    Ok(request) => request,
+    Err(e) => panic!("Failed to build request: {e}"),
+  };
+  match api::router(state).oneshot(request).await {
+    Ok(response) => response,
+    Err(e) => panic!("Request failed: {e}"),
+  }
+}
+
+async fn stored(state: &AppState) -> Option<Session> {
+  state
+    .sessions
+    .list()
+    .await
+    .into_iter()
+    .find(|session| session.id.as_str() == SESSION_ID)
+}
+
+#[tokio::test]
+async fn test_delete_soft_deletes_session() {
+  let state = state_with_session(SessionState::InProgress).await;
+
+  let response = delete(state.clone(), &format!("/sessions/{SESSION_ID}")).await;
+  assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+  match stored(&state).await {
+    Some(session) => {
+      assert_eq!(session.state, SessionState::Cancelled);
+      assert!(session.is_deleted());
+    }
+    None => panic!("Soft-deleted session should be kept"),
+  }
+
+  let response = get(state, &format!("/sessions/{SESSION_ID}")).await;
+  assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_delete_with_purge_removes_session() {
+  let state = state_with_session(SessionState::Completed).await;
+
+  let response = delete(state.clone(), &format!("/sessions/{SESSION_ID}?purge=true")).await;
+  assert_eq!(response.status(), StatusCode::NO_CONTENT);
+  assert!(stored(&state).await.is_none());
+
+  let response = get(state, &format!("/sessions/{SESSION_ID}")).await;
+  assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_delete_completed_session_conflicts() {
+  let state = state_with_session(SessionState::Completed).await;
+
+  let response = delete(state.clone(), &format!("/sessions/{SESSION_ID}")).await;
+
+  assert_eq!(response.status(), StatusCode::CONFLICT);
+  assert!(stored(&state).await.is_some_and(|s| !s.is_deleted()));
+}
+
+#[tokio::test]
+async fn test_delete_unknown_session_returns_not_found() {
+  let response = delete(AppState::new(), &format!("/sessions/{SESSION_ID}")).await;
+
+  assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,39 @@
+#![allow(clippy::disallowed_methods)]
+#![allow(clippy::panic)]
+
+//! Tests for graceful shutdown
+
+use axum::Router;
+use clarity_server::shutdown::graceful_shutdown;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+
+#[tokio::test]
+async fn test_server_stops_when_signal_fires() {
+  let listener = match TcpListener::bind("127.0.0.1:0").await {
+    Ok(listener) => listener,
+    Err(e) => panic!("Failed to bind: {e}"),
+  };
+  let (trigger, signal) = oneshot::channel::<()>();
+  let signal = async {
+    let _ = signal.await;
+  };
+
+  let server = tokio::spawn(async move {
+    axum::serve(listener, Router::new())
+      .with_graceful_shutdown(graceful_shutdown(signal))
+      .await
+  });
+
+  if trigger.send(()).is_err() {
+    panic!("Server dropped the shutdown signal");
+  }
+
+  match tokio::time::timeout(Duration::from_secs(5), server).await {
+    Ok(Ok(Ok(()))) => {}
+    Ok(Ok(Err(e))) => panic!("Server failed: {e}"),
+    Ok(Err(e)) => panic!("Server task panicked: {e}"),
+    Err(e) => panic!("Server did not shut down: {e}"),
+  }
+}
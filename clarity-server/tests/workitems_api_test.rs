@@ -0,0 +1,210 @@
+#![allow(clippy::disallowed_methods)]
+#![allow(clippy::panic)]
+
+//! Integration tests for the unified work item feed
+
+use axum::{
+  body::{to_bytes, Body},
+  http::{Request, StatusCode},
+};
+use chrono::{TimeZone, Utc};
+use clarity_core::db::{
+  create_bead, create_sqlite_pool, run_sqlite_migrations, BeadPriority, BeadStatus, BeadType,
+  NewBead, SqliteDbConfig,
+};
+use clarity_core::interview::{self, Interview};
+use clarity_core::session::{self, Session, SessionKind};
+use clarity_server::session_store::SessionStore;
+use clarity_server::{api, AppState};
+use serde_json::Value;
+use sqlx::SqlitePool;
+use tower::ServiceExt;
+
+fn session(id: &str, updated_at: i64) -> Session {
+  let result = Session::builder()
+    .id(id.to_string())
+    .kind(SessionKind::Analysis)
+    .title(format!("session {updated_at}"))
+    .created_at(session::Timestamp::from_secs(updated_at))
+    .build();
+
+  match result {
+    Ok(session) => session,
+    Err(e) => panic!("Failed to build session: {e}"),
+  }
+}
+
+fn interview(id: &str, updated_at: i64) -> Interview {
+  let result = Interview::builder()
+    .id(id.to_string())
+    .spec_name("my_spec".to_string())
+    .created_at(interview::Timestamp::from_secs(updated_at))
+    .build();
+
+  match result {
+    Ok(interview) => interview,
+    Err(e) => panic!("Failed to build interview: {e}"),
+  }
+}
+
+/// Stores a bead and backdates it so it was last updated at `updated_at`
+async fn seed_bead(pool: &SqlitePool, title: &str, status: BeadStatus, updated_at: i64) {
+  let timestamp = match Utc.timestamp_opt(updated_at, 0).single() {
+    Some(timestamp) => timestamp,
+    None => panic!("Invalid timestamp {updated_at}"),
+  };
+
+  let bead = NewBead {
+    title: title.to_string(),
+    description: None,
+    status,
+    priority: BeadPriority::MEDIUM,
+    bead_type: BeadType::Feature,
+    created_by: None,
+  };
+  let created = match create_bead(pool, &bead).await {
+    Ok(created) => created,
+    Err(e) => panic!("Failed to seed bead: {e}"),
+  };
+
+  let backdated = sqlx::query("UPDATE beads SET created_at = ?, updated_at = ? WHERE id = ?")
+    .bind(timestamp)
+    .bind(timestamp)
+    .bind(created.id.to_string())
+    .execute(pool)
+    .await;
+  if let Err(e) = backdated {
+    panic!("Failed to backdate bead: {e}");
+  }
+}
+
+/// Seeds one session, one interview, an open bead and a closed bead with distinct update times
+async fn seeded_state() -> AppState {
+  // A single connection keeps every query on the same in-memory database
+  let config = SqliteDbConfig::in_memory().with_max_connections(1);
+  let pool = match create_sqlite_pool(&config).await {
+    Ok(pool) => pool,
+    Err(e) => panic!("Failed to create pool: {e}"),
+  };
+  if let Err(e) = run_sqlite_migrations(&pool).await {
+    panic!("Failed to run migrations: {e}");
+  }
+  seed_bead(&pool, "open bead", BeadStatus::Open, 400).await;
+  seed_bead(&pool, "closed bead", BeadStatus::Closed, 100).await;
+
+  let state = AppState::new().with_db(pool);
+
+  let session = session("550e8400-e29b-41d4-a716-446655440000", 300);
+  if let Err(e) = state.sessions.create(session).await {
+    panic!("Expected session to be stored, got {e}");
+  }
+
+  if let Err(e) = state
+    .interviews
+    .create(interview("6ba7b810-9dad-11d1-80b4-00c04fd430c8", 200))
+    .await
+  {
+    panic!("Failed to seed interview: {e}");
+  }
+
+  state
+}
+
+async fn get_json(state: AppState, uri: &str) -> (StatusCode, Value) {
+  let request = match Request::builder().uri(uri).body(Body::empty()) {
+    Ok(request) => request,
+    Err(e) => panic!("Failed to build request: {e}"),
+  };
+
+  let response = match api::router(state).oneshot(request).await {
+    Ok(response) => response,
+    Err(e) => panic!("Request failed: {e}"),
+  };
+  let status = response.status();
+  let bytes = match to_bytes(response.into_body(), usize::MAX).await {
+    Ok(bytes) => bytes,
+    Err(e) => panic!("Failed to read body: {e}"),
+  };
+  let json = match serde_json::from_slice(&bytes) {
+    Ok(json) => json,
+    Err(e) => panic!("Response body is not JSON: {e}"),
+  };
+
+  (status, json)
+}
+
+fn kinds_and_times(body: &Value) -> Vec<(String, i64)> {
+  let items = match body["items"].as_array() {
+    Some(items) => items,
+    None => panic!("Expected items array"),
+  };
+
+  items
+    .iter()
+    .map(|item| {
+      (
+        item["kind"].as_str().unwrap_or_default().to_string(),
+        item["updated_at"].as_i64().unwrap_or_default(),
+      )
+    })
+    .collect()
+}
+
+#[tokio::test]
+async fn test_work_items_merged_by_recency() {
+  let (status, body) = get_json(seeded_state().await, "/workitems").await;
+
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(body["total"], 4);
+  assert_eq!(
+    kinds_and_times(&body),
+    vec![
+      ("bead".to_string(), 400),
+      ("session".to_string(), 300),
+      ("interview".to_string(), 200),
+      ("bead".to_string(), 100),
+    ]
+  );
+  assert!(body["next_offset"].is_null());
+}
+
+#[tokio::test]
+async fn test_work_items_active_filter() {
+  let (status, body) = get_json(seeded_state().await, "/workitems?active=true").await;
+
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(body["total"], 3);
+  let items = match body["items"].as_array() {
+    Some(items) => items,
+    None => panic!("Expected items array"),
+  };
+  assert!(items.iter().all(|item| item["active"] == true));
+  assert!(items.iter().all(|item| item["title"] != "closed bead"));
+}
+
+#[tokio::test]
+async fn test_work_items_pagination() {
+  let state = seeded_state().await;
+
+  let (_, first) = get_json(state.clone(), "/workitems?limit=3").await;
+  assert_eq!(kinds_and_times(&first).len(), 3);
+  assert_eq!(first["next_offset"], 3);
+
+  let (_, second) = get_json(state, "/workitems?limit=3&offset=3").await;
+  assert_eq!(kinds_and_times(&second), vec![("bead".to_string(), 100)]);
+  assert!(second["next_offset"].is_null());
+}
+
+#[tokio::test]
+async fn test_work_items_without_database_omit_beads() {
+  let state = AppState::new();
+  let session = session("550e8400-e29b-41d4-a716-446655440000", 300);
+  if let Err(e) = state.sessions.create(session).await {
+    panic!("Expected session to be stored, got {e}");
+  }
+
+  let (status, body) = get_json(state, "/workitems").await;
+
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(kinds_and_times(&body), vec![("session".to_string(), 300)]);
+}